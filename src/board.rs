@@ -0,0 +1,193 @@
+use std::{error::Error, fmt};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoardParseError {
+    Empty,
+    InvalidPosition,
+    InvalidJson,
+    MissingPositions,
+    InvalidCell,
+    UnevenRows,
+    EmptyColumn,
+    CrowdedColumn,
+}
+
+impl fmt::Display for BoardParseError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => formatter.write_str("board input is empty"),
+            Self::InvalidPosition => {
+                formatter.write_str("positions must be non-negative integers within u16 range")
+            }
+            Self::InvalidJson => formatter.write_str("board input is not valid JSON"),
+            Self::MissingPositions => {
+                formatter.write_str("JSON input must contain a positions array")
+            }
+            Self::InvalidCell => formatter.write_str(
+                "board rows may only contain 'Q' for queens, '.' or digits for empty cells",
+            ),
+            Self::UnevenRows => {
+                formatter.write_str("board rows must all be as wide as the board is tall")
+            }
+            Self::EmptyColumn => formatter.write_str("every board column must hold a queen"),
+            Self::CrowdedColumn => formatter.write_str("board columns may hold only one queen"),
+        }
+    }
+}
+
+impl Error for BoardParseError {}
+
+// Accepts a JSON summary/array, a comma or whitespace separated position list, or FEN-like rows
+// (top row first, separated by '/' or newlines) where 'Q' marks a queen and '.' or a digit run
+// marks empty cells.
+pub fn parse_board(input: &str) -> Result<Vec<u16>, BoardParseError> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(BoardParseError::Empty);
+    }
+
+    if input.starts_with('{') || input.starts_with('[') {
+        parse_json_board(input)
+    } else if input.chars().all(|character| {
+        character.is_ascii_digit() || character == ',' || character.is_whitespace()
+    }) {
+        parse_position_list(input)
+    } else {
+        parse_row_board(input)
+    }
+}
+
+fn parse_position_list(input: &str) -> Result<Vec<u16>, BoardParseError> {
+    input
+        .split(|character: char| character == ',' || character.is_whitespace())
+        .filter(|value| !value.is_empty())
+        .map(|value| value.parse().map_err(|_| BoardParseError::InvalidPosition))
+        .collect()
+}
+
+fn parse_json_board(input: &str) -> Result<Vec<u16>, BoardParseError> {
+    let value = serde_json::from_str::<serde_json::Value>(input)
+        .map_err(|_| BoardParseError::InvalidJson)?;
+    let positions = value
+        .pointer("/best_chromosome/positions")
+        .or_else(|| value.get("best_positions"))
+        .or_else(|| value.get("positions"))
+        .unwrap_or(&value)
+        .as_array()
+        .ok_or(BoardParseError::MissingPositions)?;
+
+    positions
+        .iter()
+        .map(|position| {
+            position
+                .as_u64()
+                .and_then(|position| u16::try_from(position).ok())
+                .ok_or(BoardParseError::InvalidPosition)
+        })
+        .collect()
+}
+
+fn parse_row_board(input: &str) -> Result<Vec<u16>, BoardParseError> {
+    let rows = input
+        .split(['/', '\n'])
+        .map(str::trim)
+        .filter(|row| !row.is_empty())
+        .map(parse_row)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let size = rows.len();
+    if rows.iter().any(|row| row.len() != size) {
+        return Err(BoardParseError::UnevenRows);
+    }
+
+    let mut positions = vec![None; size];
+    for (y, row) in rows.iter().enumerate() {
+        for (x, _) in row.iter().enumerate().filter(|(_, queen)| **queen) {
+            if positions[x].is_some() {
+                return Err(BoardParseError::CrowdedColumn);
+            }
+            positions[x] = Some(u16::try_from(y).map_err(|_| BoardParseError::InvalidPosition)?);
+        }
+    }
+
+    positions
+        .into_iter()
+        .map(|position| position.ok_or(BoardParseError::EmptyColumn))
+        .collect()
+}
+
+fn parse_row(row: &str) -> Result<Vec<bool>, BoardParseError> {
+    let mut cells = Vec::new();
+    let mut empty_run = String::new();
+    for character in row.chars().filter(|character| !character.is_whitespace()) {
+        if character.is_ascii_digit() {
+            empty_run.push(character);
+            continue;
+        }
+
+        push_empty_run(&mut cells, &mut empty_run)?;
+        match character {
+            'Q' | 'q' => cells.push(true),
+            '.' | '-' | '_' => cells.push(false),
+            _ => return Err(BoardParseError::InvalidCell),
+        }
+    }
+    push_empty_run(&mut cells, &mut empty_run)?;
+    Ok(cells)
+}
+
+fn push_empty_run(cells: &mut Vec<bool>, empty_run: &mut String) -> Result<(), BoardParseError> {
+    if empty_run.is_empty() {
+        return Ok(());
+    }
+
+    let count = empty_run
+        .parse::<u16>()
+        .map_err(|_| BoardParseError::InvalidPosition)?;
+    cells.extend(std::iter::repeat_n(false, usize::from(count)));
+    empty_run.clear();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BoardParseError, parse_board};
+
+    #[test]
+    fn test_parse_board_accepts_all_formats() {
+        let expected = vec![1, 3, 0, 2];
+
+        assert_eq!(parse_board("1,3,0,2"), Ok(expected.clone()));
+        assert_eq!(parse_board(" 1 3\n0 2 "), Ok(expected.clone()));
+        assert_eq!(parse_board("[1, 3, 0, 2]"), Ok(expected.clone()));
+        assert_eq!(
+            parse_board(r#"{"best_chromosome": {"positions": [1, 3, 0, 2]}}"#),
+            Ok(expected.clone())
+        );
+        assert_eq!(
+            parse_board(r#"{"event": "epoch", "best_positions": [1, 3, 0, 2]}"#),
+            Ok(expected.clone())
+        );
+        assert_eq!(parse_board("2Q1/Q3/3Q/1Q2"), Ok(expected.clone()));
+        assert_eq!(parse_board("..Q.\nQ...\n...Q\n.Q.."), Ok(expected));
+    }
+
+    #[test]
+    fn test_parse_board_rejects_malformed_input() {
+        assert_eq!(parse_board("  "), Err(BoardParseError::Empty));
+        assert_eq!(
+            parse_board("1,70000"),
+            Err(BoardParseError::InvalidPosition)
+        );
+        assert_eq!(parse_board("{"), Err(BoardParseError::InvalidJson));
+        assert_eq!(
+            parse_board(r#"{"seed": 1}"#),
+            Err(BoardParseError::MissingPositions)
+        );
+        assert_eq!(parse_board("QX/.Q"), Err(BoardParseError::InvalidCell));
+        assert_eq!(parse_board("Q./Q."), Err(BoardParseError::CrowdedColumn));
+        assert_eq!(parse_board("Q./.."), Err(BoardParseError::EmptyColumn));
+        assert_eq!(parse_board("Q./.Q/.."), Err(BoardParseError::UnevenRows));
+        assert_eq!(parse_board("QQ/.."), Ok(vec![0, 0]));
+    }
+}
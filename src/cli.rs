@@ -0,0 +1,672 @@
+pub mod bench;
+pub mod config;
+pub mod count;
+pub mod dashboard;
+pub mod portfolio;
+pub mod render;
+pub mod replay;
+pub mod solve;
+pub mod sweep;
+pub mod verify;
+
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use clap::{ArgAction, Args, Parser, Subcommand};
+use n_queens_problem::{
+    ga,
+    ga::chromosome::Chromosome,
+    render::{BoardExportFormat, RenderOptions, export_board},
+    symmetry::SymmetryConstraint,
+    tui::{self, BoardStyle, ColorMode, TerminalOptions},
+};
+use rand::RngExt;
+use simple_logger::SimpleLogger;
+
+pub const DEFAULT_BOARD_SIZE: u16 = 18;
+pub const DEFAULT_POPULATION_SIZE: usize = 40_000;
+pub const DEFAULT_MAX_EPOCHS: u32 = 5_000;
+pub const DEFAULT_MUTATION_RATE: f32 = ga::DEFAULT_MUTATION_RATE;
+pub const DEFAULT_ELITE_RATIO: f32 = ga::DEFAULT_ELITE_RATIO;
+pub const DEFAULT_OFFSPRING_RATIO: f32 = ga::DEFAULT_OFFSPRING_RATIO;
+pub const DEFAULT_MIN_DIVERSITY_RATIO: f32 = ga::DEFAULT_MIN_DIVERSITY_RATIO;
+pub const DEFAULT_DIVERSITY_MEASURE: ga::DiversityMeasure = ga::DEFAULT_DIVERSITY_MEASURE;
+pub const DEFAULT_SELECTION_STRATEGY: ga::SelectionStrategy = ga::DEFAULT_SELECTION_STRATEGY;
+pub const DEFAULT_TOURNAMENT_SIZE: usize = ga::DEFAULT_TOURNAMENT_SIZE;
+pub const DEFAULT_LOCAL_SEARCH_RATE: f32 = ga::DEFAULT_LOCAL_SEARCH_RATE;
+pub const DEFAULT_LOCAL_SEARCH_ATTEMPTS: usize = ga::DEFAULT_LOCAL_SEARCH_ATTEMPTS;
+pub const DEFAULT_SYMMETRY_CONSTRAINT: SymmetryConstraint = ga::DEFAULT_SYMMETRY_CONSTRAINT;
+pub const DEFAULT_RESTART_KEEP_RATIO: f32 = ga::DEFAULT_RESTART_KEEP_RATIO;
+pub const DEFAULT_RESTART_KIND: ga::RestartKind = ga::DEFAULT_RESTART_KIND;
+pub const DEFAULT_PARAMETER_CONTROL: ga::control::ParameterControl = ga::DEFAULT_PARAMETER_CONTROL;
+
+#[derive(Debug, Parser)]
+#[command(name = "n_queens_problem")]
+#[command(version)]
+#[command(about = "N-Queens genetic solver")]
+#[command(args_conflicts_with_subcommands = true)]
+#[command(
+    after_help = "Runs `solve` when no subcommand is given.\n\nExamples:\n  cargo run --release\n  cargo run --release -- -n 18 -p 40000 -e 5000 -s 42 -m 0.08 -r 0.10 -o 0.10 --local-search-rate 0.05\n  cargo run --release -- portfolio -n 64\n  cargo run --release -- verify 1,3,0,2\n  cargo run --release -- count -n 8 --fundamental"
+)]
+pub struct Cli {
+    #[command(flatten)]
+    pub common: CommonOptions,
+    #[command(subcommand)]
+    pub command: Option<CliCommand>,
+    #[command(flatten)]
+    pub solve_config: solve::SolveConfig,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum CliCommand {
+    #[command(about = "Run the genetic solver (default)")]
+    Solve(solve::SolveConfig),
+    #[command(
+        about = "Check a board for validity and conflicts",
+        after_help = "Exit status: 0 for a solution, 1 for a valid board with conflicts, 2 for malformed input."
+    )]
+    Verify(verify::VerifyConfig),
+    #[command(about = "Count solutions exactly by backtracking")]
+    Count(count::CountConfig),
+    #[command(about = "Draw a board given as positions, FEN-like rows, or JSON")]
+    Render(render::RenderConfig),
+    #[command(
+        about = "Replay a run log recorded with --record",
+        after_help = "Example:\n  cargo run --release -- -n 12 -s 7 --record run.ndjson\n  cargo run --release -- replay run.ndjson --tui"
+    )]
+    Replay(replay::ReplayConfig),
+    #[command(
+        about = "Run GA parameter sweeps across multiple seeds",
+        after_help = "Example:\n  cargo run --release -- sweep --sizes 18 --populations 40000 --epochs 5000 --seeds 20 --mutation-rates 0.06,0.08 --elite-ratios 0.05,0.10 --offspring-ratios 0.05,0.10 --min-diversity-ratios 0.05,0.10 --selection-strategies roulette,tournament --tournament-sizes 3,5 --local-search-rates 0,0.05 --local-search-attempts 8"
+    )]
+    Sweep(sweep::SweepConfig),
+    #[command(about = "Time repeated solver runs for one configuration")]
+    Bench(bench::BenchConfig),
+    #[command(
+        about = "Race the GA, min-conflicts, annealing, and backtracking until one solves the board",
+        after_help = "Example:\n  cargo run --release -- portfolio -n 200 --time-limit 30"
+    )]
+    Portfolio(portfolio::PortfolioConfig),
+}
+
+#[derive(Debug, Args)]
+pub struct CommonOptions {
+    #[arg(
+        long = "log-level",
+        value_name = "LEVEL",
+        global = true,
+        value_parser = parse_log_level,
+        help = "Log level: off, error, warn, info, debug, or trace [default: info for solve, off otherwise]"
+    )]
+    pub log_level: Option<log::LevelFilter>,
+    #[arg(
+        long = "quiet",
+        global = true,
+        action = ArgAction::SetTrue,
+        help = "Suppress log output"
+    )]
+    pub quiet: bool,
+    #[arg(
+        long = "json",
+        global = true,
+        action = ArgAction::SetTrue,
+        help = "Print machine-readable JSON output"
+    )]
+    pub json_output: bool,
+}
+
+impl CommonOptions {
+    pub fn init_logging(&self, default_level: log::LevelFilter) {
+        let log_level = if self.quiet || self.json_output {
+            log::LevelFilter::Off
+        } else {
+            self.log_level.unwrap_or(default_level)
+        };
+
+        SimpleLogger::new().with_level(log_level).init().unwrap();
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct GaOptions {
+    #[arg(
+        long = "config",
+        value_name = "PATH",
+        help = "Load options from a TOML or JSON file (including a --json summary); command-line flags take precedence"
+    )]
+    pub config: Option<PathBuf>,
+    #[arg(
+        long = "dump-config",
+        action = ArgAction::SetTrue,
+        help = "Print the effective configuration as TOML (JSON with --json) and exit"
+    )]
+    pub dump_config: bool,
+    #[arg(
+        short = 'n',
+        long = "size",
+        value_name = "SIZE",
+        default_value_t = DEFAULT_BOARD_SIZE,
+        value_parser = parse_positive_u16,
+        help = "Board size (number of queens, must be greater than 0)"
+    )]
+    pub board_size: u16,
+    #[arg(
+        short = 'p',
+        long = "population",
+        value_name = "COUNT",
+        default_value_t = DEFAULT_POPULATION_SIZE,
+        value_parser = parse_positive_usize,
+        help = "Initial and target population size"
+    )]
+    pub population_size: usize,
+    #[arg(
+        short = 'e',
+        long = "epochs",
+        value_name = "COUNT",
+        default_value_t = DEFAULT_MAX_EPOCHS,
+        value_parser = parse_positive_u32,
+        help = "Maximum GA epochs"
+    )]
+    pub max_epochs: u32,
+    #[arg(
+        long = "max-evaluations",
+        value_name = "COUNT",
+        value_parser = parse_positive_u64,
+        help = "Stop once this many fitness evaluations (chromosome builds and swap deltas) have run"
+    )]
+    pub max_evaluations: Option<u64>,
+    #[arg(
+        short = 's',
+        long = "seed",
+        value_name = "U64",
+        help = "Deterministic RNG seed"
+    )]
+    pub seed: Option<u64>,
+    #[arg(
+        short = 'm',
+        long = "mutation-rate",
+        value_name = "0..1",
+        default_value_t = DEFAULT_MUTATION_RATE,
+        value_parser = parse_unit_interval,
+        help = "Mutation probability"
+    )]
+    pub mutation_rate: f32,
+    #[arg(
+        short = 'r',
+        long = "elite-ratio",
+        value_name = "0..1",
+        default_value_t = DEFAULT_ELITE_RATIO,
+        value_parser = parse_unit_interval,
+        help = "Elite survivor ratio"
+    )]
+    pub elite_ratio: f32,
+    #[arg(
+        short = 'o',
+        long = "offspring-ratio",
+        value_name = "0..1",
+        default_value_t = DEFAULT_OFFSPRING_RATIO,
+        value_parser = parse_unit_interval,
+        help = "Fraction of target population produced as offspring each epoch"
+    )]
+    pub offspring_ratio: f32,
+    #[arg(
+        long = "min-diversity-ratio",
+        value_name = "0..1",
+        default_value_t = DEFAULT_MIN_DIVERSITY_RATIO,
+        value_parser = parse_unit_interval,
+        help = "Minimum population diversity, as measured by --diversity-measure, before random refresh"
+    )]
+    pub min_diversity_ratio: f32,
+    #[arg(
+        long = "diversity-measure",
+        value_name = "unique|entropy|pairwise|best-distance",
+        default_value_t = DEFAULT_DIVERSITY_MEASURE,
+        value_parser = parse_diversity_measure,
+        help = "Diversity measure compared against --min-diversity-ratio"
+    )]
+    pub diversity_measure: ga::DiversityMeasure,
+    #[arg(
+        long = "selection",
+        value_name = "roulette|tournament",
+        default_value_t = DEFAULT_SELECTION_STRATEGY,
+        value_parser = parse_selection_strategy,
+        help = "Parent selection strategy"
+    )]
+    pub selection_strategy: ga::SelectionStrategy,
+    #[arg(
+        long = "tournament-size",
+        value_name = "COUNT",
+        default_value_t = DEFAULT_TOURNAMENT_SIZE,
+        value_parser = parse_positive_usize,
+        help = "Candidate count for tournament selection"
+    )]
+    pub tournament_size: usize,
+    #[arg(
+        long = "local-search-rate",
+        value_name = "0..1",
+        default_value_t = DEFAULT_LOCAL_SEARCH_RATE,
+        value_parser = parse_unit_interval,
+        help = "Fraction of non-elite chromosomes improved with local search each epoch"
+    )]
+    pub local_search_rate: f32,
+    #[arg(
+        long = "local-search-attempts",
+        value_name = "COUNT",
+        default_value_t = DEFAULT_LOCAL_SEARCH_ATTEMPTS,
+        value_parser = parse_usize,
+        help = "Random improving swaps attempted per selected chromosome"
+    )]
+    pub local_search_attempts: usize,
+    #[arg(
+        long = "symmetry",
+        value_name = "none|rot180|rot90|reflection",
+        default_value_t = DEFAULT_SYMMETRY_CONSTRAINT,
+        value_parser = parse_symmetry_constraint,
+        help = "Only search boards invariant under this symmetry (reflection only for a one-queen board)"
+    )]
+    pub symmetry: SymmetryConstraint,
+    #[arg(
+        long = "count-symmetry-classes",
+        action = ArgAction::SetTrue,
+        help = "Also count unique chromosomes modulo board rotations and reflections"
+    )]
+    pub count_symmetry_classes: bool,
+    #[arg(
+        long = "restart-interval",
+        value_name = "EPOCHS",
+        value_parser = parse_positive_u32,
+        help = "Stagnant epochs before a soft restart [default: scaled with --epochs]"
+    )]
+    pub restart_interval: Option<u32>,
+    #[arg(
+        long = "restart-keep-ratio",
+        value_name = "0..1",
+        default_value_t = DEFAULT_RESTART_KEEP_RATIO,
+        value_parser = parse_unit_interval,
+        help = "Fraction of the elites kept through a soft restart"
+    )]
+    pub restart_keep_ratio: f32,
+    #[arg(
+        long = "restart-kind",
+        value_name = "random|perturb-best|constructive",
+        default_value_t = DEFAULT_RESTART_KIND,
+        value_parser = parse_restart_kind,
+        help = "How a soft restart generates replacement chromosomes"
+    )]
+    pub restart_kind: ga::RestartKind,
+    #[arg(
+        long = "no-adaptation",
+        action = ArgAction::SetFalse,
+        default_value_t = true,
+        help = "Keep the mutation rate, elite ratio and operators fixed instead of adapting them"
+    )]
+    pub adaptive: bool,
+    #[arg(
+        long = "parameter-control",
+        value_name = "stagnation|one-fifth|self-adaptive|bandit",
+        default_value_t = DEFAULT_PARAMETER_CONTROL,
+        value_parser = parse_parameter_control,
+        help = "How the mutation rate, elite ratio and operators adapt during the run"
+    )]
+    pub parameter_control: ga::control::ParameterControl,
+}
+
+impl GaOptions {
+    pub fn seed_or_random(&self) -> u64 {
+        self.seed.unwrap_or_else(|| rand::rng().random::<u64>())
+    }
+
+    pub fn to_ga_config(&self, seed: u64) -> Result<ga::GaConfig, ga::GaConfigError> {
+        ga::GaConfig::new(self.board_size, self.population_size, self.max_epochs, seed)
+            .with_max_evaluations(self.max_evaluations)
+            .with_mutation_rate(self.mutation_rate)
+            .with_elite_ratio(self.elite_ratio)
+            .with_offspring_ratio(self.offspring_ratio)
+            .with_min_diversity_ratio(self.min_diversity_ratio)
+            .with_diversity_measure(self.diversity_measure)
+            .with_selection_strategy(self.selection_strategy)
+            .with_tournament_size(self.tournament_size)
+            .with_local_search_rate(self.local_search_rate)
+            .with_local_search_attempts(self.local_search_attempts)
+            .with_symmetry(self.symmetry)
+            .with_symmetry_class_counting(self.count_symmetry_classes)
+            .with_restart_policy(
+                ga::RestartPolicy::default()
+                    .with_interval(self.restart_interval)
+                    .with_keep_ratio(self.restart_keep_ratio)
+                    .with_kind(self.restart_kind)
+                    .with_adaptation(self.adaptive),
+            )
+            .with_parameter_control(self.parameter_control)
+            .validated()
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct BoardInput {
+    #[arg(
+        value_name = "BOARD",
+        help = "Board as a position list (1,3,0,2), FEN-like rows (2Q1/Q3/3Q/1Q2), or --json output; read from stdin when omitted"
+    )]
+    pub board: Option<String>,
+    #[arg(
+        long = "file",
+        value_name = "PATH",
+        conflicts_with = "board",
+        help = "Read the board from a file"
+    )]
+    pub file: Option<PathBuf>,
+}
+
+impl BoardInput {
+    pub fn read(&self) -> Result<String, String> {
+        if let Some(board) = &self.board {
+            return Ok(board.clone());
+        }
+
+        if let Some(path) = &self.file {
+            return fs::read_to_string(path)
+                .map_err(|error| format!("failed to read board file {}: {error}", path.display()));
+        }
+
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|error| format!("failed to read board from stdin: {error}"))?;
+        Ok(input)
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct ExportOptions {
+    #[arg(
+        long = "export-board",
+        value_name = "PATH",
+        value_parser = parse_export_path,
+        help = "Export the board as SVG, PNG, LaTeX (.tex), or HTML, chosen by file extension"
+    )]
+    pub export_board: Option<PathBuf>,
+    #[arg(
+        long = "export-attack-lines",
+        action = ArgAction::SetTrue,
+        help = "Draw lines between attacking queens in the exported board"
+    )]
+    pub export_attack_lines: bool,
+}
+
+impl ExportOptions {
+    pub fn export(&self, chromosome: &Chromosome) -> Result<Option<&Path>, String> {
+        let Some(path) = self.export_board.as_deref() else {
+            return Ok(None);
+        };
+
+        let options = RenderOptions::for_board_size(chromosome.get_positions().len())
+            .with_attack_lines(self.export_attack_lines);
+        export_board(path, chromosome, options)
+            .map_err(|error| format!("failed to export board to {}: {error}", path.display()))?;
+        Ok(Some(path))
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct BoardDisplayOptions {
+    #[arg(
+        long = "board-style",
+        value_name = "STYLE",
+        default_value_t = BoardStyle::Auto,
+        value_parser = parse_board_style,
+        help = "Terminal board style: auto, boxed, compact, braille, half-block, or window"
+    )]
+    pub board_style: BoardStyle,
+    #[arg(
+        long = "color",
+        value_name = "WHEN",
+        default_value_t = ColorMode::Auto,
+        value_parser = parse_color_mode,
+        help = "Color queens by conflict count: auto, always, or never"
+    )]
+    pub color: ColorMode,
+    #[arg(
+        long = "board-width",
+        value_name = "COLUMNS",
+        value_parser = parse_positive_usize,
+        help = "Fit the board into this many columns instead of the detected terminal width"
+    )]
+    pub board_width: Option<usize>,
+}
+
+impl BoardDisplayOptions {
+    pub fn terminal_options(&self) -> TerminalOptions {
+        let width = self.board_width.unwrap_or_else(tui::terminal_width);
+        TerminalOptions::default()
+            .with_style(self.board_style)
+            .with_width(width)
+            .with_color(self.color.enabled())
+    }
+}
+
+pub fn seed_for_offset(first_seed: u64, seed_offset: usize) -> Result<u64, String> {
+    u64::try_from(seed_offset)
+        .ok()
+        .and_then(|seed_offset| first_seed.checked_add(seed_offset))
+        .ok_or_else(|| "seed range overflows u64".to_owned())
+}
+
+pub fn median_u32(values: &mut [u32]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+
+    values.sort_unstable();
+    let middle = values.len() / 2;
+    if values.len() % 2 == 1 {
+        Some(f64::from(values[middle]))
+    } else {
+        Some((f64::from(values[middle - 1]) + f64::from(values[middle])) / 2.0)
+    }
+}
+
+pub fn median_u128(values: &mut [u128]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+
+    values.sort_unstable();
+    let middle = values.len() / 2;
+    if values.len() % 2 == 1 {
+        Some(values[middle] as f64)
+    } else {
+        Some((values[middle - 1] as f64 + values[middle] as f64) / 2.0)
+    }
+}
+
+pub fn parse_positive_usize(raw_value: &str) -> Result<usize, String> {
+    let value = raw_value
+        .parse::<usize>()
+        .map_err(|err| format!("invalid value `{raw_value}`: {err}"))?;
+
+    if value == 0 {
+        return Err("must be greater than 0".to_owned());
+    }
+
+    Ok(value)
+}
+
+pub fn parse_positive_u16(raw_value: &str) -> Result<u16, String> {
+    let value = raw_value
+        .parse::<u16>()
+        .map_err(|err| format!("invalid value `{raw_value}`: {err}"))?;
+
+    if value == 0 {
+        return Err("must be greater than 0".to_owned());
+    }
+
+    Ok(value)
+}
+
+pub fn parse_usize(raw_value: &str) -> Result<usize, String> {
+    raw_value
+        .parse::<usize>()
+        .map_err(|err| format!("invalid value `{raw_value}`: {err}"))
+}
+
+pub fn parse_positive_u32(raw_value: &str) -> Result<u32, String> {
+    let value = raw_value
+        .parse::<u32>()
+        .map_err(|err| format!("invalid value `{raw_value}`: {err}"))?;
+
+    if value == 0 {
+        return Err("must be greater than 0".to_owned());
+    }
+
+    Ok(value)
+}
+
+pub fn parse_positive_u64(raw_value: &str) -> Result<u64, String> {
+    let value = raw_value
+        .parse::<u64>()
+        .map_err(|err| format!("invalid value `{raw_value}`: {err}"))?;
+
+    if value == 0 {
+        return Err("must be greater than 0".to_owned());
+    }
+
+    Ok(value)
+}
+
+pub fn parse_unit_interval(raw_value: &str) -> Result<f32, String> {
+    let value = raw_value
+        .parse::<f32>()
+        .map_err(|err| format!("invalid value `{raw_value}`: {err}"))?;
+
+    if !value.is_finite() || !(0.0..=1.0).contains(&value) {
+        return Err("must be between 0.0 and 1.0".to_owned());
+    }
+
+    Ok(value)
+}
+
+pub fn parse_seconds(raw_value: &str) -> Result<Duration, String> {
+    let value = raw_value
+        .parse::<f64>()
+        .map_err(|err| format!("invalid value `{raw_value}`: {err}"))?;
+
+    if !value.is_finite() || value <= 0.0 {
+        return Err("must be a number of seconds greater than 0".to_owned());
+    }
+
+    Duration::try_from_secs_f64(value).map_err(|err| format!("invalid value `{raw_value}`: {err}"))
+}
+
+pub fn parse_diversity_measure(raw_value: &str) -> Result<ga::DiversityMeasure, String> {
+    match raw_value.to_ascii_lowercase().as_str() {
+        "unique" => Ok(ga::DiversityMeasure::UniqueRatio),
+        "entropy" => Ok(ga::DiversityMeasure::GeneEntropy),
+        "pairwise" => Ok(ga::DiversityMeasure::PairwiseDistance),
+        "best-distance" => Ok(ga::DiversityMeasure::BestDistance),
+        _ => Err("must be one of: unique, entropy, pairwise, best-distance".to_owned()),
+    }
+}
+
+pub fn parse_restart_kind(raw_value: &str) -> Result<ga::RestartKind, String> {
+    match raw_value.to_ascii_lowercase().as_str() {
+        "random" => Ok(ga::RestartKind::Random),
+        "perturb-best" => Ok(ga::RestartKind::PerturbBest),
+        "constructive" => Ok(ga::RestartKind::Constructive),
+        _ => Err("must be one of: random, perturb-best, constructive".to_owned()),
+    }
+}
+
+pub fn parse_parameter_control(raw_value: &str) -> Result<ga::control::ParameterControl, String> {
+    match raw_value.to_ascii_lowercase().as_str() {
+        "stagnation" => Ok(ga::control::ParameterControl::Stagnation),
+        "one-fifth" => Ok(ga::control::ParameterControl::OneFifthRule),
+        "self-adaptive" => Ok(ga::control::ParameterControl::SelfAdaptive),
+        "bandit" => Ok(ga::control::ParameterControl::OperatorBandit),
+        _ => Err("must be one of: stagnation, one-fifth, self-adaptive, bandit".to_owned()),
+    }
+}
+
+pub fn parse_strategy(raw_value: &str) -> Result<n_queens_problem::portfolio::Strategy, String> {
+    use n_queens_problem::portfolio::Strategy;
+
+    match raw_value.to_ascii_lowercase().as_str() {
+        "ga" => Ok(Strategy::GeneticAlgorithm),
+        "min-conflicts" => Ok(Strategy::MinConflicts),
+        "annealing" => Ok(Strategy::Annealing),
+        "backtracking" => Ok(Strategy::Backtracking),
+        _ => Err("must be one of: ga, min-conflicts, annealing, backtracking".to_owned()),
+    }
+}
+
+pub fn parse_selection_strategy(raw_value: &str) -> Result<ga::SelectionStrategy, String> {
+    match raw_value.to_ascii_lowercase().as_str() {
+        "roulette" => Ok(ga::SelectionStrategy::Roulette),
+        "tournament" => Ok(ga::SelectionStrategy::Tournament),
+        _ => Err("must be one of: roulette, tournament".to_owned()),
+    }
+}
+
+pub fn parse_symmetry_constraint(raw_value: &str) -> Result<SymmetryConstraint, String> {
+    match raw_value.to_ascii_lowercase().as_str() {
+        "none" => Ok(SymmetryConstraint::None),
+        "rot180" => Ok(SymmetryConstraint::Rotation180),
+        "rot90" => Ok(SymmetryConstraint::Rotation90),
+        "reflection" => Ok(SymmetryConstraint::Reflection),
+        _ => Err("must be one of: none, rot180, rot90, reflection".to_owned()),
+    }
+}
+
+pub fn parse_board_style(raw_value: &str) -> Result<BoardStyle, String> {
+    match raw_value.to_ascii_lowercase().as_str() {
+        "auto" => Ok(BoardStyle::Auto),
+        "boxed" => Ok(BoardStyle::Boxed),
+        "compact" => Ok(BoardStyle::Compact),
+        "braille" => Ok(BoardStyle::Braille),
+        "half-block" | "halfblock" => Ok(BoardStyle::HalfBlock),
+        "window" => Ok(BoardStyle::Window),
+        _ => Err("must be one of: auto, boxed, compact, braille, half-block, window".to_owned()),
+    }
+}
+
+pub fn parse_color_mode(raw_value: &str) -> Result<ColorMode, String> {
+    match raw_value.to_ascii_lowercase().as_str() {
+        "auto" => Ok(ColorMode::Auto),
+        "always" => Ok(ColorMode::Always),
+        "never" => Ok(ColorMode::Never),
+        _ => Err("must be one of: auto, always, never".to_owned()),
+    }
+}
+
+pub fn parse_export_path(raw_value: &str) -> Result<PathBuf, String> {
+    let path = PathBuf::from(raw_value);
+    if BoardExportFormat::from_path(&path).is_none() {
+        return Err("must end in .svg, .png, .tex, .html, or .htm".to_owned());
+    }
+
+    Ok(path)
+}
+
+pub fn parse_progress_format(raw_value: &str) -> Result<solve::ProgressFormat, String> {
+    match raw_value.to_ascii_lowercase().as_str() {
+        "none" => Ok(solve::ProgressFormat::None),
+        "ndjson" => Ok(solve::ProgressFormat::Ndjson),
+        _ => Err("must be one of: none, ndjson".to_owned()),
+    }
+}
+
+pub fn parse_log_level(raw_value: &str) -> Result<log::LevelFilter, String> {
+    match raw_value.to_ascii_lowercase().as_str() {
+        "off" => Ok(log::LevelFilter::Off),
+        "error" => Ok(log::LevelFilter::Error),
+        "warn" | "warning" => Ok(log::LevelFilter::Warn),
+        "info" => Ok(log::LevelFilter::Info),
+        "debug" => Ok(log::LevelFilter::Debug),
+        "trace" => Ok(log::LevelFilter::Trace),
+        _ => Err("must be one of: off, error, warn, info, debug, trace".to_owned()),
+    }
+}
@@ -0,0 +1,121 @@
+use clap::Args;
+use n_queens_problem::ga;
+use serde_json::json;
+
+use super::{CommonOptions, GaOptions, config, median_u128, parse_positive_usize, seed_for_offset};
+
+const DEFAULT_BENCH_REPEAT: usize = 5;
+
+#[derive(Debug, Args)]
+pub struct BenchConfig {
+    #[command(flatten)]
+    pub ga: GaOptions,
+    #[arg(
+        long = "repeat",
+        value_name = "COUNT",
+        default_value_t = DEFAULT_BENCH_REPEAT,
+        value_parser = parse_positive_usize,
+        help = "Number of consecutive seeds to time"
+    )]
+    pub repeat: usize,
+}
+
+struct BenchRun {
+    seed: u64,
+    solved_epoch: Option<u32>,
+    epochs_run: usize,
+    elapsed_ms: u128,
+    best_conflicts_sum: u32,
+}
+
+impl BenchRun {
+    fn epochs_per_second(&self) -> f64 {
+        self.epochs_run as f64 * 1_000.0 / self.elapsed_ms.max(1) as f64
+    }
+}
+
+pub fn run(bench_config: &BenchConfig, common: &CommonOptions) -> Result<(), String> {
+    if bench_config.ga.dump_config {
+        print!("{}", config::dump_config(&bench_config.ga, None, common)?);
+        return Ok(());
+    }
+
+    let first_seed = bench_config.ga.seed_or_random();
+    let mut runs = Vec::with_capacity(bench_config.repeat);
+
+    for offset in 0..bench_config.repeat {
+        let seed = seed_for_offset(first_seed, offset)?;
+        let ga_config = bench_config
+            .ga
+            .to_ga_config(seed)
+            .map_err(|error| format!("invalid GA config: {error}"))?;
+        let mut genetic_algorithm = ga::build_genetic_algorithm(ga_config)
+            .map_err(|error| format!("invalid GA config: {error}"))?;
+        let run_metrics = genetic_algorithm.run_algorithm();
+        let run = BenchRun {
+            seed,
+            solved_epoch: run_metrics.solved_epoch(),
+            epochs_run: run_metrics.epochs().len(),
+            elapsed_ms: run_metrics.total_elapsed_ms(),
+            best_conflicts_sum: genetic_algorithm.get_best_chromosome().get_conflicts_sum(),
+        };
+
+        if !common.json_output {
+            let outcome = match run.solved_epoch {
+                Some(solved_epoch) => format!("solved at epoch {solved_epoch}"),
+                None => format!("no solution, best conflicts {}", run.best_conflicts_sum),
+            };
+            println!(
+                "Seed {seed}: {outcome}, {} ms, {:.1} epochs/s",
+                run.elapsed_ms,
+                run.epochs_per_second()
+            );
+        }
+        runs.push(run);
+    }
+
+    let solved_runs = runs.iter().filter(|run| run.solved_epoch.is_some()).count();
+    let mut elapsed_values = runs.iter().map(|run| run.elapsed_ms).collect::<Vec<_>>();
+    let min_elapsed_ms = elapsed_values.iter().copied().min().unwrap_or_default();
+    let max_elapsed_ms = elapsed_values.iter().copied().max().unwrap_or_default();
+    let median_elapsed_ms = median_u128(&mut elapsed_values).unwrap_or_default();
+    let mean_epochs_per_second =
+        runs.iter().map(BenchRun::epochs_per_second).sum::<f64>() / runs.len() as f64;
+
+    if common.json_output {
+        let summary = json!({
+            "board_size": bench_config.ga.board_size,
+            "target_population": bench_config.ga.population_size,
+            "max_epochs": bench_config.ga.max_epochs,
+            "runs": runs
+                .iter()
+                .map(|run| {
+                    json!({
+                        "seed": run.seed,
+                        "solved_epoch": run.solved_epoch,
+                        "epochs_run": run.epochs_run,
+                        "elapsed_ms": run.elapsed_ms,
+                        "best_conflicts_sum": run.best_conflicts_sum,
+                    })
+                })
+                .collect::<Vec<_>>(),
+            "solved_runs": solved_runs,
+            "min_elapsed_ms": min_elapsed_ms,
+            "median_elapsed_ms": median_elapsed_ms,
+            "max_elapsed_ms": max_elapsed_ms,
+            "mean_epochs_per_second": mean_epochs_per_second,
+        });
+        serde_json::to_writer_pretty(std::io::stdout(), &summary)
+            .map_err(|error| format!("failed to write JSON summary: {error}"))?;
+        println!();
+        return Ok(());
+    }
+
+    println!("--------------------------------");
+    println!("Solved: {solved_runs} of {} runs", runs.len());
+    println!(
+        "Elapsed (ms): min {min_elapsed_ms}, median {median_elapsed_ms:.1}, max {max_elapsed_ms}"
+    );
+    println!("Mean Epochs/s: {mean_epochs_per_second:.1}");
+    Ok(())
+}
@@ -0,0 +1,575 @@
+use std::{fs, path::Path};
+
+use clap::{ArgMatches, parser::ValueSource};
+use n_queens_problem::ga::run_log::json_f32;
+use serde_json::{Map, Value, json};
+
+use super::{
+    CommonOptions, GaOptions, bench::BenchConfig, parse_board_style, parse_color_mode,
+    parse_diversity_measure, parse_export_path, parse_log_level, parse_parameter_control,
+    parse_positive_u16, parse_positive_u32, parse_positive_u64, parse_positive_usize,
+    parse_progress_format, parse_restart_kind, parse_selection_strategy, parse_symmetry_constraint,
+    parse_unit_interval, parse_usize, portfolio::PortfolioConfig, solve::SolveConfig,
+};
+
+const GA_OPTION_KEYS: &[&str] = &[
+    "seed",
+    "board_size",
+    "target_population",
+    "max_epochs",
+    "max_evaluations",
+    "mutation_rate",
+    "elite_ratio",
+    "offspring_ratio",
+    "min_diversity_ratio",
+    "diversity_measure",
+    "selection_strategy",
+    "tournament_size",
+    "local_search_rate",
+    "local_search_attempts",
+    "symmetry",
+    "count_symmetry_classes",
+    "restart_interval",
+    "restart_keep_ratio",
+    "restart_kind",
+    "adaptive",
+    "parameter_control",
+];
+
+const COMMON_OPTION_KEYS: &[&str] = &["json", "quiet", "log_level"];
+
+const SOLVE_OUTPUT_KEYS: &[&str] = &[
+    "runs",
+    "draw_board",
+    "metrics_csv",
+    "record",
+    "progress_format",
+    "tui",
+    "board_style",
+    "color",
+    "board_width",
+    "export_board",
+    "export_attack_lines",
+];
+
+// Keys written by the JSON run summary that describe the outcome rather than the configuration,
+// so a summary can be passed back to `--config` unchanged.
+const SUMMARY_RESULT_KEYS: &[&str] = &[
+    "event",
+    "solved_runs",
+    "distinct_solutions",
+    "final_population",
+    "final_unique_chromosomes",
+    "final_diversity_ratio",
+    "final_unique_symmetry_classes",
+    "final_gene_entropy",
+    "final_mean_pairwise_distance",
+    "final_mean_best_distance",
+    "last_local_search_improvements",
+    "last_diversity_replacements",
+    "elapsed_ms",
+    "phase_us",
+    "evaluations",
+    "evaluation_budget_reached",
+    "solved_epoch",
+    "best_chromosome",
+    "best_symmetry_group",
+    "worst_chromosome",
+];
+
+struct ConfigValues<'a> {
+    values: Map<String, Value>,
+    matches: &'a ArgMatches,
+    common_matches: &'a ArgMatches,
+}
+
+impl ConfigValues<'_> {
+    fn set<T>(
+        &self,
+        key: &str,
+        arg_id: &str,
+        target: &mut T,
+        parse: impl Fn(&str) -> Result<T, String>,
+    ) -> Result<(), String> {
+        self.set_from(self.matches, key, arg_id, target, parse)
+    }
+
+    fn set_common<T>(
+        &self,
+        key: &str,
+        arg_id: &str,
+        target: &mut T,
+        parse: impl Fn(&str) -> Result<T, String>,
+    ) -> Result<(), String> {
+        self.set_from(self.common_matches, key, arg_id, target, parse)
+    }
+
+    fn set_from<T>(
+        &self,
+        matches: &ArgMatches,
+        key: &str,
+        arg_id: &str,
+        target: &mut T,
+        parse: impl Fn(&str) -> Result<T, String>,
+    ) -> Result<(), String> {
+        if matches.value_source(arg_id) == Some(ValueSource::CommandLine) {
+            return Ok(());
+        }
+
+        let Some(value) = self.values.get(key).filter(|value| !value.is_null()) else {
+            return Ok(());
+        };
+        let raw_value = match value {
+            Value::String(value) => value.clone(),
+            Value::Number(_) | Value::Bool(_) => value.to_string(),
+            _ => {
+                return Err(format!(
+                    "config key `{key}` must be a string, number, or boolean"
+                ));
+            }
+        };
+
+        *target = parse(&raw_value).map_err(|error| format!("invalid config `{key}`: {error}"))?;
+        Ok(())
+    }
+
+    fn apply_ga_options(&self, ga: &mut GaOptions) -> Result<(), String> {
+        self.set("seed", "seed", &mut ga.seed, |value| {
+            parse_u64(value).map(Some)
+        })?;
+        self.set(
+            "board_size",
+            "board_size",
+            &mut ga.board_size,
+            parse_positive_u16,
+        )?;
+        self.set(
+            "target_population",
+            "population_size",
+            &mut ga.population_size,
+            parse_positive_usize,
+        )?;
+        self.set(
+            "max_epochs",
+            "max_epochs",
+            &mut ga.max_epochs,
+            parse_positive_u32,
+        )?;
+        self.set(
+            "max_evaluations",
+            "max_evaluations",
+            &mut ga.max_evaluations,
+            |value| parse_positive_u64(value).map(Some),
+        )?;
+        self.set(
+            "mutation_rate",
+            "mutation_rate",
+            &mut ga.mutation_rate,
+            parse_unit_interval,
+        )?;
+        self.set(
+            "elite_ratio",
+            "elite_ratio",
+            &mut ga.elite_ratio,
+            parse_unit_interval,
+        )?;
+        self.set(
+            "offspring_ratio",
+            "offspring_ratio",
+            &mut ga.offspring_ratio,
+            parse_unit_interval,
+        )?;
+        self.set(
+            "min_diversity_ratio",
+            "min_diversity_ratio",
+            &mut ga.min_diversity_ratio,
+            parse_unit_interval,
+        )?;
+        self.set(
+            "diversity_measure",
+            "diversity_measure",
+            &mut ga.diversity_measure,
+            parse_diversity_measure,
+        )?;
+        self.set(
+            "selection_strategy",
+            "selection_strategy",
+            &mut ga.selection_strategy,
+            parse_selection_strategy,
+        )?;
+        self.set(
+            "tournament_size",
+            "tournament_size",
+            &mut ga.tournament_size,
+            parse_positive_usize,
+        )?;
+        self.set(
+            "local_search_rate",
+            "local_search_rate",
+            &mut ga.local_search_rate,
+            parse_unit_interval,
+        )?;
+        self.set(
+            "local_search_attempts",
+            "local_search_attempts",
+            &mut ga.local_search_attempts,
+            parse_usize,
+        )?;
+        self.set(
+            "symmetry",
+            "symmetry",
+            &mut ga.symmetry,
+            parse_symmetry_constraint,
+        )?;
+        self.set(
+            "count_symmetry_classes",
+            "count_symmetry_classes",
+            &mut ga.count_symmetry_classes,
+            parse_bool,
+        )?;
+        self.set(
+            "restart_interval",
+            "restart_interval",
+            &mut ga.restart_interval,
+            |value| parse_positive_u32(value).map(Some),
+        )?;
+        self.set(
+            "restart_keep_ratio",
+            "restart_keep_ratio",
+            &mut ga.restart_keep_ratio,
+            parse_unit_interval,
+        )?;
+        self.set(
+            "restart_kind",
+            "restart_kind",
+            &mut ga.restart_kind,
+            parse_restart_kind,
+        )?;
+        self.set("adaptive", "adaptive", &mut ga.adaptive, parse_bool)?;
+        self.set(
+            "parameter_control",
+            "parameter_control",
+            &mut ga.parameter_control,
+            parse_parameter_control,
+        )
+    }
+
+    fn apply_common_options(&self, common: &mut CommonOptions) -> Result<(), String> {
+        self.set_common("json", "json_output", &mut common.json_output, parse_bool)?;
+        self.set_common("quiet", "quiet", &mut common.quiet, parse_bool)?;
+        self.set_common("log_level", "log_level", &mut common.log_level, |value| {
+            parse_log_level(value).map(Some)
+        })
+    }
+}
+
+pub fn apply_to_solve(
+    solve_config: &mut SolveConfig,
+    common: &mut CommonOptions,
+    matches: &ArgMatches,
+    common_matches: &ArgMatches,
+) -> Result<(), String> {
+    let Some(config_values) =
+        load_config_values(solve_config.ga.config.as_deref(), matches, common_matches)?
+    else {
+        return Ok(());
+    };
+
+    config_values.apply_ga_options(&mut solve_config.ga)?;
+    config_values.apply_common_options(common)?;
+    config_values.set("runs", "runs", &mut solve_config.runs, parse_positive_usize)?;
+    config_values.set(
+        "draw_board",
+        "draw_board",
+        &mut solve_config.draw_board,
+        parse_bool,
+    )?;
+    config_values.set(
+        "metrics_csv",
+        "metrics_csv",
+        &mut solve_config.metrics_csv,
+        |value| Ok(Some(value.into())),
+    )?;
+    config_values.set("record", "record", &mut solve_config.record, |value| {
+        Ok(Some(value.into()))
+    })?;
+    config_values.set(
+        "progress_format",
+        "progress_format",
+        &mut solve_config.progress_format,
+        parse_progress_format,
+    )?;
+    config_values.set("tui", "tui", &mut solve_config.tui, parse_bool)?;
+    config_values.set(
+        "board_style",
+        "board_style",
+        &mut solve_config.display.board_style,
+        parse_board_style,
+    )?;
+    config_values.set(
+        "color",
+        "color",
+        &mut solve_config.display.color,
+        parse_color_mode,
+    )?;
+    config_values.set(
+        "board_width",
+        "board_width",
+        &mut solve_config.display.board_width,
+        |value| parse_positive_usize(value).map(Some),
+    )?;
+    config_values.set(
+        "export_board",
+        "export_board",
+        &mut solve_config.export.export_board,
+        |value| parse_export_path(value).map(Some),
+    )?;
+    config_values.set(
+        "export_attack_lines",
+        "export_attack_lines",
+        &mut solve_config.export.export_attack_lines,
+        parse_bool,
+    )?;
+
+    if solve_config.runs > 1
+        && (solve_config.metrics_csv.is_some()
+            || solve_config.record.is_some()
+            || solve_config.export.export_board.is_some())
+    {
+        return Err(
+            "`runs` greater than 1 cannot be combined with `metrics_csv`, `record`, or `export_board`"
+                .to_owned(),
+        );
+    }
+    Ok(())
+}
+
+pub fn apply_to_bench(
+    bench_config: &mut BenchConfig,
+    common: &mut CommonOptions,
+    matches: &ArgMatches,
+    common_matches: &ArgMatches,
+) -> Result<(), String> {
+    let Some(config_values) =
+        load_config_values(bench_config.ga.config.as_deref(), matches, common_matches)?
+    else {
+        return Ok(());
+    };
+
+    config_values.apply_ga_options(&mut bench_config.ga)?;
+    config_values.apply_common_options(common)
+}
+
+pub fn apply_to_portfolio(
+    portfolio_config: &mut PortfolioConfig,
+    common: &mut CommonOptions,
+    matches: &ArgMatches,
+    common_matches: &ArgMatches,
+) -> Result<(), String> {
+    let Some(config_values) = load_config_values(
+        portfolio_config.ga.config.as_deref(),
+        matches,
+        common_matches,
+    )?
+    else {
+        return Ok(());
+    };
+
+    config_values.apply_ga_options(&mut portfolio_config.ga)?;
+    config_values.apply_common_options(common)
+}
+
+fn load_config_values<'a>(
+    path: Option<&Path>,
+    matches: &'a ArgMatches,
+    common_matches: &'a ArgMatches,
+) -> Result<Option<ConfigValues<'a>>, String> {
+    let Some(path) = path else {
+        return Ok(None);
+    };
+
+    let mut values = load_config_file(path)?;
+    // A `--runs` summary lists every run under `runs`; reloading it repeats that many runs.
+    if let Some(Value::Array(runs)) = values.get("runs") {
+        let run_count = runs.len();
+        values.insert("runs".to_owned(), json!(run_count));
+    }
+    if let Some(key) = values.keys().find(|key| !is_known_key(key)) {
+        return Err(format!(
+            "unknown key `{key}` in config file {}",
+            path.display()
+        ));
+    }
+
+    Ok(Some(ConfigValues {
+        values,
+        matches,
+        common_matches,
+    }))
+}
+
+fn load_config_file(path: &Path) -> Result<Map<String, Value>, String> {
+    let contents = fs::read_to_string(path)
+        .map_err(|error| format!("failed to read config file {}: {error}", path.display()))?;
+    let is_json = match path.extension().and_then(|extension| extension.to_str()) {
+        Some(extension) => extension.eq_ignore_ascii_case("json"),
+        None => contents.trim_start().starts_with('{'),
+    };
+
+    if is_json {
+        serde_json::from_str(&contents)
+            .map_err(|error| format!("failed to parse JSON config {}: {error}", path.display()))
+    } else {
+        toml::from_str(&contents)
+            .map_err(|error| format!("failed to parse TOML config {}: {error}", path.display()))
+    }
+}
+
+fn is_known_key(key: &str) -> bool {
+    GA_OPTION_KEYS.contains(&key)
+        || COMMON_OPTION_KEYS.contains(&key)
+        || SOLVE_OUTPUT_KEYS.contains(&key)
+        || SUMMARY_RESULT_KEYS.contains(&key)
+}
+
+pub fn dump_config(
+    ga: &GaOptions,
+    solve_config: Option<&SolveConfig>,
+    common: &CommonOptions,
+) -> Result<String, String> {
+    let mut values = ga_option_values(ga);
+
+    if let Some(solve_config) = solve_config {
+        values.insert("runs".to_owned(), json!(solve_config.runs));
+        values.insert("draw_board".to_owned(), json!(solve_config.draw_board));
+        values.insert(
+            "progress_format".to_owned(),
+            json!(solve_config.progress_format.to_string()),
+        );
+        values.insert("tui".to_owned(), json!(solve_config.tui));
+        values.insert(
+            "board_style".to_owned(),
+            json!(solve_config.display.board_style.to_string()),
+        );
+        values.insert(
+            "color".to_owned(),
+            json!(solve_config.display.color.to_string()),
+        );
+        if let Some(board_width) = solve_config.display.board_width {
+            values.insert("board_width".to_owned(), json!(board_width));
+        }
+        if let Some(metrics_csv) = &solve_config.metrics_csv {
+            values.insert(
+                "metrics_csv".to_owned(),
+                json!(metrics_csv.display().to_string()),
+            );
+        }
+        if let Some(record) = &solve_config.record {
+            values.insert("record".to_owned(), json!(record.display().to_string()));
+        }
+        if let Some(export_board) = &solve_config.export.export_board {
+            values.insert(
+                "export_board".to_owned(),
+                json!(export_board.display().to_string()),
+            );
+        }
+        values.insert(
+            "export_attack_lines".to_owned(),
+            json!(solve_config.export.export_attack_lines),
+        );
+    }
+
+    values.insert("json".to_owned(), json!(common.json_output));
+    values.insert("quiet".to_owned(), json!(common.quiet));
+    if let Some(log_level) = common.log_level {
+        values.insert(
+            "log_level".to_owned(),
+            json!(log_level.to_string().to_ascii_lowercase()),
+        );
+    }
+
+    if common.json_output {
+        serde_json::to_string_pretty(&values)
+            .map_err(|error| format!("failed to write JSON config: {error}"))
+    } else {
+        toml::to_string(&values).map_err(|error| format!("failed to write TOML config: {error}"))
+    }
+}
+
+// The GA options under their config keys, shared by `--dump-config` and the JSON summaries.
+pub fn ga_option_values(ga: &GaOptions) -> Map<String, Value> {
+    let mut values = Map::new();
+    if let Some(seed) = ga.seed {
+        values.insert("seed".to_owned(), json!(seed));
+    }
+    values.insert("board_size".to_owned(), json!(ga.board_size));
+    values.insert("target_population".to_owned(), json!(ga.population_size));
+    values.insert("max_epochs".to_owned(), json!(ga.max_epochs));
+    if let Some(max_evaluations) = ga.max_evaluations {
+        values.insert("max_evaluations".to_owned(), json!(max_evaluations));
+    }
+    values.insert(
+        "mutation_rate".to_owned(),
+        json!(json_f32(ga.mutation_rate)),
+    );
+    values.insert("elite_ratio".to_owned(), json!(json_f32(ga.elite_ratio)));
+    values.insert(
+        "offspring_ratio".to_owned(),
+        json!(json_f32(ga.offspring_ratio)),
+    );
+    values.insert(
+        "min_diversity_ratio".to_owned(),
+        json!(json_f32(ga.min_diversity_ratio)),
+    );
+    values.insert(
+        "diversity_measure".to_owned(),
+        json!(ga.diversity_measure.to_string()),
+    );
+    values.insert(
+        "selection_strategy".to_owned(),
+        json!(ga.selection_strategy.to_string()),
+    );
+    values.insert("tournament_size".to_owned(), json!(ga.tournament_size));
+    values.insert(
+        "local_search_rate".to_owned(),
+        json!(json_f32(ga.local_search_rate)),
+    );
+    values.insert(
+        "local_search_attempts".to_owned(),
+        json!(ga.local_search_attempts),
+    );
+    values.insert("symmetry".to_owned(), json!(ga.symmetry.to_string()));
+    values.insert(
+        "count_symmetry_classes".to_owned(),
+        json!(ga.count_symmetry_classes),
+    );
+    if let Some(restart_interval) = ga.restart_interval {
+        values.insert("restart_interval".to_owned(), json!(restart_interval));
+    }
+    values.insert(
+        "restart_keep_ratio".to_owned(),
+        json!(json_f32(ga.restart_keep_ratio)),
+    );
+    values.insert(
+        "restart_kind".to_owned(),
+        json!(ga.restart_kind.to_string()),
+    );
+    values.insert("adaptive".to_owned(), json!(ga.adaptive));
+    values.insert(
+        "parameter_control".to_owned(),
+        json!(ga.parameter_control.to_string()),
+    );
+    values
+}
+
+fn parse_u64(raw_value: &str) -> Result<u64, String> {
+    raw_value
+        .parse::<u64>()
+        .map_err(|err| format!("invalid value `{raw_value}`: {err}"))
+}
+
+fn parse_bool(raw_value: &str) -> Result<bool, String> {
+    raw_value
+        .parse::<bool>()
+        .map_err(|err| format!("invalid value `{raw_value}`: {err}"))
+}
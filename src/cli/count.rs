@@ -0,0 +1,95 @@
+use std::collections::HashSet;
+
+use clap::{ArgAction, Args};
+use n_queens_problem::{
+    exact,
+    symmetry::{self, SymmetryConstraint},
+};
+use serde_json::json;
+
+use super::{
+    CommonOptions, DEFAULT_SYMMETRY_CONSTRAINT, parse_positive_u16, parse_symmetry_constraint,
+    parse_usize,
+};
+
+const DEFAULT_COUNT_BOARD_SIZE: u16 = 8;
+
+#[derive(Debug, Args)]
+pub struct CountConfig {
+    #[arg(
+        short = 'n',
+        long = "size",
+        value_name = "SIZE",
+        default_value_t = DEFAULT_COUNT_BOARD_SIZE,
+        value_parser = parse_positive_u16,
+        help = "Board size (number of queens, must be greater than 0)"
+    )]
+    pub board_size: u16,
+    #[arg(
+        long = "symmetry",
+        value_name = "none|rot180|rot90|reflection",
+        default_value_t = DEFAULT_SYMMETRY_CONSTRAINT,
+        value_parser = parse_symmetry_constraint,
+        help = "Only count boards invariant under this symmetry (reflection only matches a one-queen board)"
+    )]
+    pub symmetry: SymmetryConstraint,
+    #[arg(
+        long = "fundamental",
+        action = ArgAction::SetTrue,
+        help = "Also count solutions that are distinct up to rotation and reflection"
+    )]
+    pub fundamental: bool,
+    #[arg(
+        long = "list",
+        value_name = "COUNT",
+        default_value_t = 0,
+        value_parser = parse_usize,
+        help = "Print up to this many solutions"
+    )]
+    pub list: usize,
+}
+
+pub fn run(count_config: &CountConfig, common: &CommonOptions) -> Result<(), String> {
+    let board_size = count_config.board_size;
+    let symmetry_constraint = count_config.symmetry;
+    let (solutions, fundamental_solutions) = if count_config.fundamental {
+        let solutions = exact::find_solutions(board_size, symmetry_constraint, usize::MAX);
+        let canonical_solutions = solutions
+            .iter()
+            .map(|positions| symmetry::canonical_positions(positions))
+            .collect::<HashSet<_>>();
+        (solutions.len() as u64, Some(canonical_solutions.len()))
+    } else {
+        (
+            exact::count_solutions(board_size, symmetry_constraint),
+            None,
+        )
+    };
+    let listed = exact::find_solutions(board_size, symmetry_constraint, count_config.list);
+
+    if common.json_output {
+        let summary = json!({
+            "board_size": board_size,
+            "symmetry": symmetry_constraint.to_string(),
+            "solutions": solutions,
+            "fundamental_solutions": fundamental_solutions,
+            "listed": listed,
+        });
+        serde_json::to_writer_pretty(std::io::stdout(), &summary)
+            .map_err(|error| format!("failed to write JSON summary: {error}"))?;
+        println!();
+        return Ok(());
+    }
+
+    println!("Board Size: {board_size}");
+    println!("Symmetry: {symmetry_constraint}");
+    println!("Solutions: {solutions}");
+    if let Some(fundamental_solutions) = fundamental_solutions {
+        println!("Fundamental Solutions: {fundamental_solutions}");
+    }
+    for (index, positions) in listed.iter().enumerate() {
+        println!("Solution {}: {positions:?}", index + 1);
+    }
+
+    Ok(())
+}
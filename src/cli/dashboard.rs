@@ -0,0 +1,595 @@
+use std::{
+    collections::VecDeque,
+    fs,
+    io::{self, IsTerminal},
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver},
+    },
+    thread,
+    time::Duration,
+};
+
+use n_queens_problem::{
+    ga::{
+        self,
+        run_log::{self, RunLog, json_f32},
+    },
+    tui::{self, BoardStyle, TerminalOptions},
+};
+use ratatui::{
+    DefaultTerminal, Frame,
+    crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers},
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Sparkline},
+};
+
+const FRAME_INTERVAL: Duration = Duration::from_millis(50);
+const PAUSE_POLL_INTERVAL: Duration = Duration::from_millis(20);
+const HISTORY_LIMIT: usize = 512;
+const MAX_BOXED_SIZE: usize = 30;
+const REPLAY_PAGE_EPOCHS: usize = 10;
+const LIVE_KEYS: &str = " [space/p] pause/resume  [c] cancel  [s] save snapshot  [q] quit ";
+const REPLAY_KEYS: &str = " [←/→] step  [PgUp/PgDn] ±10  [Home/End] first/last  [space] play/pause  [s] save snapshot  [q] quit ";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RunState {
+    Running,
+    Paused,
+    Cancelling,
+    Solved,
+    Finished,
+    Cancelled,
+    Replay,
+    Playing,
+}
+
+impl RunState {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Running => "Running",
+            Self::Paused => "Paused",
+            Self::Cancelling => "Cancelling",
+            Self::Solved => "Solved",
+            Self::Finished => "Finished",
+            Self::Cancelled => "Cancelled",
+            Self::Replay => "Replay",
+            Self::Playing => "Playing",
+        }
+    }
+
+    fn is_active(self) -> bool {
+        matches!(self, Self::Running | Self::Paused | Self::Cancelling)
+    }
+}
+
+#[derive(Default)]
+struct RunControl {
+    paused: AtomicBool,
+    cancel: AtomicBool,
+}
+
+impl RunControl {
+    // Blocks the solver between epochs while paused; returns whether to keep running.
+    fn wait_while_paused(&self) -> bool {
+        while self.paused.load(Ordering::Relaxed) && !self.cancel.load(Ordering::Relaxed) {
+            thread::sleep(PAUSE_POLL_INTERVAL);
+        }
+        !self.cancel.load(Ordering::Relaxed)
+    }
+}
+
+struct DashboardState {
+    seed: Option<u64>,
+    max_epochs: Option<u32>,
+    keys: &'static str,
+    latest: Option<ga::EpochSnapshot>,
+    best_history: VecDeque<u64>,
+    average_history: VecDeque<u64>,
+    diversity_history: VecDeque<u64>,
+    run_state: RunState,
+    message: Option<String>,
+    quit_requested: bool,
+}
+
+impl DashboardState {
+    fn new(seed: Option<u64>, max_epochs: Option<u32>, keys: &'static str) -> Self {
+        Self {
+            seed,
+            max_epochs,
+            keys,
+            latest: None,
+            best_history: VecDeque::with_capacity(HISTORY_LIMIT),
+            average_history: VecDeque::with_capacity(HISTORY_LIMIT),
+            diversity_history: VecDeque::with_capacity(HISTORY_LIMIT),
+            run_state: RunState::Running,
+            message: None,
+            quit_requested: false,
+        }
+    }
+
+    fn record(&mut self, snapshot: ga::EpochSnapshot) {
+        self.push_history(&snapshot);
+        self.latest = Some(snapshot);
+    }
+
+    // Shows `snapshots` as if they had just been recorded live, ending at the last one.
+    fn rewind_to(&mut self, snapshots: &[ga::EpochSnapshot]) {
+        self.best_history.clear();
+        self.average_history.clear();
+        self.diversity_history.clear();
+        for snapshot in &snapshots[snapshots.len().saturating_sub(HISTORY_LIMIT)..] {
+            self.push_history(snapshot);
+        }
+        self.latest = snapshots.last().cloned();
+    }
+
+    fn push_history(&mut self, snapshot: &ga::EpochSnapshot) {
+        let metrics = snapshot.metrics();
+        push_history(
+            &mut self.best_history,
+            u64::from(snapshot.best_conflicts_sum()),
+        );
+        push_history(
+            &mut self.average_history,
+            metrics.average_conflicts_sum().round() as u64,
+        );
+        push_history(
+            &mut self.diversity_history,
+            (metrics.diversity_ratio() * 1_000.0).round() as u64,
+        );
+    }
+
+    fn finish(&mut self, cancelled: bool) {
+        let solved = self
+            .latest
+            .as_ref()
+            .is_some_and(|snapshot| snapshot.best_conflicts_sum() == 0);
+        self.run_state = if solved {
+            RunState::Solved
+        } else if cancelled {
+            RunState::Cancelled
+        } else {
+            RunState::Finished
+        };
+    }
+
+    fn handle_key(&mut self, code: KeyCode, modifiers: KeyModifiers, control: &RunControl) {
+        match code {
+            KeyCode::Char(' ') | KeyCode::Char('p') => self.toggle_pause(control),
+            KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => {
+                self.cancel(control);
+                self.quit_requested = true;
+            }
+            KeyCode::Char('c') => self.cancel(control),
+            KeyCode::Char('s') => self.save_snapshot(),
+            KeyCode::Char('q') | KeyCode::Esc => {
+                self.cancel(control);
+                self.quit_requested = true;
+            }
+            _ => {}
+        }
+    }
+
+    fn toggle_pause(&mut self, control: &RunControl) {
+        self.run_state = match self.run_state {
+            RunState::Running => RunState::Paused,
+            RunState::Paused => RunState::Running,
+            _ => return,
+        };
+        control
+            .paused
+            .store(self.run_state == RunState::Paused, Ordering::Relaxed);
+    }
+
+    fn cancel(&mut self, control: &RunControl) {
+        if self.run_state.is_active() {
+            control.cancel.store(true, Ordering::Relaxed);
+            control.paused.store(false, Ordering::Relaxed);
+            self.run_state = RunState::Cancelling;
+        }
+    }
+
+    fn save_snapshot(&mut self) {
+        let Some(snapshot) = &self.latest else {
+            self.message = Some("No epoch to save yet".to_owned());
+            return;
+        };
+
+        let epoch = snapshot.metrics().epoch();
+        let path = PathBuf::from(match self.seed {
+            Some(seed) => format!("n_queens_snapshot_seed{seed}_epoch{epoch}.json"),
+            None => format!("n_queens_snapshot_epoch{epoch}.json"),
+        });
+        let mut event = run_log::snapshot_json(snapshot);
+        event["event"] = "snapshot".into();
+        if let Some(seed) = self.seed {
+            event["seed"] = seed.into();
+        }
+        let contents = serde_json::to_string_pretty(&event).unwrap_or_default() + "\n";
+        self.message = Some(match fs::write(&path, contents) {
+            Ok(()) => format!("Saved snapshot to {}", path.display()),
+            Err(error) => format!("Failed to save {}: {error}", path.display()),
+        });
+    }
+}
+
+fn push_history(history: &mut VecDeque<u64>, value: u64) {
+    if history.len() == HISTORY_LIMIT {
+        history.pop_front();
+    }
+    history.push_back(value);
+}
+
+fn init_terminal() -> Result<DefaultTerminal, String> {
+    if !io::stdout().is_terminal() {
+        return Err("--tui needs stdout to be an interactive terminal".to_owned());
+    }
+
+    ratatui::try_init().map_err(|error| {
+        ratatui::restore();
+        format!("failed to start terminal dashboard: {error}")
+    })
+}
+
+pub fn run(
+    genetic_algorithm: &mut ga::GeneticAlgorithm,
+    seed: u64,
+    max_epochs: u32,
+    mut on_epoch: impl FnMut(&ga::EpochSnapshot) + Send,
+) -> Result<ga::RunMetrics, String> {
+    let mut terminal = init_terminal()?;
+    let control = RunControl::default();
+    let mut state = DashboardState::new(Some(seed), Some(max_epochs), LIVE_KEYS);
+    let (sender, receiver) = mpsc::channel();
+
+    let (run_metrics, ui_result) = thread::scope(|scope| {
+        let worker = scope.spawn(|| {
+            genetic_algorithm.run_algorithm_with_progress(|snapshot| {
+                on_epoch(snapshot);
+                sender.send(snapshot.clone()).is_ok() && control.wait_while_paused()
+            })
+        });
+        let ui_result = event_loop(&mut terminal, &mut state, &receiver, &control, || {
+            worker.is_finished()
+        });
+        // Make sure a failing UI does not leave the solver blocked or running to completion.
+        if ui_result.is_err() {
+            control.cancel.store(true, Ordering::Relaxed);
+        }
+        (worker.join(), ui_result)
+    });
+
+    ratatui::restore();
+    ui_result.map_err(|error| format!("terminal dashboard failed: {error}"))?;
+    run_metrics.map_err(|_| "solver thread panicked".to_owned())
+}
+
+fn event_loop(
+    terminal: &mut DefaultTerminal,
+    state: &mut DashboardState,
+    receiver: &Receiver<ga::EpochSnapshot>,
+    control: &RunControl,
+    worker_finished: impl Fn() -> bool,
+) -> io::Result<()> {
+    loop {
+        for snapshot in receiver.try_iter() {
+            state.record(snapshot);
+        }
+        if state.run_state.is_active() && worker_finished() {
+            for snapshot in receiver.try_iter() {
+                state.record(snapshot);
+            }
+            state.finish(control.cancel.load(Ordering::Relaxed));
+        }
+
+        terminal.draw(|frame| draw(frame, state))?;
+        if state.quit_requested && !state.run_state.is_active() {
+            return Ok(());
+        }
+
+        if event::poll(FRAME_INTERVAL)?
+            && let Event::Key(key) = event::read()?
+            && key.kind == KeyEventKind::Press
+        {
+            state.handle_key(key.code, key.modifiers, control);
+        }
+    }
+}
+
+pub fn replay(run_log: &RunLog, start_index: usize) -> Result<(), String> {
+    let mut terminal = init_terminal()?;
+    let header = run_log.header();
+    let mut state = DashboardState::new(
+        run_log.seed(),
+        header.map(|header| header.max_epochs),
+        REPLAY_KEYS,
+    );
+    state.run_state = RunState::Replay;
+
+    let result = replay_loop(&mut terminal, &mut state, run_log.snapshots(), start_index);
+    ratatui::restore();
+    result.map_err(|error| format!("terminal dashboard failed: {error}"))
+}
+
+fn replay_loop(
+    terminal: &mut DefaultTerminal,
+    state: &mut DashboardState,
+    snapshots: &[ga::EpochSnapshot],
+    start_index: usize,
+) -> io::Result<()> {
+    let last_index = snapshots.len().saturating_sub(1);
+    let mut index = start_index.min(last_index);
+    let mut shown_index = None;
+    loop {
+        if shown_index != Some(index) {
+            state.rewind_to(&snapshots[..=index]);
+            shown_index = Some(index);
+        }
+        terminal.draw(|frame| draw(frame, state))?;
+
+        if !event::poll(FRAME_INTERVAL)? {
+            if state.run_state == RunState::Playing {
+                if index == last_index {
+                    state.run_state = RunState::Replay;
+                } else {
+                    index += 1;
+                }
+            }
+            continue;
+        }
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        match key.code {
+            KeyCode::Left | KeyCode::Char('h') => index = index.saturating_sub(1),
+            KeyCode::Right | KeyCode::Char('l') => index = (index + 1).min(last_index),
+            KeyCode::PageUp => index = index.saturating_sub(REPLAY_PAGE_EPOCHS),
+            KeyCode::PageDown => index = (index + REPLAY_PAGE_EPOCHS).min(last_index),
+            KeyCode::Home => index = 0,
+            KeyCode::End => index = last_index,
+            KeyCode::Char(' ') | KeyCode::Char('p') => {
+                state.run_state = if state.run_state == RunState::Playing {
+                    RunState::Replay
+                } else {
+                    if index == last_index {
+                        index = 0;
+                    }
+                    RunState::Playing
+                };
+            }
+            KeyCode::Char('s') => state.save_snapshot(),
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return Ok(()),
+            _ => {}
+        }
+    }
+}
+
+fn draw(frame: &mut Frame, state: &DashboardState) {
+    let [main_area, footer_area] =
+        Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+    let [board_area, side_area] =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .areas(main_area);
+    let [metrics_area, best_area, average_area, diversity_area] = Layout::vertical([
+        Constraint::Length(13),
+        Constraint::Min(3),
+        Constraint::Min(3),
+        Constraint::Min(3),
+    ])
+    .areas(side_area);
+
+    draw_board(frame, board_area, state);
+    frame.render_widget(
+        Paragraph::new(metric_lines(state)).block(Block::bordered().title(" Metrics ")),
+        metrics_area,
+    );
+    draw_sparkline(
+        frame,
+        best_area,
+        " Best conflicts ",
+        &state.best_history,
+        Color::Red,
+    );
+    draw_sparkline(
+        frame,
+        average_area,
+        " Average conflicts ",
+        &state.average_history,
+        Color::Yellow,
+    );
+    draw_sparkline(
+        frame,
+        diversity_area,
+        " Diversity (per mille) ",
+        &state.diversity_history,
+        Color::Cyan,
+    );
+
+    let mut footer = vec![Span::raw(state.keys)];
+    if let Some(message) = &state.message {
+        footer.push(Span::raw(format!("· {message}")).bold());
+    }
+    frame.render_widget(Line::from(footer).reversed(), footer_area);
+}
+
+fn draw_board(frame: &mut Frame, area: Rect, state: &DashboardState) {
+    let size = state
+        .latest
+        .as_ref()
+        .map_or(0, |snapshot| snapshot.best_positions().len());
+    let mut status = String::from(" ");
+    if let Some(seed) = state.seed {
+        status.push_str(&format!("Seed {seed} · "));
+    }
+    if let Some(snapshot) = &state.latest {
+        status.push_str(&format!("epoch {}", snapshot.metrics().epoch()));
+        if let Some(max_epochs) = state.max_epochs {
+            status.push_str(&format!("/{max_epochs}"));
+        }
+        status.push_str(" · ");
+    }
+    status.push_str(state.run_state.label());
+    status.push(' ');
+    let block = Block::bordered()
+        .title(format!(" {size}x{size} board "))
+        .title(
+            Line::from(status)
+                .style(run_state_style(state.run_state))
+                .right_aligned(),
+        );
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let Some(snapshot) = &state.latest else {
+        frame.render_widget(Paragraph::new("Waiting for the first epoch..."), inner);
+        return;
+    };
+    let options = board_options(size, usize::from(inner.width), usize::from(inner.height));
+    let board = tui::render_board(
+        snapshot.best_positions(),
+        snapshot.best_conflicts(),
+        &options,
+    );
+    frame.render_widget(
+        Paragraph::new(board.lines().map(ansi_line).collect::<Vec<_>>()),
+        inner,
+    );
+}
+
+// Largest terminal style that fits the panel: framed grid, one character per cell, then braille.
+fn board_options(size: usize, width: usize, height: usize) -> TerminalOptions {
+    let options = TerminalOptions::default().with_color(true);
+    if size <= MAX_BOXED_SIZE && 5 * size < width && 2 * size < height {
+        options.with_style(BoardStyle::Boxed)
+    } else if size <= width && size <= height {
+        options.with_style(BoardStyle::Compact)
+    } else {
+        options
+            .with_style(BoardStyle::Braille)
+            .with_width(width.min(2 * height.saturating_sub(1)))
+    }
+}
+
+// Converts the ANSI colors emitted by `tui::render_board` into styled spans.
+fn ansi_line(line: &str) -> Line<'static> {
+    let mut spans = Vec::new();
+    let mut style = Style::default();
+    let mut rest = line;
+    while let Some(start) = rest.find("\x1b[") {
+        if start > 0 {
+            spans.push(Span::styled(rest[..start].to_owned(), style));
+        }
+        let escape = &rest[start + 2..];
+        let Some(end) = escape.find('m') else {
+            break;
+        };
+        style = match &escape[..end] {
+            "31" => Style::new().fg(Color::Red),
+            "32" => Style::new().fg(Color::Green),
+            "33" => Style::new().fg(Color::Yellow),
+            _ => Style::default(),
+        };
+        rest = &escape[end + 1..];
+    }
+    if !rest.is_empty() {
+        spans.push(Span::styled(rest.to_owned(), style));
+    }
+    Line::from(spans)
+}
+
+fn run_state_style(run_state: RunState) -> Style {
+    match run_state {
+        RunState::Running | RunState::Playing => Style::new().fg(Color::Green),
+        RunState::Replay => Style::new().fg(Color::Cyan),
+        RunState::Paused | RunState::Cancelling => Style::new().fg(Color::Yellow),
+        RunState::Solved => Style::new().fg(Color::Green).bold(),
+        RunState::Finished | RunState::Cancelled => Style::new().fg(Color::Red),
+    }
+}
+
+fn metric_lines(state: &DashboardState) -> Vec<Line<'static>> {
+    let Some(snapshot) = &state.latest else {
+        return Vec::new();
+    };
+    let metrics = snapshot.metrics();
+    let rows = [
+        (
+            "Epoch",
+            match state.max_epochs {
+                Some(max_epochs) => format!("{} / {max_epochs}", metrics.epoch()),
+                None => metrics.epoch().to_string(),
+            },
+        ),
+        ("Best conflicts", snapshot.best_conflicts_sum().to_string()),
+        (
+            "Average conflicts",
+            format!("{:.2}", metrics.average_conflicts_sum()),
+        ),
+        (
+            "Unique chromosomes",
+            format!(
+                "{} of {}",
+                metrics.unique_chromosomes(),
+                metrics.population_size()
+            ),
+        ),
+        (
+            "Diversity ratio",
+            json_f32(metrics.diversity_ratio()).to_string(),
+        ),
+        (
+            "Mutation rate",
+            json_f32(metrics.mutation_rate()).to_string(),
+        ),
+        ("Elite ratio", json_f32(metrics.elite_ratio()).to_string()),
+        ("Offspring", metrics.offspring_count().to_string()),
+        (
+            "Local search improvements",
+            metrics.local_search_improvements().to_string(),
+        ),
+        ("Stagnation epochs", metrics.stagnation_epochs().to_string()),
+        (
+            "Diversity replacements",
+            metrics.diversity_replacements().to_string(),
+        ),
+    ];
+
+    rows.into_iter()
+        .map(|(label, value)| Line::from(vec![Span::raw(format!("{label:<27}")), value.bold()]))
+        .collect()
+}
+
+fn draw_sparkline(
+    frame: &mut Frame,
+    area: Rect,
+    title: &str,
+    history: &VecDeque<u64>,
+    color: Color,
+) {
+    let block = Block::bordered().title(format!(
+        "{title}{} ",
+        history.back().map(u64::to_string).unwrap_or_default()
+    ));
+    let visible = usize::from(block.inner(area).width);
+    let data = history
+        .iter()
+        .skip(history.len().saturating_sub(visible))
+        .copied()
+        .collect::<Vec<_>>();
+    frame.render_widget(
+        Sparkline::default()
+            .block(block)
+            .data(data)
+            .style(Style::new().fg(color)),
+        area,
+    );
+}
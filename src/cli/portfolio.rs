@@ -0,0 +1,144 @@
+use std::time::Duration;
+
+use clap::{ArgAction, Args};
+use n_queens_problem::{portfolio, tui};
+use serde_json::json;
+
+use super::{
+    BoardDisplayOptions, CommonOptions, GaOptions, config, parse_positive_u16, parse_seconds,
+    parse_strategy, solve::chromosome_json,
+};
+
+const DEFAULT_TIME_LIMIT: &str = "60";
+
+#[derive(Debug, Args)]
+pub struct PortfolioConfig {
+    #[command(flatten)]
+    pub ga: GaOptions,
+    #[arg(
+        long = "strategies",
+        value_name = "STRATEGY[,STRATEGY]",
+        value_delimiter = ',',
+        default_value = "ga,min-conflicts,annealing,backtracking",
+        value_parser = parse_strategy,
+        help = "Strategies to race: ga, min-conflicts, annealing, backtracking"
+    )]
+    pub strategies: Vec<portfolio::Strategy>,
+    #[arg(
+        long = "time-limit",
+        value_name = "SECONDS",
+        default_value = DEFAULT_TIME_LIMIT,
+        value_parser = parse_seconds,
+        help = "Stop every strategy once this much time has passed"
+    )]
+    pub time_limit: Duration,
+    #[arg(
+        long = "backtracking-max-size",
+        value_name = "SIZE",
+        default_value_t = portfolio::DEFAULT_BACKTRACKING_MAX_SIZE,
+        value_parser = parse_positive_u16,
+        help = "Largest board the exact backtracking strategy is tried on"
+    )]
+    pub backtracking_max_size: u16,
+    #[arg(
+        long = "no-board",
+        action = ArgAction::SetFalse,
+        default_value_t = true,
+        help = "Skip board rendering output"
+    )]
+    pub draw_board: bool,
+    #[command(flatten)]
+    pub display: BoardDisplayOptions,
+}
+
+pub fn run(portfolio_config: &PortfolioConfig, common: &CommonOptions) -> Result<(), String> {
+    if portfolio_config.ga.dump_config {
+        print!(
+            "{}",
+            config::dump_config(&portfolio_config.ga, None, common)?
+        );
+        return Ok(());
+    }
+
+    let seed = portfolio_config.ga.seed_or_random();
+    let ga_config = portfolio_config
+        .ga
+        .to_ga_config(seed)
+        .map_err(|error| format!("invalid GA config: {error}"))?;
+    let config = portfolio::PortfolioConfig::new(ga_config)
+        .with_strategies(&portfolio_config.strategies)
+        .with_time_limit(Some(portfolio_config.time_limit))
+        .with_backtracking_max_size(portfolio_config.backtracking_max_size);
+    let outcome =
+        portfolio::run_portfolio(&config).map_err(|error| format!("invalid GA config: {error}"))?;
+
+    if common.json_output {
+        let summary = json!({
+            "board_size": ga_config.size,
+            "seed": seed,
+            "symmetry": ga_config.symmetry.to_string(),
+            "time_limit_ms": portfolio_config.time_limit.as_millis(),
+            "winner": outcome.winner.map(|winner| winner.to_string()),
+            "timed_out": outcome.timed_out,
+            "elapsed_ms": outcome.elapsed.as_millis(),
+            "strategies": outcome
+                .reports
+                .iter()
+                .map(|report| {
+                    json!({
+                        "strategy": report.strategy.to_string(),
+                        "status": report.status.to_string(),
+                        "elapsed_ms": report.elapsed.as_millis(),
+                        "steps": report.steps,
+                    })
+                })
+                .collect::<Vec<_>>(),
+            "solution": outcome.solution().map(chromosome_json),
+        });
+        serde_json::to_writer_pretty(std::io::stdout(), &summary)
+            .map_err(|error| format!("failed to write JSON summary: {error}"))?;
+        println!();
+        return Ok(());
+    }
+
+    for report in &outcome.reports {
+        if report.status == portfolio::StrategyStatus::Skipped {
+            println!("{}: skipped", report.strategy);
+            continue;
+        }
+        println!(
+            "{}: {} after {} steps, {} ms",
+            report.strategy,
+            report.status,
+            report.steps,
+            report.elapsed.as_millis()
+        );
+    }
+    println!("--------------------------------");
+    println!("Seed: {seed}");
+    println!("Elapsed (ms): {}", outcome.elapsed.as_millis());
+    let Some(solution) = outcome.solution() else {
+        if outcome.timed_out {
+            println!("No solution within the time limit.");
+        } else {
+            println!("No solution found.");
+        }
+        return Ok(());
+    };
+    if let Some(winner) = outcome.winner {
+        println!("Winner: {winner}");
+    }
+    println!("Solution = {solution:?}");
+
+    if !portfolio_config.draw_board {
+        println!("Board rendering disabled (--no-board).");
+    } else {
+        tui::draw_board_with(
+            solution.get_positions(),
+            solution.get_conflicts(),
+            &portfolio_config.display.terminal_options(),
+        );
+    }
+
+    Ok(())
+}
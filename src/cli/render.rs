@@ -0,0 +1,56 @@
+use clap::Args;
+use n_queens_problem::{board, ga::chromosome::Chromosome, tui};
+use serde_json::json;
+
+use super::{BoardDisplayOptions, BoardInput, CommonOptions, ExportOptions};
+
+#[derive(Debug, Args)]
+pub struct RenderConfig {
+    #[command(flatten)]
+    pub input: BoardInput,
+    #[command(flatten)]
+    pub display: BoardDisplayOptions,
+    #[command(flatten)]
+    pub export: ExportOptions,
+}
+
+pub fn run(render_config: &RenderConfig, common: &CommonOptions) -> Result<(), String> {
+    let positions = board::parse_board(&render_config.input.read()?)
+        .map_err(|error| format!("invalid board: {error}"))?;
+    let chromosome =
+        Chromosome::try_new(positions).map_err(|error| format!("invalid board: {error}"))?;
+    if let Some(export_path) = render_config.export.export(&chromosome)?
+        && !common.json_output
+    {
+        println!("Board exported to {}", export_path.display());
+    }
+
+    if common.json_output {
+        let positions = chromosome.get_positions();
+        let rows = (0..positions.len())
+            .map(|y| {
+                positions
+                    .iter()
+                    .map(|&row| if usize::from(row) == y { 'Q' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        let summary = json!({
+            "board_size": positions.len(),
+            "positions": positions,
+            "conflicts": chromosome.get_conflicts(),
+            "rows": rows,
+        });
+        serde_json::to_writer_pretty(std::io::stdout(), &summary)
+            .map_err(|error| format!("failed to write JSON summary: {error}"))?;
+        println!();
+        return Ok(());
+    }
+
+    tui::draw_board_with(
+        chromosome.get_positions(),
+        chromosome.get_conflicts(),
+        &render_config.display.terminal_options(),
+    );
+    Ok(())
+}
@@ -0,0 +1,146 @@
+use std::path::PathBuf;
+
+use clap::{ArgAction, Args};
+use n_queens_problem::{
+    ga::run_log::{self, RunLog, json_f32},
+    tui,
+};
+use serde_json::json;
+
+use super::{BoardDisplayOptions, CommonOptions, dashboard};
+
+#[derive(Debug, Args)]
+pub struct ReplayConfig {
+    #[arg(
+        value_name = "PATH",
+        help = "Run log written by --record, or a captured --progress-format ndjson stream"
+    )]
+    pub path: PathBuf,
+    #[arg(
+        long = "epoch",
+        value_name = "EPOCH",
+        help = "Show the board and metrics at this epoch [default: last recorded epoch]"
+    )]
+    pub epoch: Option<u32>,
+    #[arg(
+        long = "tui",
+        action = ArgAction::SetTrue,
+        help = "Step through the recorded epochs in the terminal dashboard"
+    )]
+    pub tui: bool,
+    #[command(flatten)]
+    pub display: BoardDisplayOptions,
+}
+
+pub fn run(replay_config: &ReplayConfig, common: &CommonOptions) -> Result<(), String> {
+    let run_log = RunLog::load(&replay_config.path).map_err(|error| {
+        format!(
+            "failed to load run log `{}`: {error}",
+            replay_config.path.display()
+        )
+    })?;
+    let snapshots = run_log.snapshots();
+    let index = match replay_config.epoch {
+        Some(epoch) => snapshots
+            .iter()
+            .position(|snapshot| snapshot.metrics().epoch() == epoch)
+            .ok_or_else(|| {
+                format!(
+                    "epoch {epoch} is not in the run log (recorded epochs {}-{})",
+                    snapshots[0].metrics().epoch(),
+                    snapshots[snapshots.len() - 1].metrics().epoch()
+                )
+            })?,
+        None => snapshots.len() - 1,
+    };
+
+    if replay_config.tui {
+        if common.json_output {
+            return Err("--tui cannot be combined with --json".to_owned());
+        }
+        return dashboard::replay(&run_log, index);
+    }
+
+    // Epochs where the best board improved, which is how the run's best board evolved.
+    let mut improvements = Vec::new();
+    for snapshot in &snapshots[..=index] {
+        let improved = improvements.last().is_none_or(|&(_, best_conflicts_sum)| {
+            snapshot.best_conflicts_sum() < best_conflicts_sum
+        });
+        if improved {
+            improvements.push((snapshot.metrics().epoch(), snapshot.best_conflicts_sum()));
+        }
+    }
+    let snapshot = &snapshots[index];
+    // Metrics CSV files carry no boards, so their size comes from the header.
+    let board_size = run_log
+        .header()
+        .map_or(snapshot.best_positions().len(), |header| {
+            usize::from(header.board_size)
+        });
+
+    if common.json_output {
+        let summary = json!({
+            "seed": run_log.seed(),
+            "board_size": board_size,
+            "max_epochs": run_log.header().map(|header| header.max_epochs),
+            "epochs_recorded": snapshots.len(),
+            "solved_epoch": run_log.solved_epoch(),
+            "elapsed_ms": run_log.total_elapsed_ms(),
+            "improvements": improvements
+                .iter()
+                .map(|&(epoch, best_conflicts_sum)| {
+                    json!({ "epoch": epoch, "best_conflicts_sum": best_conflicts_sum })
+                })
+                .collect::<Vec<_>>(),
+            "snapshot": run_log::snapshot_json(snapshot),
+        });
+        serde_json::to_writer_pretty(std::io::stdout(), &summary)
+            .map_err(|error| format!("failed to write JSON summary: {error}"))?;
+        println!();
+        return Ok(());
+    }
+
+    let size = board_size;
+    match run_log.seed() {
+        Some(seed) => println!("Run: seed {seed}, {size}x{size} board"),
+        None => println!("Run: {size}x{size} board"),
+    }
+    println!("Epochs Recorded: {}", snapshots.len());
+    if let Some(solved_epoch) = run_log.solved_epoch() {
+        println!("Solved Epoch: {solved_epoch}");
+    }
+    println!("--------------------------------");
+    for (epoch, best_conflicts_sum) in &improvements {
+        println!("Epoch {epoch}: best conflicts {best_conflicts_sum}");
+    }
+    println!("--------------------------------");
+
+    let metrics = snapshot.metrics();
+    println!("Epoch: {}", metrics.epoch());
+    println!("Best Conflicts: {}", snapshot.best_conflicts_sum());
+    println!(
+        "Average Conflicts: {}",
+        json_f32(metrics.average_conflicts_sum())
+    );
+    println!(
+        "Unique Chromosomes: {} of {}",
+        metrics.unique_chromosomes(),
+        metrics.population_size()
+    );
+    println!(
+        "Mutation Rate: {}, Elite Ratio: {}",
+        json_f32(metrics.mutation_rate()),
+        json_f32(metrics.elite_ratio())
+    );
+    println!("Elapsed (ms): {}", metrics.elapsed_ms());
+    if snapshot.best_positions().is_empty() {
+        return Ok(());
+    }
+    tui::draw_board_with(
+        snapshot.best_positions(),
+        snapshot.best_conflicts(),
+        &replay_config.display.terminal_options(),
+    );
+    Ok(())
+}
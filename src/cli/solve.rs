@@ -0,0 +1,638 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use clap::{ArgAction, Args};
+use n_queens_problem::{
+    ga::{
+        self,
+        run_log::{self, RunLogHeader, RunLogWriter, json_f32},
+    },
+    symmetry, tui,
+};
+use serde_json::json;
+
+use super::{
+    BoardDisplayOptions, CommonOptions, ExportOptions, GaOptions, config, dashboard,
+    parse_positive_usize, parse_progress_format, seed_for_offset,
+};
+
+#[derive(Debug, Args)]
+pub struct SolveConfig {
+    #[command(flatten)]
+    pub ga: GaOptions,
+    #[arg(
+        long = "runs",
+        value_name = "COUNT",
+        default_value_t = 1,
+        value_parser = parse_positive_usize,
+        conflicts_with_all = ["metrics_csv", "export_board", "record"],
+        help = "Run consecutive seeds and report whether each solution is new up to symmetry"
+    )]
+    pub runs: usize,
+    #[arg(
+        long = "no-board",
+        action = ArgAction::SetFalse,
+        default_value_t = true,
+        help = "Skip board rendering output"
+    )]
+    pub draw_board: bool,
+    #[arg(
+        long = "metrics-csv",
+        value_name = "PATH",
+        help = "Write per-epoch run metrics to CSV"
+    )]
+    pub metrics_csv: Option<PathBuf>,
+    #[arg(
+        long = "record",
+        value_name = "PATH",
+        help = "Record every epoch's metrics and best board to a run log for `replay`"
+    )]
+    pub record: Option<PathBuf>,
+    #[command(flatten)]
+    pub display: BoardDisplayOptions,
+    #[command(flatten)]
+    pub export: ExportOptions,
+    #[arg(
+        long = "progress-format",
+        value_name = "none|ndjson",
+        default_value_t = ProgressFormat::None,
+        value_parser = parse_progress_format,
+        help = "Stream one JSON object per epoch on stdout (ndjson implies --json)"
+    )]
+    pub progress_format: ProgressFormat,
+    #[arg(
+        long = "tui",
+        action = ArgAction::SetTrue,
+        help = "Show a live full-screen terminal dashboard with pause, resume, cancel, and snapshot keys"
+    )]
+    pub tui: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProgressFormat {
+    #[default]
+    None,
+    Ndjson,
+}
+
+impl fmt::Display for ProgressFormat {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => formatter.write_str("none"),
+            Self::Ndjson => formatter.write_str("ndjson"),
+        }
+    }
+}
+
+pub fn run(solve_config: &SolveConfig, common: &CommonOptions) -> Result<(), String> {
+    if solve_config.ga.dump_config {
+        print!(
+            "{}",
+            config::dump_config(&solve_config.ga, Some(solve_config), common)?
+        );
+        return Ok(());
+    }
+
+    if solve_config.tui && (common.json_output || solve_config.runs > 1) {
+        return Err(
+            "--tui cannot be combined with --json, --progress-format ndjson, or --runs".to_owned(),
+        );
+    }
+
+    let seed = solve_config.ga.seed_or_random();
+    if solve_config.runs > 1 {
+        return run_seed_series(solve_config, common, seed);
+    }
+
+    let ga_config = solve_config
+        .ga
+        .to_ga_config(seed)
+        .map_err(|error| format!("invalid GA config: {error}"))?
+        .with_diversity_report_interval(diversity_report_interval(solve_config));
+
+    log::info!(
+        "start n_queens_problem board_size={} population={} epochs={} max_evaluations={:?} seed={seed} mutation_rate={} elite_ratio={} offspring_ratio={} min_diversity_ratio={} diversity_measure={} selection_strategy={} tournament_size={} local_search_rate={} local_search_attempts={} symmetry={} restart_interval={:?} restart_keep_ratio={} restart_kind={} adaptive={} parameter_control={} draw_board={}",
+        solve_config.ga.board_size,
+        solve_config.ga.population_size,
+        solve_config.ga.max_epochs,
+        solve_config.ga.max_evaluations,
+        solve_config.ga.mutation_rate,
+        solve_config.ga.elite_ratio,
+        solve_config.ga.offspring_ratio,
+        solve_config.ga.min_diversity_ratio,
+        solve_config.ga.diversity_measure,
+        solve_config.ga.selection_strategy,
+        solve_config.ga.tournament_size,
+        solve_config.ga.local_search_rate,
+        solve_config.ga.local_search_attempts,
+        solve_config.ga.symmetry,
+        solve_config.ga.restart_interval,
+        solve_config.ga.restart_keep_ratio,
+        solve_config.ga.restart_kind,
+        solve_config.ga.adaptive,
+        solve_config.ga.parameter_control,
+        solve_config.draw_board,
+    );
+
+    let mut recorder = RunRecorder::create(solve_config.record.as_deref(), &ga_config)?;
+    let mut genetic_algorithm = ga::build_genetic_algorithm(ga_config)
+        .map_err(|error| format!("invalid GA config: {error}"))?;
+
+    log::info!("done building genetic algorithm");
+    let run_metrics = if solve_config.tui {
+        dashboard::run(
+            &mut genetic_algorithm,
+            seed,
+            solve_config.ga.max_epochs,
+            |snapshot| recorder.record(snapshot),
+        )?
+    } else {
+        genetic_algorithm.run_algorithm_with_progress(|snapshot| {
+            recorder.record(snapshot);
+            report_progress(solve_config.progress_format, seed, snapshot)
+        })
+    };
+
+    if let Some(record_path) = recorder.finish(&run_metrics)?
+        && !common.json_output
+    {
+        println!("Run log written to {}", record_path.display());
+    }
+
+    if let Some(metrics_path) = solve_config.metrics_csv.as_deref() {
+        write_run_metrics_csv(metrics_path, solve_config, seed, &run_metrics)?;
+        if !common.json_output {
+            println!("Metrics written to {}", metrics_path.display());
+        }
+    }
+
+    let best_chromosome = genetic_algorithm.get_best_chromosome();
+    let worst_chromosome = genetic_algorithm.get_worst_chromosome();
+    let population_size = genetic_algorithm.get_population_size();
+
+    if let Some(export_path) = solve_config.export.export(best_chromosome)?
+        && !common.json_output
+    {
+        println!("Board exported to {}", export_path.display());
+    }
+
+    if common.json_output {
+        return print_run_summary_json(
+            solve_config,
+            seed,
+            &run_metrics,
+            best_chromosome,
+            worst_chromosome,
+            population_size,
+            solve_config.metrics_csv.as_deref(),
+        );
+    }
+
+    log::info!("done running epoch");
+    println!("--------------------------------");
+    println!("Best  = {best_chromosome:?}");
+    println!("Worst = {worst_chromosome:?}");
+    println!("Final Population: {population_size}");
+    println!("Elapsed (ms): {}", run_metrics.total_elapsed_ms());
+    let phases = run_metrics.total_phase_timings();
+    println!(
+        "Phase Time (ms): mating {:.1}, mutation {:.1}, local search {:.1}, selection {:.1}, fitness {:.1}, diversity {:.1}",
+        phases.mating_us as f64 / 1_000.0,
+        phases.mutation_us as f64 / 1_000.0,
+        phases.local_search_us as f64 / 1_000.0,
+        phases.selection_us as f64 / 1_000.0,
+        phases.fitness_us as f64 / 1_000.0,
+        phases.diversity_us as f64 / 1_000.0,
+    );
+    let evaluations = run_metrics.total_evaluations();
+    println!(
+        "Evaluations: {} ({} full, {} swap delta)",
+        evaluations.total(),
+        evaluations.full,
+        evaluations.swap_delta,
+    );
+    if run_metrics.evaluation_budget_reached() {
+        println!("Evaluation budget reached.");
+    }
+    if let Some(solved_epoch) = run_metrics.solved_epoch() {
+        println!("Solved Epoch: {solved_epoch}");
+        println!(
+            "Solution Symmetry: {}",
+            symmetry::classify(best_chromosome.get_positions())
+        );
+    }
+
+    if !solve_config.draw_board {
+        println!("Board rendering disabled (--no-board).");
+    } else {
+        let best_positions = best_chromosome.get_positions();
+        let best_conflicts = best_chromosome.get_conflicts();
+        tui::draw_board_with(
+            best_positions,
+            best_conflicts,
+            &solve_config.display.terminal_options(),
+        );
+    }
+
+    log::info!("done n_queens_problem");
+    Ok(())
+}
+
+pub(super) fn chromosome_json(chromosome: &ga::chromosome::Chromosome) -> serde_json::Value {
+    json!({
+        "positions": chromosome.get_positions(),
+        "conflicts": chromosome.get_conflicts(),
+        "conflicts_sum": chromosome.get_conflicts_sum(),
+    })
+}
+
+fn phase_timings_json(timings: ga::PhaseTimings) -> serde_json::Value {
+    json!({
+        "mating": timings.mating_us,
+        "mutation": timings.mutation_us,
+        "local_search": timings.local_search_us,
+        "selection": timings.selection_us,
+        "fitness": timings.fitness_us,
+        "diversity": timings.diversity_us,
+    })
+}
+
+fn evaluations_json(evaluations: ga::EvaluationCounts) -> serde_json::Value {
+    json!({
+        "full": evaluations.full,
+        "swap_delta": evaluations.swap_delta,
+        "total": evaluations.total(),
+    })
+}
+
+fn print_run_summary_json(
+    solve_config: &SolveConfig,
+    seed: u64,
+    run_metrics: &ga::RunMetrics,
+    best_chromosome: &ga::chromosome::Chromosome,
+    worst_chromosome: &ga::chromosome::Chromosome,
+    final_population: usize,
+    metrics_csv: Option<&Path>,
+) -> Result<(), String> {
+    let final_epoch = run_metrics.epochs().last();
+    let mut summary = summary_config_json(solve_config, seed);
+    let results = json!({
+        "final_population": final_population,
+        "final_unique_chromosomes": final_epoch.map(|metrics| metrics.unique_chromosomes()),
+        "final_diversity_ratio": final_epoch.map(|metrics| json_f32(metrics.diversity_ratio())),
+        "final_unique_symmetry_classes": final_epoch.and_then(|metrics| metrics.unique_symmetry_classes()),
+        "final_gene_entropy": final_epoch.and_then(|metrics| metrics.gene_entropy()).map(json_f32),
+        "final_mean_pairwise_distance": final_epoch.and_then(|metrics| metrics.mean_pairwise_distance()).map(json_f32),
+        "final_mean_best_distance": final_epoch.and_then(|metrics| metrics.mean_best_distance()).map(json_f32),
+        "last_local_search_improvements": final_epoch
+            .map(|metrics| metrics.local_search_improvements())
+            .unwrap_or_default(),
+        "last_diversity_replacements": final_epoch
+            .map(|metrics| metrics.diversity_replacements())
+            .unwrap_or_default(),
+        "elapsed_ms": run_metrics.total_elapsed_ms(),
+        "phase_us": phase_timings_json(run_metrics.total_phase_timings()),
+        "evaluations": evaluations_json(run_metrics.total_evaluations()),
+        "evaluation_budget_reached": run_metrics.evaluation_budget_reached(),
+        "solved_epoch": run_metrics.solved_epoch(),
+        "metrics_csv": metrics_csv.map(|path| path.display().to_string()),
+        "record": solve_config
+            .record
+            .as_ref()
+            .map(|path| path.display().to_string()),
+        "export_board": solve_config
+            .export
+            .export_board
+            .as_ref()
+            .map(|path| path.display().to_string()),
+        "export_attack_lines": solve_config.export.export_attack_lines,
+        "best_chromosome": chromosome_json(best_chromosome),
+        "best_symmetry_group": symmetry::classify(best_chromosome.get_positions()).to_string(),
+        "worst_chromosome": chromosome_json(worst_chromosome),
+    });
+    if let serde_json::Value::Object(results) = results {
+        summary.extend(results);
+    }
+
+    write_json_summary(solve_config.progress_format, summary.into())
+}
+
+// The configuration half of a JSON summary, under the `--config` keys so it reloads as one.
+fn summary_config_json(
+    solve_config: &SolveConfig,
+    seed: u64,
+) -> serde_json::Map<String, serde_json::Value> {
+    let mut summary = config::ga_option_values(&solve_config.ga);
+    summary.insert("seed".to_owned(), json!(seed));
+    summary.insert(
+        "max_evaluations".to_owned(),
+        json!(solve_config.ga.max_evaluations),
+    );
+    summary.insert(
+        "restart_interval".to_owned(),
+        json!(solve_config.ga.restart_interval),
+    );
+    summary
+}
+
+// Writes the run log for `--record`; the first write error is reported once the run ends.
+struct RunRecorder<'a> {
+    path: Option<&'a Path>,
+    writer: Option<RunLogWriter<BufWriter<File>>>,
+    error: Option<io::Error>,
+}
+
+impl<'a> RunRecorder<'a> {
+    fn create(path: Option<&'a Path>, ga_config: &ga::GaConfig) -> Result<Self, String> {
+        let writer = path
+            .map(|path| {
+                RunLogWriter::create(path, &RunLogHeader::from_config(ga_config)).map_err(|error| {
+                    format!("failed to create run log `{}`: {error}", path.display())
+                })
+            })
+            .transpose()?;
+
+        Ok(Self {
+            path,
+            writer,
+            error: None,
+        })
+    }
+
+    fn record(&mut self, snapshot: &ga::EpochSnapshot) {
+        if self.error.is_some() {
+            return;
+        }
+        if let Some(writer) = self.writer.as_mut()
+            && let Err(error) = writer.record(snapshot)
+        {
+            self.error = Some(error);
+        }
+    }
+
+    fn finish(self, run_metrics: &ga::RunMetrics) -> Result<Option<&'a Path>, String> {
+        let (Some(path), Some(writer)) = (self.path, self.writer) else {
+            return Ok(None);
+        };
+
+        self.error
+            .map_or_else(|| writer.finish(run_metrics).map(|_| ()), Err)
+            .map_err(|error| format!("failed to write run log `{}`: {error}", path.display()))?;
+        Ok(Some(path))
+    }
+}
+
+// Per-epoch output reports every diversity measure; otherwise only the last epoch's
+// are computed beyond the configured one.
+fn diversity_report_interval(solve_config: &SolveConfig) -> Option<u32> {
+    (solve_config.metrics_csv.is_some()
+        || solve_config.record.is_some()
+        || solve_config.progress_format == ProgressFormat::Ndjson)
+        .then_some(1)
+}
+
+fn write_json_summary(
+    progress_format: ProgressFormat,
+    mut summary: serde_json::Value,
+) -> Result<(), String> {
+    let result = if progress_format == ProgressFormat::Ndjson {
+        summary["event"] = json!("summary");
+        serde_json::to_writer(io::stdout(), &summary)
+    } else {
+        serde_json::to_writer_pretty(io::stdout(), &summary)
+    };
+    result.map_err(|error| format!("failed to write JSON summary: {error}"))?;
+    println!();
+    Ok(())
+}
+
+fn report_progress(
+    progress_format: ProgressFormat,
+    seed: u64,
+    snapshot: &ga::EpochSnapshot,
+) -> bool {
+    if progress_format == ProgressFormat::None {
+        return true;
+    }
+
+    let event = epoch_event(seed, snapshot);
+    // A closed stdout (for example a dashboard that stopped reading) cancels the run.
+    writeln!(io::stdout(), "{event}").is_ok()
+}
+
+pub(super) fn epoch_event(seed: u64, snapshot: &ga::EpochSnapshot) -> serde_json::Value {
+    let mut event = run_log::snapshot_json(snapshot);
+    event["seed"] = json!(seed);
+    event
+}
+
+fn write_run_metrics_csv(
+    metrics_path: &Path,
+    solve_config: &SolveConfig,
+    seed: u64,
+    run_metrics: &ga::RunMetrics,
+) -> Result<(), String> {
+    if let Some(parent) = metrics_path
+        .parent()
+        .filter(|path| !path.as_os_str().is_empty())
+    {
+        fs::create_dir_all(parent).map_err(|error| {
+            format!(
+                "failed to create metrics directory `{}`: {error}",
+                parent.display()
+            )
+        })?;
+    }
+
+    let mut metrics_file = File::create(metrics_path).map_err(|error| {
+        format!(
+            "failed to create metrics file `{}`: {error}",
+            metrics_path.display()
+        )
+    })?;
+
+    writeln!(
+        metrics_file,
+        "seed,board_size,target_population,max_epochs,mutation_rate,elite_ratio,offspring_ratio,min_diversity_ratio,selection_strategy,tournament_size,local_search_rate,local_search_attempts,count_symmetry_classes,diversity_measure,max_evaluations,restart_interval,restart_keep_ratio,restart_kind,adaptive,parameter_control,symmetry,epoch,best_conflicts_sum,population_size,elapsed_ms,average_conflicts_sum,unique_chromosomes,unique_symmetry_classes,diversity_ratio,gene_entropy,mean_pairwise_distance,mean_best_distance,epoch_mutation_rate,epoch_elite_ratio,offspring_count,local_search_improvements,stagnation_epochs,diversity_replacements,mating_us,mutation_us,local_search_us,selection_us,fitness_us,diversity_us,full_evaluations,swap_evaluations,evaluations,mutation_success_ratio,pmx_probability,order_probability,cycle_probability,swap_probability,inversion_probability,insertion_probability"
+    )
+    .map_err(|error| {
+        format!(
+            "failed to write metrics header `{}`: {error}",
+            metrics_path.display()
+        )
+    })?;
+
+    for epoch_metrics in run_metrics.epochs() {
+        let timings = epoch_metrics.phase_timings();
+        let control = epoch_metrics.control();
+        writeln!(
+            metrics_file,
+            "{seed},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+            solve_config.ga.board_size,
+            solve_config.ga.population_size,
+            solve_config.ga.max_epochs,
+            solve_config.ga.mutation_rate,
+            solve_config.ga.elite_ratio,
+            solve_config.ga.offspring_ratio,
+            solve_config.ga.min_diversity_ratio,
+            solve_config.ga.selection_strategy,
+            solve_config.ga.tournament_size,
+            solve_config.ga.local_search_rate,
+            solve_config.ga.local_search_attempts,
+            solve_config.ga.count_symmetry_classes,
+            solve_config.ga.diversity_measure,
+            solve_config.ga.max_evaluations.map_or(String::new(), |max_evaluations| max_evaluations.to_string()),
+            solve_config.ga.restart_interval.map_or(String::new(), |restart_interval| restart_interval.to_string()),
+            solve_config.ga.restart_keep_ratio,
+            solve_config.ga.restart_kind,
+            solve_config.ga.adaptive,
+            solve_config.ga.parameter_control,
+            solve_config.ga.symmetry,
+            epoch_metrics.epoch(),
+            epoch_metrics.best_conflicts_sum(),
+            epoch_metrics.population_size(),
+            epoch_metrics.elapsed_ms(),
+            epoch_metrics.average_conflicts_sum(),
+            epoch_metrics.unique_chromosomes(),
+            epoch_metrics
+                .unique_symmetry_classes()
+                .map_or(String::new(), |classes| classes.to_string()),
+            epoch_metrics.diversity_ratio(),
+            epoch_metrics
+                .gene_entropy()
+                .map_or(String::new(), |value| value.to_string()),
+            epoch_metrics
+                .mean_pairwise_distance()
+                .map_or(String::new(), |value| value.to_string()),
+            epoch_metrics
+                .mean_best_distance()
+                .map_or(String::new(), |value| value.to_string()),
+            epoch_metrics.mutation_rate(),
+            epoch_metrics.elite_ratio(),
+            epoch_metrics.offspring_count(),
+            epoch_metrics.local_search_improvements(),
+            epoch_metrics.stagnation_epochs(),
+            epoch_metrics.diversity_replacements(),
+            timings.mating_us,
+            timings.mutation_us,
+            timings.local_search_us,
+            timings.selection_us,
+            timings.fitness_us,
+            timings.diversity_us,
+            epoch_metrics.evaluations().full,
+            epoch_metrics.evaluations().swap_delta,
+            epoch_metrics.total_evaluations(),
+            control.mutation_success_ratio,
+            control.crossover_probabilities[0],
+            control.crossover_probabilities[1],
+            control.crossover_probabilities[2],
+            control.mutation_probabilities[0],
+            control.mutation_probabilities[1],
+            control.mutation_probabilities[2],
+        )
+        .map_err(|error| {
+            format!(
+                "failed to write metrics row `{}`: {error}",
+                metrics_path.display()
+            )
+        })?;
+    }
+
+    Ok(())
+}
+
+fn run_seed_series(
+    solve_config: &SolveConfig,
+    common: &CommonOptions,
+    first_seed: u64,
+) -> Result<(), String> {
+    let mut first_seed_by_solution = HashMap::<Vec<u16>, u64>::new();
+    let mut solved_runs = 0;
+    let mut runs = Vec::with_capacity(solve_config.runs);
+
+    for offset in 0..solve_config.runs {
+        let seed = seed_for_offset(first_seed, offset)?;
+        let ga_config = solve_config
+            .ga
+            .to_ga_config(seed)
+            .map_err(|error| format!("invalid GA config: {error}"))?
+            .with_diversity_report_interval(diversity_report_interval(solve_config));
+        let mut genetic_algorithm = ga::build_genetic_algorithm(ga_config)
+            .map_err(|error| format!("invalid GA config: {error}"))?;
+        let run_metrics = genetic_algorithm.run_algorithm_with_progress(|snapshot| {
+            report_progress(solve_config.progress_format, seed, snapshot)
+        });
+        let best_chromosome = genetic_algorithm.get_best_chromosome();
+        let positions = best_chromosome.get_positions();
+        let solved_epoch = run_metrics.solved_epoch();
+
+        let mut run = json!({
+            "seed": seed,
+            "solved_epoch": solved_epoch,
+            "elapsed_ms": run_metrics.total_elapsed_ms(),
+            "best_conflicts_sum": best_chromosome.get_conflicts_sum(),
+            "positions": positions,
+        });
+
+        let Some(solved_epoch) = solved_epoch else {
+            if !common.json_output {
+                println!(
+                    "Seed {seed}: no solution, best conflicts {}",
+                    best_chromosome.get_conflicts_sum()
+                );
+            }
+            runs.push(run);
+            continue;
+        };
+
+        solved_runs += 1;
+        let canonical_positions = symmetry::canonical_positions(positions);
+        let symmetry_group = symmetry::classify(positions);
+        let first_seed_for_solution = *first_seed_by_solution
+            .entry(canonical_positions.clone())
+            .or_insert(seed);
+        let is_new_solution = first_seed_for_solution == seed;
+
+        run["symmetry_group"] = json!(symmetry_group.to_string());
+        run["canonical_positions"] = json!(canonical_positions);
+        run["new_solution"] = json!(is_new_solution);
+        run["first_seed"] = json!(first_seed_for_solution);
+        runs.push(run);
+
+        if !common.json_output {
+            let novelty = if is_new_solution {
+                format!("new solution #{}", first_seed_by_solution.len())
+            } else {
+                format!("same solution as seed {first_seed_for_solution}")
+            };
+            println!(
+                "Seed {seed}: solved at epoch {solved_epoch}, symmetry {symmetry_group}, {novelty}"
+            );
+        }
+    }
+
+    if common.json_output {
+        let mut summary = summary_config_json(solve_config, first_seed);
+        summary.insert("runs".to_owned(), json!(runs));
+        summary.insert("solved_runs".to_owned(), json!(solved_runs));
+        summary.insert(
+            "distinct_solutions".to_owned(),
+            json!(first_seed_by_solution.len()),
+        );
+        write_json_summary(solve_config.progress_format, summary.into())?;
+    } else {
+        println!("--------------------------------");
+        println!(
+            "Distinct solutions: {} from {solved_runs} solved of {} runs",
+            first_seed_by_solution.len(),
+            solve_config.runs
+        );
+    }
+
+    Ok(())
+}
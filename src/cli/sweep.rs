@@ -1,15 +1,17 @@
-use std::{error::Error, time::Instant};
+use std::time::Instant;
 
-use clap::Parser;
-use n_queens_problem::ga::{self, GaConfig};
+use clap::Args;
+use n_queens_problem::ga::{self, GaConfig, run_log::json_f32};
+use serde_json::json;
 
-#[derive(Debug, Parser)]
-#[command(name = "parameter_sweep")]
-#[command(about = "Run N-Queens GA parameter sweeps across multiple seeds")]
-#[command(
-    after_help = "Example:\n  cargo run --release --example parameter_sweep -- --sizes 18 --populations 40000 --epochs 5000 --seeds 20 --mutation-rates 0.06,0.08 --elite-ratios 0.05,0.10 --offspring-ratios 0.05,0.10 --min-diversity-ratios 0.05,0.10 --selection-strategies roulette,tournament --tournament-sizes 3,5 --local-search-rates 0,0.05 --local-search-attempts 8"
-)]
-struct SweepConfig {
+use super::{
+    CommonOptions, median_u32, median_u128, parse_positive_u16, parse_positive_u32,
+    parse_positive_usize, parse_selection_strategy, parse_unit_interval, parse_usize,
+    seed_for_offset,
+};
+
+#[derive(Debug, Args)]
+pub struct SweepConfig {
     #[arg(
         short = 'n',
         long = "sizes",
@@ -19,7 +21,7 @@ struct SweepConfig {
         value_parser = parse_positive_u16,
         help = "Board sizes to test"
     )]
-    sizes: Vec<u16>,
+    pub sizes: Vec<u16>,
     #[arg(
         short = 'p',
         long = "populations",
@@ -29,7 +31,7 @@ struct SweepConfig {
         value_parser = parse_positive_usize,
         help = "Population sizes to test"
     )]
-    populations: Vec<usize>,
+    pub populations: Vec<usize>,
     #[arg(
         short = 'e',
         long = "epochs",
@@ -39,20 +41,20 @@ struct SweepConfig {
         value_parser = parse_positive_u32,
         help = "Maximum epoch counts to test"
     )]
-    epochs: Vec<u32>,
+    pub epochs: Vec<u32>,
     #[arg(
         long = "seed-start",
         default_value_t = 1,
         help = "First deterministic seed"
     )]
-    seed_start: u64,
+    pub seed_start: u64,
     #[arg(
         long = "seeds",
         default_value_t = 20,
         value_parser = parse_positive_usize,
         help = "Number of consecutive seeds to run per configuration"
     )]
-    seed_count: usize,
+    pub seed_count: usize,
     #[arg(
         long = "mutation-rates",
         value_name = "RATE[,RATE]",
@@ -61,7 +63,7 @@ struct SweepConfig {
         value_parser = parse_unit_interval,
         help = "Mutation rates to test"
     )]
-    mutation_rates: Vec<f32>,
+    pub mutation_rates: Vec<f32>,
     #[arg(
         long = "elite-ratios",
         value_name = "RATIO[,RATIO]",
@@ -70,7 +72,7 @@ struct SweepConfig {
         value_parser = parse_unit_interval,
         help = "Elite survivor ratios to test"
     )]
-    elite_ratios: Vec<f32>,
+    pub elite_ratios: Vec<f32>,
     #[arg(
         long = "offspring-ratios",
         value_name = "RATIO[,RATIO]",
@@ -79,7 +81,7 @@ struct SweepConfig {
         value_parser = parse_unit_interval,
         help = "Offspring ratios to test"
     )]
-    offspring_ratios: Vec<f32>,
+    pub offspring_ratios: Vec<f32>,
     #[arg(
         long = "min-diversity-ratios",
         value_name = "RATIO[,RATIO]",
@@ -88,7 +90,7 @@ struct SweepConfig {
         value_parser = parse_unit_interval,
         help = "Minimum diversity ratios to test"
     )]
-    min_diversity_ratios: Vec<f32>,
+    pub min_diversity_ratios: Vec<f32>,
     #[arg(
         long = "selection-strategies",
         value_name = "STRATEGY[,STRATEGY]",
@@ -97,7 +99,7 @@ struct SweepConfig {
         value_parser = parse_selection_strategy,
         help = "Parent selection strategies to test"
     )]
-    selection_strategies: Vec<ga::SelectionStrategy>,
+    pub selection_strategies: Vec<ga::SelectionStrategy>,
     #[arg(
         long = "tournament-sizes",
         value_name = "COUNT[,COUNT]",
@@ -106,7 +108,7 @@ struct SweepConfig {
         value_parser = parse_positive_usize,
         help = "Tournament sizes to test"
     )]
-    tournament_sizes: Vec<usize>,
+    pub tournament_sizes: Vec<usize>,
     #[arg(
         long = "local-search-rates",
         value_name = "RATE[,RATE]",
@@ -115,7 +117,7 @@ struct SweepConfig {
         value_parser = parse_unit_interval,
         help = "Local-search rates to test"
     )]
-    local_search_rates: Vec<f32>,
+    pub local_search_rates: Vec<f32>,
     #[arg(
         long = "local-search-attempts",
         value_name = "COUNT[,COUNT]",
@@ -124,7 +126,7 @@ struct SweepConfig {
         value_parser = parse_usize,
         help = "Local-search swap-attempt counts to test"
     )]
-    local_search_attempts: Vec<usize>,
+    pub local_search_attempts: Vec<usize>,
 }
 
 struct SweepRun {
@@ -148,77 +150,6 @@ struct SweepCase {
     local_search_attempts: usize,
 }
 
-fn parse_positive_u16(raw_value: &str) -> Result<u16, String> {
-    let value = raw_value
-        .parse::<u16>()
-        .map_err(|err| format!("invalid value `{raw_value}`: {err}"))?;
-
-    if value == 0 {
-        return Err("must be greater than 0".to_owned());
-    }
-
-    Ok(value)
-}
-
-fn parse_positive_usize(raw_value: &str) -> Result<usize, String> {
-    let value = raw_value
-        .parse::<usize>()
-        .map_err(|err| format!("invalid value `{raw_value}`: {err}"))?;
-
-    if value == 0 {
-        return Err("must be greater than 0".to_owned());
-    }
-
-    Ok(value)
-}
-
-fn parse_usize(raw_value: &str) -> Result<usize, String> {
-    raw_value
-        .parse::<usize>()
-        .map_err(|err| format!("invalid value `{raw_value}`: {err}"))
-}
-
-fn parse_positive_u32(raw_value: &str) -> Result<u32, String> {
-    let value = raw_value
-        .parse::<u32>()
-        .map_err(|err| format!("invalid value `{raw_value}`: {err}"))?;
-
-    if value == 0 {
-        return Err("must be greater than 0".to_owned());
-    }
-
-    Ok(value)
-}
-
-fn parse_unit_interval(raw_value: &str) -> Result<f32, String> {
-    let value = raw_value
-        .parse::<f32>()
-        .map_err(|err| format!("invalid value `{raw_value}`: {err}"))?;
-
-    if !value.is_finite() || !(0.0..=1.0).contains(&value) {
-        return Err("must be between 0.0 and 1.0".to_owned());
-    }
-
-    Ok(value)
-}
-
-fn parse_selection_strategy(raw_value: &str) -> Result<ga::SelectionStrategy, String> {
-    match raw_value.to_ascii_lowercase().as_str() {
-        "roulette" => Ok(ga::SelectionStrategy::Roulette),
-        "tournament" => Ok(ga::SelectionStrategy::Tournament),
-        _ => Err("must be one of: roulette, tournament".to_owned()),
-    }
-}
-
-fn seed_for_offset(seed_start: u64, seed_offset: usize) -> Result<u64, String> {
-    let seed_offset =
-        u64::try_from(seed_offset).map_err(|_| "seed offset does not fit into u64".to_owned())?;
-
-    seed_start
-        .checked_add(seed_offset)
-        .ok_or_else(|| "seed range overflows u64".to_owned())
-}
-
 fn run_single_seed(case: SweepCase, seed: u64) -> Result<SweepRun, ga::GaConfigError> {
     let config = GaConfig::new(case.size, case.population, case.epochs, seed)
         .with_mutation_rate(case.mutation_rate)
@@ -242,39 +173,15 @@ fn run_single_seed(case: SweepCase, seed: u64) -> Result<SweepRun, ga::GaConfigE
     })
 }
 
-fn median_u32(values: &mut [u32]) -> Option<f64> {
-    if values.is_empty() {
-        return None;
-    }
-
-    values.sort_unstable();
-    let middle = values.len() / 2;
-    if values.len() % 2 == 1 {
-        Some(f64::from(values[middle]))
-    } else {
-        Some((f64::from(values[middle - 1]) + f64::from(values[middle])) / 2.0)
-    }
-}
-
-fn median_u128(values: &mut [u128]) -> Option<f64> {
-    if values.is_empty() {
-        return None;
-    }
-
-    values.sort_unstable();
-    let middle = values.len() / 2;
-    if values.len() % 2 == 1 {
-        Some(values[middle] as f64)
-    } else {
-        Some((values[middle - 1] as f64 + values[middle] as f64) / 2.0)
-    }
-}
-
 fn format_optional(value: Option<f64>) -> String {
     value.map(|value| format!("{value:.1}")).unwrap_or_default()
 }
 
-fn print_summary(case: SweepCase, runs: &[SweepRun]) {
+fn print_summary(
+    case: SweepCase,
+    runs: &[SweepRun],
+    json_rows: Option<&mut Vec<serde_json::Value>>,
+) {
     let solved_count = runs.iter().filter(|run| run.solved_epoch.is_some()).count();
     let solve_rate = solved_count as f64 / runs.len() as f64;
     let total_elapsed_ms = runs.iter().map(|run| run.elapsed_ms).sum::<u128>();
@@ -306,21 +213,50 @@ fn print_summary(case: SweepCase, runs: &[SweepRun]) {
     let local_search_rate = case.local_search_rate;
     let local_search_attempts = case.local_search_attempts;
     let seed_count = runs.len();
-    let median_solved_epoch = format_optional(median_u32(&mut solved_epochs));
-    let median_elapsed_ms = format_optional(median_u128(&mut elapsed_values));
-    let best_conflicts_median = format_optional(median_u32(&mut best_conflicts));
+    let median_solved_epoch = median_u32(&mut solved_epochs);
+    let median_elapsed_ms = median_u128(&mut elapsed_values);
+    let best_conflicts_median = median_u32(&mut best_conflicts);
+
+    if let Some(json_rows) = json_rows {
+        json_rows.push(json!({
+            "size": size,
+            "population": population,
+            "epochs": epochs,
+            "mutation_rate": json_f32(mutation_rate),
+            "elite_ratio": json_f32(elite_ratio),
+            "offspring_ratio": json_f32(offspring_ratio),
+            "min_diversity_ratio": json_f32(min_diversity_ratio),
+            "selection_strategy": selection_strategy.to_string(),
+            "tournament_size": tournament_size,
+            "local_search_rate": json_f32(local_search_rate),
+            "local_search_attempts": local_search_attempts,
+            "seeds": seed_count,
+            "solved": solved_count,
+            "solve_rate": solve_rate,
+            "median_solved_epoch": median_solved_epoch,
+            "median_elapsed_ms": median_elapsed_ms,
+            "total_elapsed_ms": total_elapsed_ms,
+            "best_conflicts_median": best_conflicts_median,
+            "best_conflicts_min": best_conflicts_min,
+        }));
+        return;
+    }
 
+    let median_solved_epoch = format_optional(median_solved_epoch);
+    let median_elapsed_ms = format_optional(median_elapsed_ms);
+    let best_conflicts_median = format_optional(best_conflicts_median);
     println!(
         "{size},{population},{epochs},{mutation_rate:.6},{elite_ratio:.6},{offspring_ratio:.6},{min_diversity_ratio:.6},{selection_strategy},{tournament_size},{local_search_rate:.6},{local_search_attempts},{seed_count},{solved_count},{solve_rate:.3},{median_solved_epoch},{median_elapsed_ms},{total_elapsed_ms},{best_conflicts_median},{best_conflicts_min}",
     );
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let sweep_config = SweepConfig::parse();
-
-    println!(
-        "size,population,epochs,mutation_rate,elite_ratio,offspring_ratio,min_diversity_ratio,selection_strategy,tournament_size,local_search_rate,local_search_attempts,seeds,solved,solve_rate,median_solved_epoch,median_elapsed_ms,total_elapsed_ms,best_conflicts_median,best_conflicts_min"
-    );
+pub fn run(sweep_config: &SweepConfig, common: &CommonOptions) -> Result<(), String> {
+    let mut json_rows = Vec::new();
+    if !common.json_output {
+        println!(
+            "size,population,epochs,mutation_rate,elite_ratio,offspring_ratio,min_diversity_ratio,selection_strategy,tournament_size,local_search_rate,local_search_attempts,seeds,solved,solve_rate,median_solved_epoch,median_elapsed_ms,total_elapsed_ms,best_conflicts_median,best_conflicts_min"
+        );
+    }
 
     for &size in &sweep_config.sizes {
         for &population in &sweep_config.populations {
@@ -355,11 +291,19 @@ fn main() -> Result<(), Box<dyn Error>> {
                                                         sweep_config.seed_start,
                                                         seed_offset,
                                                     )?;
-                                                    let run = run_single_seed(case, seed)?;
+                                                    let run = run_single_seed(case, seed).map_err(
+                                                        |error| {
+                                                            format!("invalid GA config: {error}")
+                                                        },
+                                                    )?;
                                                     runs.push(run);
                                                 }
 
-                                                print_summary(case, &runs);
+                                                print_summary(
+                                                    case,
+                                                    &runs,
+                                                    common.json_output.then_some(&mut json_rows),
+                                                );
                                             }
                                         }
                                     }
@@ -372,5 +316,11 @@ fn main() -> Result<(), Box<dyn Error>> {
         }
     }
 
+    if common.json_output {
+        serde_json::to_writer_pretty(std::io::stdout(), &json_rows)
+            .map_err(|error| format!("failed to write JSON summary: {error}"))?;
+        println!();
+    }
+
     Ok(())
 }
@@ -0,0 +1,96 @@
+use clap::Args;
+use n_queens_problem::{board, ga, symmetry};
+use serde_json::json;
+
+use super::{BoardInput, CommonOptions};
+
+const VERIFY_EXIT_CONFLICTED: i32 = 1;
+const VERIFY_EXIT_MALFORMED: i32 = 2;
+
+#[derive(Debug, Args)]
+pub struct VerifyConfig {
+    #[command(flatten)]
+    pub input: BoardInput,
+}
+
+pub fn run(verify_config: &VerifyConfig, common: &CommonOptions) -> Result<i32, String> {
+    let positions = board::parse_board(&verify_config.input.read()?)
+        .map_err(|error| format!("invalid board: {error}"))?;
+
+    let chromosome = match ga::chromosome::Chromosome::try_new(positions.clone()) {
+        Ok(chromosome) => chromosome,
+        Err(error) => {
+            if common.json_output {
+                println!(
+                    "{}",
+                    json!({
+                        "board_size": positions.len(),
+                        "positions": positions,
+                        "valid": false,
+                        "error": error.to_string(),
+                        "solution": false,
+                    })
+                );
+            } else {
+                println!("Board Size: {}", positions.len());
+                println!("Positions: {positions:?}");
+                println!("Valid Permutation: no ({error})");
+                println!("Result: malformed board");
+            }
+            return Ok(VERIFY_EXIT_MALFORMED);
+        }
+    };
+
+    let positions = chromosome.get_positions();
+    let attacking_pairs = chromosome
+        .get_attacking_pairs()
+        .into_iter()
+        .map(|(x_one, x_two)| ((x_one, positions[x_one]), (x_two, positions[x_two])))
+        .collect::<Vec<_>>();
+    let solved = chromosome.get_conflicts_sum() == 0;
+
+    if common.json_output {
+        println!(
+            "{}",
+            json!({
+                "board_size": positions.len(),
+                "positions": positions,
+                "valid": true,
+                "error": null,
+                "solution": solved,
+                "conflicts": chromosome.get_conflicts(),
+                "conflicts_sum": chromosome.get_conflicts_sum(),
+                "attacking_pairs": attacking_pairs
+                    .iter()
+                    .map(|&((x_one, y_one), (x_two, y_two))| json!([[x_one, y_one], [x_two, y_two]]))
+                    .collect::<Vec<_>>(),
+                "symmetry_group": symmetry::classify(positions).to_string(),
+            })
+        );
+    } else {
+        println!("Board Size: {}", positions.len());
+        println!("Positions: {positions:?}");
+        println!("Valid Permutation: yes");
+        println!("Queen Conflicts: {:?}", chromosome.get_conflicts());
+        println!("Conflicts Sum: {}", chromosome.get_conflicts_sum());
+        if attacking_pairs.is_empty() {
+            println!("Attacking Pairs: none");
+        } else {
+            println!("Attacking Pairs:");
+            for ((x_one, y_one), (x_two, y_two)) in &attacking_pairs {
+                println!("  ({x_one}, {y_one}) x ({x_two}, {y_two})");
+            }
+        }
+        println!("Symmetry: {}", symmetry::classify(positions));
+        println!(
+            "Result: {}",
+            if solved {
+                "solution"
+            } else {
+                "valid board with conflicts"
+            }
+        );
+    }
+
+    Ok(if solved { 0 } else { VERIFY_EXIT_CONFLICTED })
+}
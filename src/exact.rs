@@ -0,0 +1,234 @@
+use crate::symmetry::SymmetryConstraint;
+
+pub fn count_solutions(size: u16, symmetry: SymmetryConstraint) -> u64 {
+    let mut count = 0;
+    search(size, symmetry, |_| {
+        count += 1;
+        true
+    });
+    count
+}
+
+pub fn find_solutions(size: u16, symmetry: SymmetryConstraint, limit: usize) -> Vec<Vec<u16>> {
+    let mut solutions = Vec::new();
+    if limit == 0 {
+        return solutions;
+    }
+
+    search(size, symmetry, |positions| {
+        solutions.push(positions.to_vec());
+        solutions.len() < limit
+    });
+    solutions
+}
+
+pub fn first_solution(size: u16, symmetry: SymmetryConstraint) -> Option<Vec<u16>> {
+    find_solutions(size, symmetry, 1).pop()
+}
+
+// Like `first_solution`, but calls `should_stop` at every search node and gives up with
+// `None` as soon as it returns true.
+pub fn first_solution_until<S>(
+    size: u16,
+    symmetry: SymmetryConstraint,
+    mut should_stop: S,
+) -> Option<Vec<u16>>
+where
+    S: FnMut() -> bool,
+{
+    let mut solution = None;
+    search_until(size, symmetry, &mut should_stop, |positions| {
+        solution = Some(positions.to_vec());
+        false
+    });
+    solution
+}
+
+// Backtracks column by column, placing the whole symmetry orbit of each queen at once so only
+// boards invariant under `symmetry` are ever visited.
+fn search<F>(size: u16, symmetry: SymmetryConstraint, on_solution: F)
+where
+    F: FnMut(&[u16]) -> bool,
+{
+    search_until(size, symmetry, &mut || false, on_solution);
+}
+
+fn search_until<S, F>(
+    size: u16,
+    symmetry: SymmetryConstraint,
+    should_stop: &mut S,
+    mut on_solution: F,
+) where
+    S: FnMut() -> bool,
+    F: FnMut(&[u16]) -> bool,
+{
+    if size == 0 || !symmetry.supports_size(size) {
+        return;
+    }
+
+    let mut board = Board::new(size);
+    place_from_column(&mut board, symmetry, 0, should_stop, &mut on_solution);
+}
+
+fn place_from_column<S, F>(
+    board: &mut Board,
+    symmetry: SymmetryConstraint,
+    column: u16,
+    should_stop: &mut S,
+    on_solution: &mut F,
+) -> bool
+where
+    S: FnMut() -> bool,
+    F: FnMut(&[u16]) -> bool,
+{
+    if should_stop() {
+        return false;
+    }
+
+    let Some(column) = (column..board.size).find(|&x| board.positions[usize::from(x)].is_none())
+    else {
+        let positions = board
+            .positions
+            .iter()
+            .map(|position| position.expect("every column should hold a queen"))
+            .collect::<Vec<_>>();
+        return on_solution(&positions);
+    };
+
+    for row in 0..board.size {
+        let orbit = symmetry.orbit(board.size, column, row);
+        let placed = board.try_place_all(&orbit);
+        if placed == orbit.len() {
+            let keep_searching =
+                place_from_column(board, symmetry, column + 1, should_stop, on_solution);
+            board.remove_all(&orbit[..placed]);
+            if !keep_searching {
+                return false;
+            }
+        } else {
+            board.remove_all(&orbit[..placed]);
+        }
+    }
+
+    true
+}
+
+struct Board {
+    size: u16,
+    positions: Vec<Option<u16>>,
+    rows: Vec<bool>,
+    descending_diagonals: Vec<bool>,
+    ascending_diagonals: Vec<bool>,
+}
+
+impl Board {
+    fn new(size: u16) -> Self {
+        let size_usize = usize::from(size);
+        let diagonal_span = size_usize * 2 - 1;
+        Self {
+            size,
+            positions: vec![None; size_usize],
+            rows: vec![false; size_usize],
+            descending_diagonals: vec![false; diagonal_span],
+            ascending_diagonals: vec![false; diagonal_span],
+        }
+    }
+
+    fn try_place_all(&mut self, cells: &[(u16, u16)]) -> usize {
+        cells
+            .iter()
+            .take_while(|&&(x, y)| self.try_place(x, y))
+            .count()
+    }
+
+    fn try_place(&mut self, x: u16, y: u16) -> bool {
+        let (column, row, descending, ascending) = self.indices(x, y);
+        if self.positions[column].is_some()
+            || self.rows[row]
+            || self.descending_diagonals[descending]
+            || self.ascending_diagonals[ascending]
+        {
+            return false;
+        }
+
+        self.positions[column] = Some(y);
+        self.rows[row] = true;
+        self.descending_diagonals[descending] = true;
+        self.ascending_diagonals[ascending] = true;
+        true
+    }
+
+    fn remove_all(&mut self, cells: &[(u16, u16)]) {
+        for &(x, y) in cells {
+            let (column, row, descending, ascending) = self.indices(x, y);
+            self.positions[column] = None;
+            self.rows[row] = false;
+            self.descending_diagonals[descending] = false;
+            self.ascending_diagonals[ascending] = false;
+        }
+    }
+
+    fn indices(&self, x: u16, y: u16) -> (usize, usize, usize, usize) {
+        let x = usize::from(x);
+        let y = usize::from(y);
+        let diagonal_offset = usize::from(self.size) - 1;
+        (x, y, x + diagonal_offset - y, x + y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{count_solutions, find_solutions, first_solution, first_solution_until};
+    use crate::{
+        ga::chromosome::Chromosome,
+        symmetry::{SymmetryConstraint, is_invariant},
+    };
+
+    #[test]
+    fn test_count_solutions_matches_known_totals() {
+        let expected = [1, 0, 0, 2, 10, 4, 40, 92];
+        for (size, &count) in (1u16..).zip(&expected) {
+            assert_eq!(count_solutions(size, SymmetryConstraint::None), count);
+        }
+    }
+
+    #[test]
+    fn test_count_solutions_respects_symmetry() {
+        assert_eq!(count_solutions(4, SymmetryConstraint::Rotation90), 2);
+        assert_eq!(count_solutions(5, SymmetryConstraint::Rotation90), 2);
+        assert_eq!(count_solutions(8, SymmetryConstraint::Rotation90), 0);
+        assert_eq!(count_solutions(6, SymmetryConstraint::Rotation180), 4);
+        assert_eq!(count_solutions(8, SymmetryConstraint::Rotation180), 4);
+        assert_eq!(count_solutions(6, SymmetryConstraint::Rotation90), 0);
+        assert_eq!(count_solutions(1, SymmetryConstraint::Reflection), 1);
+        assert_eq!(count_solutions(8, SymmetryConstraint::Reflection), 0);
+    }
+
+    #[test]
+    fn test_find_solutions_returns_valid_symmetric_boards() {
+        let solutions = find_solutions(10, SymmetryConstraint::Rotation180, 3);
+
+        assert_eq!(solutions.len(), 3);
+        for positions in solutions {
+            assert!(is_invariant(&positions, SymmetryConstraint::Rotation180));
+            assert_eq!(Chromosome::new(positions).get_conflicts_sum(), 0);
+        }
+        assert_eq!(first_solution(3, SymmetryConstraint::None), None);
+    }
+
+    #[test]
+    fn test_first_solution_until_gives_up_when_asked() {
+        let mut nodes = 0;
+        let solution = first_solution_until(30, SymmetryConstraint::None, || {
+            nodes += 1;
+            nodes > 100
+        });
+
+        assert_eq!(solution, None);
+        assert_eq!(nodes, 101);
+        assert_eq!(
+            first_solution_until(8, SymmetryConstraint::None, || false),
+            first_solution(8, SymmetryConstraint::None)
+        );
+    }
+}
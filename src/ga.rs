@@ -1,17 +1,35 @@
-use std::{collections::HashSet, error::Error, fmt, time::Instant};
+use std::{
+    collections::HashSet,
+    error::Error,
+    fmt,
+    time::{Duration, Instant},
+};
 
 use rand::{Rng, RngExt, SeedableRng, rngs::StdRng, seq::SliceRandom};
 use rayon::prelude::*;
 
-use self::chromosome::Chromosome;
+use self::{
+    chromosome::{Chromosome, ChromosomeError},
+    control::{ControlState, Controller, CrossoverOperator, MutationOperator, ParameterControl},
+    observer::{GaEvent, GaObserver, LogObserver, RunStart},
+    population::PopulationSummary,
+};
+use crate::symmetry::{self, SymmetryConstraint};
 
 pub mod chromosome;
+pub mod control;
+pub mod observer;
+pub mod population;
+pub mod run_log;
 
 const TARGET_EPOCH_PROGRESS_LOGS: u32 = 20;
+pub const DIVERSITY_SAMPLE_SIZE: usize = 128;
+const ENTROPY_COUNT_LIMIT: usize = 1 << 20;
 const TARGET_STAGNATION_RESETS: u32 = 20;
 const MIN_STAGNATION_RESET_EPOCHS: u32 = 50;
 const MAX_STAGNATION_RESET_EPOCHS: u32 = 500;
-const SOFT_RESTART_ELITE_RATIO_SCALE: f32 = 0.4;
+const RESTART_PERTURBATION_DIVISOR: usize = 4;
+const CONSTRUCTIVE_RESTART_CANDIDATES: usize = 8;
 const MUTATION_STAGNATION_BOOST_SCALE: f32 = 3.0;
 const MAX_ADAPTIVE_MUTATION_RATE: f32 = 0.60;
 const MIN_ADAPTIVE_ELITE_RATIO: f32 = 0.01;
@@ -21,9 +39,22 @@ pub const DEFAULT_ELITE_RATIO: f32 = 0.10;
 pub const DEFAULT_OFFSPRING_RATIO: f32 = 0.10;
 pub const DEFAULT_MIN_DIVERSITY_RATIO: f32 = 0.10;
 pub const DEFAULT_SELECTION_STRATEGY: SelectionStrategy = SelectionStrategy::Roulette;
+pub const DEFAULT_DIVERSITY_MEASURE: DiversityMeasure = DiversityMeasure::UniqueRatio;
 pub const DEFAULT_TOURNAMENT_SIZE: usize = 3;
 pub const DEFAULT_LOCAL_SEARCH_RATE: f32 = 0.0;
 pub const DEFAULT_LOCAL_SEARCH_ATTEMPTS: usize = 8;
+pub const DEFAULT_SYMMETRY_CONSTRAINT: SymmetryConstraint = SymmetryConstraint::None;
+pub const DEFAULT_COUNT_SYMMETRY_CLASSES: bool = false;
+pub const DEFAULT_POPULATION_SUMMARY: bool = false;
+pub const DEFAULT_RESTART_KEEP_RATIO: f32 = 0.4;
+pub const DEFAULT_RESTART_KIND: RestartKind = RestartKind::Random;
+pub const DEFAULT_PARAMETER_CONTROL: ParameterControl = ParameterControl::Stagnation;
+pub const DEFAULT_RESTART_POLICY: RestartPolicy = RestartPolicy {
+    interval: None,
+    keep_ratio: DEFAULT_RESTART_KEEP_RATIO,
+    kind: DEFAULT_RESTART_KIND,
+    adaptive: true,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SelectionStrategy {
@@ -40,6 +71,107 @@ impl fmt::Display for SelectionStrategy {
     }
 }
 
+// Which population diversity `min_diversity_ratio` is compared against; every measure
+// lies in 0..=1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiversityMeasure {
+    UniqueRatio,
+    GeneEntropy,
+    PairwiseDistance,
+    BestDistance,
+}
+
+impl fmt::Display for DiversityMeasure {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UniqueRatio => formatter.write_str("unique"),
+            Self::GeneEntropy => formatter.write_str("entropy"),
+            Self::PairwiseDistance => formatter.write_str("pairwise"),
+            Self::BestDistance => formatter.write_str("best-distance"),
+        }
+    }
+}
+
+// How the chromosomes replaced by a soft restart are generated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RestartKind {
+    Random,
+    // Copies of the best chromosome with up to a quarter of its queens swapped.
+    PerturbBest,
+    // One greedy board per replacement, built column by column with each queen on the
+    // least attacked of a few sampled free rows. Not available with a symmetry constraint.
+    Constructive,
+}
+
+impl fmt::Display for RestartKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Random => formatter.write_str("random"),
+            Self::PerturbBest => formatter.write_str("perturb-best"),
+            Self::Constructive => formatter.write_str("constructive"),
+        }
+    }
+}
+
+// How a run reacts to stagnation: after `interval` epochs without improving the best
+// conflicts sum, the population is soft-restarted, keeping `keep_ratio` of its elites.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RestartPolicy {
+    // `None` scales the interval with the epoch limit.
+    pub interval: Option<u32>,
+    pub keep_ratio: f32,
+    pub kind: RestartKind,
+    // When false, no `ParameterControl` runs: every epoch uses the configured mutation
+    // rate, elite ratio, and operators.
+    pub adaptive: bool,
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        DEFAULT_RESTART_POLICY
+    }
+}
+
+impl RestartPolicy {
+    pub fn with_interval(mut self, interval: Option<u32>) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    pub fn with_keep_ratio(mut self, keep_ratio: f32) -> Self {
+        self.keep_ratio = keep_ratio;
+        self
+    }
+
+    pub fn with_kind(mut self, kind: RestartKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    pub fn with_adaptation(mut self, adaptive: bool) -> Self {
+        self.adaptive = adaptive;
+        self
+    }
+
+    // Stagnant epochs before a soft restart in a run of `max_epoch_count` epochs.
+    pub fn interval_for(&self, max_epoch_count: u32) -> u32 {
+        self.interval
+            .unwrap_or_else(|| stagnation_reset_interval(max_epoch_count))
+    }
+
+    pub fn validate(&self) -> Result<(), GaConfigError> {
+        if self.interval == Some(0) {
+            return Err(GaConfigError::RestartIntervalZero);
+        }
+
+        if !is_unit_interval(self.keep_ratio) {
+            return Err(GaConfigError::InvalidRestartKeepRatio);
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct EpochMetrics {
     epoch: u32,
@@ -48,12 +180,107 @@ pub struct EpochMetrics {
     elapsed_ms: u128,
     average_conflicts_sum: f32,
     unique_chromosomes: usize,
+    unique_symmetry_classes: Option<usize>,
+    gene_entropy: Option<f32>,
+    mean_pairwise_distance: Option<f32>,
+    mean_best_distance: Option<f32>,
     mutation_rate: f32,
     elite_ratio: f32,
     offspring_count: usize,
     local_search_improvements: usize,
     stagnation_epochs: u32,
+    stagnation_reset: bool,
     diversity_replacements: usize,
+    phase_timings: PhaseTimings,
+    evaluations: EvaluationCounts,
+    total_evaluations: u64,
+    control: ControlState,
+}
+
+// Microseconds an epoch spent in each phase. `fitness_us` covers every fitness pass
+// outside the diversity refresh, including the one after a stagnation reset, and
+// `diversity_us` covers measuring diversity as well as refreshing the population.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PhaseTimings {
+    pub mating_us: u64,
+    pub mutation_us: u64,
+    pub local_search_us: u64,
+    pub selection_us: u64,
+    pub fitness_us: u64,
+    pub diversity_us: u64,
+}
+
+impl std::ops::AddAssign for PhaseTimings {
+    fn add_assign(&mut self, other: Self) {
+        self.mating_us += other.mating_us;
+        self.mutation_us += other.mutation_us;
+        self.local_search_us += other.local_search_us;
+        self.selection_us += other.selection_us;
+        self.fitness_us += other.fitness_us;
+        self.diversity_us += other.diversity_us;
+    }
+}
+
+impl PhaseTimings {
+    pub fn total_us(&self) -> u64 {
+        self.mating_us
+            + self.mutation_us
+            + self.local_search_us
+            + self.selection_us
+            + self.fitness_us
+            + self.diversity_us
+    }
+}
+
+// Objective evaluations an epoch performed. `full` counts chromosomes whose conflicts
+// were counted from scratch: offspring, restarted and refreshed chromosomes, injected
+// boards, and symmetric mutations and local-search trials. `swap_delta` counts swaps
+// scored incrementally: mutations, local-search trials, and fixed-queen repairs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EvaluationCounts {
+    pub full: u64,
+    pub swap_delta: u64,
+}
+
+impl std::ops::AddAssign for EvaluationCounts {
+    fn add_assign(&mut self, other: Self) {
+        self.full += other.full;
+        self.swap_delta += other.swap_delta;
+    }
+}
+
+impl EvaluationCounts {
+    pub fn total(&self) -> u64 {
+        self.full + self.swap_delta
+    }
+}
+
+// What is left of `GaConfig::max_evaluations` for the running epoch. Work is reserved
+// before it is done, so an epoch stops exactly at the budget instead of overshooting it.
+#[derive(Debug, Clone, Copy, Default)]
+struct EvaluationBudget {
+    left: Option<u64>,
+    exhausted: bool,
+}
+
+impl EvaluationBudget {
+    // Grants as many of `count` pieces of work costing `cost` evaluations each as the
+    // budget still covers, and notes when it had to refuse some.
+    fn take(&mut self, count: usize, cost: u64) -> usize {
+        let Some(left) = self.left.as_mut() else {
+            return count;
+        };
+        let granted = count.min(usize::try_from(*left / cost.max(1)).unwrap_or(usize::MAX));
+        *left -= granted as u64 * cost;
+        self.exhausted |= granted < count;
+        granted
+    }
+
+    fn refund(&mut self, evaluations: u64) {
+        if let Some(left) = self.left.as_mut() {
+            *left += evaluations;
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -63,10 +290,51 @@ struct EpochRecordContext {
     offspring_count: usize,
     local_search_improvements: usize,
     stagnation_epochs: u32,
+    stagnation_reset: bool,
     diversity_replacements: usize,
+    phase_timings: PhaseTimings,
+    evaluations: EvaluationCounts,
+    control: ControlState,
     elapsed_ms: u128,
 }
 
+// Which of the optional diversity measures `population_metrics` computes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct DiversityMeasures {
+    gene_entropy: bool,
+    pairwise_distance: bool,
+    best_distance: bool,
+}
+
+impl DiversityMeasures {
+    const NONE: Self = Self {
+        gene_entropy: false,
+        pairwise_distance: false,
+        best_distance: false,
+    };
+    const ALL: Self = Self {
+        gene_entropy: true,
+        pairwise_distance: true,
+        best_distance: true,
+    };
+
+    fn only(measure: DiversityMeasure) -> Self {
+        Self {
+            gene_entropy: measure == DiversityMeasure::GeneEntropy,
+            pairwise_distance: measure == DiversityMeasure::PairwiseDistance,
+            best_distance: measure == DiversityMeasure::BestDistance,
+        }
+    }
+
+    fn missing_from(metrics: &EpochMetrics) -> Self {
+        Self {
+            gene_entropy: metrics.gene_entropy.is_none(),
+            pairwise_distance: metrics.mean_pairwise_distance.is_none(),
+            best_distance: metrics.mean_best_distance.is_none(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 struct PopulationMetrics {
     population_size: usize,
@@ -74,6 +342,24 @@ struct PopulationMetrics {
     best_conflicts_sum: u32,
     average_conflicts_sum: f32,
     unique_chromosomes: usize,
+    unique_symmetry_classes: Option<usize>,
+    gene_entropy: Option<f32>,
+    mean_pairwise_distance: Option<f32>,
+    mean_best_distance: Option<f32>,
+}
+
+impl PopulationMetrics {
+    fn diversity(&self, measure: DiversityMeasure) -> f32 {
+        match measure {
+            DiversityMeasure::UniqueRatio if self.population_size == 0 => 0.0,
+            DiversityMeasure::UniqueRatio => {
+                self.unique_chromosomes as f32 / self.population_size as f32
+            }
+            DiversityMeasure::GeneEntropy => self.gene_entropy.unwrap_or_default(),
+            DiversityMeasure::PairwiseDistance => self.mean_pairwise_distance.unwrap_or_default(),
+            DiversityMeasure::BestDistance => self.mean_best_distance.unwrap_or_default(),
+        }
+    }
 }
 
 impl EpochMetrics {
@@ -101,6 +387,10 @@ impl EpochMetrics {
         self.unique_chromosomes
     }
 
+    pub fn unique_symmetry_classes(&self) -> Option<usize> {
+        self.unique_symmetry_classes
+    }
+
     pub fn diversity_ratio(&self) -> f32 {
         if self.population_size == 0 {
             0.0
@@ -109,6 +399,35 @@ impl EpochMetrics {
         }
     }
 
+    // The three measures below are `None` for epochs that only computed the configured
+    // diversity measure; see `GaConfig::with_diversity_report_interval`.
+
+    // Mean per-column Shannon entropy of the queens' rows, scaled to 0..=1.
+    pub fn gene_entropy(&self) -> Option<f32> {
+        self.gene_entropy
+    }
+
+    // Mean Hamming distance between chromosomes as a fraction of the board size, over a
+    // sample of at most `DIVERSITY_SAMPLE_SIZE` chromosomes.
+    pub fn mean_pairwise_distance(&self) -> Option<f32> {
+        self.mean_pairwise_distance
+    }
+
+    // Mean Hamming distance from each chromosome to the population's best, as a fraction
+    // of the board size.
+    pub fn mean_best_distance(&self) -> Option<f32> {
+        self.mean_best_distance
+    }
+
+    pub fn diversity(&self, measure: DiversityMeasure) -> Option<f32> {
+        match measure {
+            DiversityMeasure::UniqueRatio => Some(self.diversity_ratio()),
+            DiversityMeasure::GeneEntropy => self.gene_entropy,
+            DiversityMeasure::PairwiseDistance => self.mean_pairwise_distance,
+            DiversityMeasure::BestDistance => self.mean_best_distance,
+        }
+    }
+
     pub fn mutation_rate(&self) -> f32 {
         self.mutation_rate
     }
@@ -129,24 +448,70 @@ impl EpochMetrics {
         self.stagnation_epochs
     }
 
+    // Whether the population was soft-restarted for stagnating at the start of this epoch.
+    pub fn stagnation_reset(&self) -> bool {
+        self.stagnation_reset
+    }
+
     pub fn diversity_replacements(&self) -> usize {
         self.diversity_replacements
     }
+
+    pub fn phase_timings(&self) -> PhaseTimings {
+        self.phase_timings
+    }
+
+    pub fn evaluations(&self) -> EvaluationCounts {
+        self.evaluations
+    }
+
+    // Evaluations of every kind from the start of the run through this epoch.
+    pub fn total_evaluations(&self) -> u64 {
+        self.total_evaluations
+    }
+
+    pub fn control(&self) -> ControlState {
+        self.control
+    }
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct RunMetrics {
     epochs: Vec<EpochMetrics>,
     solved_epoch: Option<u32>,
+    evaluation_budget_reached: bool,
     total_elapsed_ms: u128,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EpochOutcome {
+    Improved,
+    Stagnant,
+    Solved,
+    EpochLimitReached,
+    EvaluationBudgetReached,
+    // No epoch ran because the run had already finished.
+    Finished,
+}
+
+// Loop state of a run driven by `init` and `step`, kept between epochs.
+#[derive(Debug, Clone, Default)]
+struct RunState {
+    run_metrics: RunMetrics,
+    active_time: Duration,
+    best_conflicts_sum: u32,
+    stagnation_epochs: u32,
+    epoch: u32,
+    finished: bool,
+}
+
 #[derive(Debug, Clone)]
 pub struct EpochSnapshot {
     metrics: EpochMetrics,
     best_positions: Vec<u16>,
     best_conflicts: Vec<u32>,
     best_conflicts_sum: u32,
+    population_summary: Option<PopulationSummary>,
 }
 
 impl RunMetrics {
@@ -162,12 +527,41 @@ impl RunMetrics {
         self.total_elapsed_ms
     }
 
+    // Whether the run ended unsolved because it used up `GaConfig::max_evaluations`.
+    pub fn evaluation_budget_reached(&self) -> bool {
+        self.evaluation_budget_reached
+    }
+
+    pub fn total_evaluations(&self) -> EvaluationCounts {
+        let mut total = EvaluationCounts::default();
+        for metrics in &self.epochs {
+            total += metrics.evaluations;
+        }
+        total
+    }
+
+    fn evaluations_so_far(&self) -> u64 {
+        self.epochs
+            .last()
+            .map_or(0, |metrics| metrics.total_evaluations)
+    }
+
+    // Each phase's time summed over every recorded epoch.
+    pub fn total_phase_timings(&self) -> PhaseTimings {
+        let mut total = PhaseTimings::default();
+        for metrics in &self.epochs {
+            total += metrics.phase_timings;
+        }
+        total
+    }
+
     fn record_epoch(
         &mut self,
         epoch: u32,
         population_metrics: PopulationMetrics,
         context: EpochRecordContext,
     ) {
+        let total_evaluations = self.evaluations_so_far() + context.evaluations.total();
         self.epochs.push(EpochMetrics {
             epoch,
             best_conflicts_sum: population_metrics.best_conflicts_sum,
@@ -175,12 +569,21 @@ impl RunMetrics {
             elapsed_ms: context.elapsed_ms,
             average_conflicts_sum: population_metrics.average_conflicts_sum,
             unique_chromosomes: population_metrics.unique_chromosomes,
+            unique_symmetry_classes: population_metrics.unique_symmetry_classes,
+            gene_entropy: population_metrics.gene_entropy,
+            mean_pairwise_distance: population_metrics.mean_pairwise_distance,
+            mean_best_distance: population_metrics.mean_best_distance,
             mutation_rate: context.mutation_rate,
             elite_ratio: context.elite_ratio,
             offspring_count: context.offspring_count,
             local_search_improvements: context.local_search_improvements,
             stagnation_epochs: context.stagnation_epochs,
+            stagnation_reset: context.stagnation_reset,
             diversity_replacements: context.diversity_replacements,
+            phase_timings: context.phase_timings,
+            evaluations: context.evaluations,
+            total_evaluations,
+            control: context.control,
         });
     }
 
@@ -209,6 +612,15 @@ impl EpochSnapshot {
     pub fn best_conflicts_sum(&self) -> u32 {
         self.best_conflicts_sum
     }
+
+    // Present when the run was configured with `GaConfig::with_population_summary`.
+    pub fn population_summary(&self) -> Option<&PopulationSummary> {
+        self.population_summary.as_ref()
+    }
+
+    pub fn take_population_summary(&mut self) -> Option<PopulationSummary> {
+        self.population_summary.take()
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -221,10 +633,18 @@ pub struct GaConfig {
     pub elite_ratio: f32,
     pub offspring_ratio: f32,
     pub min_diversity_ratio: f32,
+    pub diversity_measure: DiversityMeasure,
     pub selection_strategy: SelectionStrategy,
     pub tournament_size: usize,
     pub local_search_rate: f32,
     pub local_search_attempts: usize,
+    pub symmetry: SymmetryConstraint,
+    pub count_symmetry_classes: bool,
+    pub population_summary: bool,
+    pub diversity_report_interval: Option<u32>,
+    pub max_evaluations: Option<u64>,
+    pub restart_policy: RestartPolicy,
+    pub parameter_control: ParameterControl,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -232,12 +652,20 @@ pub enum GaConfigError {
     BoardSizeZero,
     InitialPopulationZero,
     MaxEpochCountZero,
+    MaxEvaluationsZero,
+    MaxEvaluationsBelowSpent,
+    DiversityReportIntervalZero,
     InvalidMutationRate,
     InvalidEliteRatio,
     InvalidOffspringRatio,
     InvalidMinDiversityRatio,
     InvalidLocalSearchRate,
     TournamentSizeZero,
+    RestartIntervalZero,
+    InvalidRestartKeepRatio,
+    ConstructiveRestartWithSymmetry,
+    ParameterControlWithoutAdaptation,
+    UnsupportedSymmetry,
 }
 
 impl fmt::Display for GaConfigError {
@@ -250,6 +678,15 @@ impl fmt::Display for GaConfigError {
             Self::MaxEpochCountZero => {
                 formatter.write_str("max epoch count must be greater than 0")
             }
+            Self::MaxEvaluationsZero => {
+                formatter.write_str("evaluation budget must be greater than 0")
+            }
+            Self::MaxEvaluationsBelowSpent => formatter.write_str(
+                "evaluation budget must cover the initial population and evaluations already made",
+            ),
+            Self::DiversityReportIntervalZero => {
+                formatter.write_str("diversity report interval must be greater than 0")
+            }
             Self::InvalidMutationRate => {
                 formatter.write_str("mutation rate must be finite and between 0.0 and 1.0")
             }
@@ -267,12 +704,89 @@ impl fmt::Display for GaConfigError {
             Self::TournamentSizeZero => {
                 formatter.write_str("tournament size must be greater than 0")
             }
+            Self::RestartIntervalZero => {
+                formatter.write_str("restart interval must be greater than 0")
+            }
+            Self::InvalidRestartKeepRatio => {
+                formatter.write_str("restart keep ratio must be finite and between 0.0 and 1.0")
+            }
+            Self::ConstructiveRestartWithSymmetry => formatter
+                .write_str("constructive restarts cannot be combined with a symmetry constraint"),
+            Self::ParameterControlWithoutAdaptation => formatter.write_str(
+                "a parameter control other than stagnation needs adaptation to be enabled",
+            ),
+            Self::UnsupportedSymmetry => {
+                formatter.write_str("no solution of this board size has the requested symmetry")
+            }
         }
     }
 }
 
 impl Error for GaConfigError {}
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoardSeedError {
+    InvalidBoard(ChromosomeError),
+    SizeMismatch {
+        expected: usize,
+        actual: usize,
+    },
+    NotSymmetric,
+    FixedQueenOutOfBounds {
+        column: usize,
+        row: u16,
+    },
+    FixedQueensShareLine {
+        column_one: usize,
+        column_two: usize,
+    },
+    FixedQueensWithSymmetry,
+    EvaluationBudgetExhausted,
+}
+
+impl fmt::Display for BoardSeedError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidBoard(error) => write!(formatter, "invalid board: {error}"),
+            Self::SizeMismatch { expected, actual } => write!(
+                formatter,
+                "board has {actual} queens but the solver is configured for {expected}"
+            ),
+            Self::NotSymmetric => {
+                formatter.write_str("board does not have the configured symmetry")
+            }
+            Self::FixedQueenOutOfBounds { column, row } => {
+                write!(
+                    formatter,
+                    "fixed queen ({column}, {row}) is outside the board"
+                )
+            }
+            Self::FixedQueensShareLine {
+                column_one,
+                column_two,
+            } => write!(
+                formatter,
+                "fixed queens in columns {column_one} and {column_two} attack each other"
+            ),
+            Self::FixedQueensWithSymmetry => {
+                formatter.write_str("fixed queens cannot be combined with a symmetry constraint")
+            }
+            Self::EvaluationBudgetExhausted => {
+                formatter.write_str("the evaluation budget has no evaluation left for the board")
+            }
+        }
+    }
+}
+
+impl Error for BoardSeedError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidBoard(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
 impl GaConfig {
     pub fn new(size: u16, initial_population: usize, max_epoch_count: u32, seed: u64) -> Self {
         Self {
@@ -284,10 +798,18 @@ impl GaConfig {
             elite_ratio: DEFAULT_ELITE_RATIO,
             offspring_ratio: DEFAULT_OFFSPRING_RATIO,
             min_diversity_ratio: DEFAULT_MIN_DIVERSITY_RATIO,
+            diversity_measure: DEFAULT_DIVERSITY_MEASURE,
             selection_strategy: DEFAULT_SELECTION_STRATEGY,
             tournament_size: DEFAULT_TOURNAMENT_SIZE,
             local_search_rate: DEFAULT_LOCAL_SEARCH_RATE,
             local_search_attempts: DEFAULT_LOCAL_SEARCH_ATTEMPTS,
+            symmetry: DEFAULT_SYMMETRY_CONSTRAINT,
+            count_symmetry_classes: DEFAULT_COUNT_SYMMETRY_CLASSES,
+            population_summary: DEFAULT_POPULATION_SUMMARY,
+            diversity_report_interval: None,
+            max_evaluations: None,
+            restart_policy: DEFAULT_RESTART_POLICY,
+            parameter_control: DEFAULT_PARAMETER_CONTROL,
         }
     }
 
@@ -320,6 +842,11 @@ impl GaConfig {
         self
     }
 
+    pub fn with_diversity_measure(mut self, diversity_measure: DiversityMeasure) -> Self {
+        self.diversity_measure = diversity_measure;
+        self
+    }
+
     pub fn with_selection_strategy(mut self, selection_strategy: SelectionStrategy) -> Self {
         self.selection_strategy = selection_strategy;
         self
@@ -340,6 +867,47 @@ impl GaConfig {
         self
     }
 
+    pub fn with_symmetry(mut self, symmetry: SymmetryConstraint) -> Self {
+        self.symmetry = symmetry;
+        self
+    }
+
+    pub fn with_symmetry_class_counting(mut self, count_symmetry_classes: bool) -> Self {
+        self.count_symmetry_classes = count_symmetry_classes;
+        self
+    }
+
+    pub fn with_population_summary(mut self, population_summary: bool) -> Self {
+        self.population_summary = population_summary;
+        self
+    }
+
+    // Every epoch computes the configured diversity measure. The other measures are
+    // computed at epoch 0, every `interval` epochs and at the last epoch; `None` leaves
+    // only the last epoch.
+    pub fn with_diversity_report_interval(mut self, interval: Option<u32>) -> Self {
+        self.diversity_report_interval = interval;
+        self
+    }
+
+    // Ends the run at the epoch that reaches the budget, which stops its work part way
+    // so the total never exceeds it. It must cover the initial population; `None`
+    // means no budget.
+    pub fn with_max_evaluations(mut self, max_evaluations: Option<u64>) -> Self {
+        self.max_evaluations = max_evaluations;
+        self
+    }
+
+    pub fn with_restart_policy(mut self, restart_policy: RestartPolicy) -> Self {
+        self.restart_policy = restart_policy;
+        self
+    }
+
+    pub fn with_parameter_control(mut self, parameter_control: ParameterControl) -> Self {
+        self.parameter_control = parameter_control;
+        self
+    }
+
     pub fn validated(self) -> Result<Self, GaConfigError> {
         self.validate()?;
         Ok(self)
@@ -358,6 +926,21 @@ impl GaConfig {
             return Err(GaConfigError::MaxEpochCountZero);
         }
 
+        if self.max_evaluations == Some(0) {
+            return Err(GaConfigError::MaxEvaluationsZero);
+        }
+
+        if self
+            .max_evaluations
+            .is_some_and(|max_evaluations| max_evaluations < self.initial_population as u64)
+        {
+            return Err(GaConfigError::MaxEvaluationsBelowSpent);
+        }
+
+        if self.diversity_report_interval == Some(0) {
+            return Err(GaConfigError::DiversityReportIntervalZero);
+        }
+
         if !is_unit_interval(self.mutation_rate) {
             return Err(GaConfigError::InvalidMutationRate);
         }
@@ -382,6 +965,14 @@ impl GaConfig {
             return Err(GaConfigError::TournamentSizeZero);
         }
 
+        self.restart_policy.validate()?;
+        restart_kind_supports_symmetry(self.restart_policy.kind, self.symmetry)?;
+        parameter_control_supports_policy(self.parameter_control, self.restart_policy)?;
+
+        if !self.symmetry.supports_size(self.size) {
+            return Err(GaConfigError::UnsupportedSymmetry);
+        }
+
         Ok(())
     }
 }
@@ -396,10 +987,25 @@ pub struct GeneticAlgorithm {
     elite_ratio: f32,
     offspring_ratio: f32,
     min_diversity_ratio: f32,
+    diversity_measure: DiversityMeasure,
     selection_strategy: SelectionStrategy,
     tournament_size: usize,
     local_search_rate: f32,
     local_search_attempts: usize,
+    symmetry: SymmetryConstraint,
+    count_symmetry_classes: bool,
+    population_summary: bool,
+    diversity_report_interval: Option<u32>,
+    max_evaluations: Option<u64>,
+    restart_policy: RestartPolicy,
+    parameter_control: ParameterControl,
+    controller: Controller,
+    // Evaluations since the last recorded epoch, including those of injected boards.
+    pending_evaluations: EvaluationCounts,
+    evaluation_budget: EvaluationBudget,
+    fixed_queens: Vec<(usize, u16)>,
+    run_state: Option<RunState>,
+    observers: Vec<Box<dyn GaObserver>>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -410,10 +1016,18 @@ struct GeneticAlgorithmParams {
     elite_ratio: f32,
     offspring_ratio: f32,
     min_diversity_ratio: f32,
+    diversity_measure: DiversityMeasure,
     selection_strategy: SelectionStrategy,
     tournament_size: usize,
     local_search_rate: f32,
     local_search_attempts: usize,
+    symmetry: SymmetryConstraint,
+    count_symmetry_classes: bool,
+    population_summary: bool,
+    diversity_report_interval: Option<u32>,
+    max_evaluations: Option<u64>,
+    restart_policy: RestartPolicy,
+    parameter_control: ParameterControl,
 }
 
 impl GeneticAlgorithm {
@@ -428,230 +1042,754 @@ impl GeneticAlgorithm {
             elite_ratio: params.elite_ratio,
             offspring_ratio: params.offspring_ratio,
             min_diversity_ratio: params.min_diversity_ratio,
+            diversity_measure: params.diversity_measure,
             selection_strategy: params.selection_strategy,
             tournament_size: params.tournament_size,
             local_search_rate: params.local_search_rate,
             local_search_attempts: params.local_search_attempts,
+            symmetry: params.symmetry,
+            count_symmetry_classes: params.count_symmetry_classes,
+            population_summary: params.population_summary,
+            diversity_report_interval: params.diversity_report_interval,
+            max_evaluations: params.max_evaluations,
+            restart_policy: params.restart_policy,
+            parameter_control: params.parameter_control,
+            controller: Controller::new(
+                params
+                    .restart_policy
+                    .adaptive
+                    .then_some(params.parameter_control),
+                params.symmetry != SymmetryConstraint::None,
+                params.mutation_rate,
+            ),
+            pending_evaluations: EvaluationCounts::default(),
+            evaluation_budget: EvaluationBudget::default(),
+            fixed_queens: Vec::new(),
+            run_state: None,
+            observers: vec![Box::new(LogObserver::default())],
         }
     }
 
-    pub fn get_population_size(&self) -> usize {
-        self.population.len()
+    fn board_size(&self) -> usize {
+        self.population
+            .first()
+            .map_or(0, |chromosome| chromosome.get_positions().len())
     }
 
-    pub fn run_algorithm(&mut self) -> RunMetrics {
-        self.run_algorithm_with_progress(|_| true)
+    // Puts a known board, such as one arranged by hand, into the starting population.
+    pub fn seed_population(&mut self, positions: &[u16]) -> Result<(), BoardSeedError> {
+        self.population[0] = self.seed_chromosome(positions)?;
+        Ok(())
     }
 
-    pub fn run_algorithm_with_progress<F>(&mut self, mut on_epoch: F) -> RunMetrics
-    where
-        F: FnMut(&EpochSnapshot) -> bool,
-    {
-        let started_at = Instant::now();
-        let mut run_metrics = RunMetrics::default();
-
-        if self.population.is_empty() {
-            log::warn!("cannot run algorithm with empty population");
-            run_metrics.set_total_elapsed_ms(started_at.elapsed().as_millis());
-            return run_metrics;
+    // Swaps a board into a running population in place of its worst chromosome, so the
+    // population size is unchanged; it competes with the rest from the next epoch. The
+    // board's evaluation counts against the budget, so a spent budget rejects it.
+    pub fn inject_chromosome(&mut self, positions: &[u16]) -> Result<(), BoardSeedError> {
+        let mut chromosome = self.seed_chromosome(positions)?;
+        let mut budget = EvaluationBudget {
+            left: self
+                .max_evaluations
+                .map(|max_evaluations| max_evaluations.saturating_sub(self.evaluations_spent())),
+            exhausted: false,
+        };
+        if budget.take(1, 1) == 0 {
+            return Err(BoardSeedError::EvaluationBudgetExhausted);
         }
-
+        chromosome.set_mutation_rate(self.controller.initial_mutation_rate(&mut self.rng));
+        let worst_index = self
+            .population
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, chromosome)| chromosome.get_conflicts_sum())
+            .map_or(0, |(index, _)| index);
+        self.population[worst_index] = chromosome;
+        self.pending_evaluations.full += 1;
         self.calc_fitness();
-        let initial_population_metrics = population_metrics(&self.population);
-        self.update_best_chromosome(initial_population_metrics);
-        let mut best_conflicts_sum = self.get_best_chromosome().get_conflicts_sum();
-        let offspring_count =
-            offspring_count_for_population(self.target_population_size, self.offspring_ratio);
-        let mut initial_record_metrics = initial_population_metrics;
-        initial_record_metrics.best_conflicts_sum = best_conflicts_sum;
-        run_metrics.record_epoch(
-            0,
-            initial_record_metrics,
-            EpochRecordContext {
-                mutation_rate: self.mutation_rate,
-                elite_ratio: self.elite_ratio,
-                offspring_count,
-                local_search_improvements: 0,
-                stagnation_epochs: 0,
-                diversity_replacements: 0,
-                elapsed_ms: started_at.elapsed().as_millis(),
-            },
-        );
+        Ok(())
+    }
 
-        if best_conflicts_sum == 0 {
-            log::info!("ga solved in initial population");
-            run_metrics.mark_solved(0);
-            run_metrics.set_total_elapsed_ms(started_at.elapsed().as_millis());
-            self.report_latest_epoch(&run_metrics, &mut on_epoch);
-            return run_metrics;
+    fn seed_chromosome(&self, positions: &[u16]) -> Result<Chromosome, BoardSeedError> {
+        let expected = self.board_size();
+        if positions.len() != expected {
+            return Err(BoardSeedError::SizeMismatch {
+                expected,
+                actual: positions.len(),
+            });
         }
 
-        if !self.report_latest_epoch(&run_metrics, &mut on_epoch) {
-            run_metrics.set_total_elapsed_ms(started_at.elapsed().as_millis());
-            return run_metrics;
+        let mut chromosome =
+            Chromosome::try_new(positions.to_vec()).map_err(BoardSeedError::InvalidBoard)?;
+        if !symmetry::is_invariant(positions, self.symmetry) {
+            return Err(BoardSeedError::NotSymmetric);
         }
 
-        let progress_log_interval = epoch_progress_log_interval(self.max_epoch_count);
-        let stagnation_reset_interval = stagnation_reset_interval(self.max_epoch_count);
-        let mut stagnation_epochs = 0;
-        log::info!(
-            "running ga epochs={} population_size={} progress_log_interval={} stagnation_reset_interval={} initial_best_conflicts_sum={best_conflicts_sum} base_mutation_rate={} base_elite_ratio={} offspring_ratio={} offspring_count={} selection_strategy={} tournament_size={} local_search_rate={} local_search_attempts={}",
-            self.max_epoch_count,
-            self.get_population_size(),
-            progress_log_interval,
-            stagnation_reset_interval,
-            self.mutation_rate,
-            self.elite_ratio,
-            self.offspring_ratio,
-            offspring_count,
-            self.selection_strategy,
-            self.tournament_size,
-            self.local_search_rate,
-            self.local_search_attempts,
-        );
+        pin_queens(&mut chromosome, &self.fixed_queens);
+        Ok(chromosome)
+    }
 
-        for epoch in 0..self.max_epoch_count {
-            let epoch_number = epoch + 1;
+    // Keeps each `(column, row)` queen in place for the whole run. Every chromosome is
+    // repaired by swapping rows, so the population stays a set of permutations.
+    pub fn fix_queens(&mut self, queens: &[(usize, u16)]) -> Result<(), BoardSeedError> {
+        if !queens.is_empty() && self.symmetry != SymmetryConstraint::None {
+            return Err(BoardSeedError::FixedQueensWithSymmetry);
+        }
 
-            if stagnation_epochs >= stagnation_reset_interval {
-                let (_, reset_elite_ratio) = adaptive_ga_parameters(
-                    self.mutation_rate,
-                    self.elite_ratio,
-                    stagnation_epochs,
-                    stagnation_reset_interval,
-                );
-                let replaced_count = self.soft_restart_population(reset_elite_ratio);
-                self.calc_fitness();
-
-                let post_reset_population_metrics = population_metrics(&self.population);
-                self.update_best_chromosome(post_reset_population_metrics);
-                let post_reset_best_conflicts_sum =
-                    post_reset_population_metrics.best_conflicts_sum;
-                best_conflicts_sum =
-                    best_conflicts_sum.min(self.get_best_chromosome().get_conflicts_sum());
-
-                log::info!(
-                    "ga stagnation reset epoch={epoch_number} stagnant_epochs={stagnation_epochs} replaced={replaced_count} best_conflicts_sum={post_reset_best_conflicts_sum} elite_ratio={reset_elite_ratio:.4} population_size={}",
-                    self.get_population_size(),
-                );
+        let size = self.board_size();
+        for (index, &(column, row)) in queens.iter().enumerate() {
+            if column >= size || usize::from(row) >= size {
+                return Err(BoardSeedError::FixedQueenOutOfBounds { column, row });
+            }
 
-                stagnation_epochs = 0;
+            for &(other_column, other_row) in &queens[..index] {
+                let column_distance = column.abs_diff(other_column);
+                let row_distance = usize::from(row.abs_diff(other_row));
+                if column_distance == 0 || row_distance == 0 || column_distance == row_distance {
+                    return Err(BoardSeedError::FixedQueensShareLine {
+                        column_one: other_column,
+                        column_two: column,
+                    });
+                }
             }
+        }
 
-            let (epoch_mutation_rate, epoch_elite_ratio) = adaptive_ga_parameters(
-                self.mutation_rate,
-                self.elite_ratio,
-                stagnation_epochs,
-                stagnation_reset_interval,
-            );
+        self.fixed_queens = queens.to_vec();
+        self.pin_fixed_queens();
+        Ok(())
+    }
 
-            self.mate_random_chromosomes(offspring_count);
-            self.mutate_population(epoch_mutation_rate, epoch_elite_ratio);
-            let local_search_improvements =
-                self.improve_population_with_local_search(epoch_elite_ratio);
-            self.select_survivors(epoch_elite_ratio);
-            self.calc_fitness();
+    fn pin_fixed_queens(&mut self) {
+        if self.fixed_queens.is_empty() {
+            return;
+        }
 
-            let mut epoch_population_metrics = population_metrics(&self.population);
-            let diversity_replacements = self.refresh_low_diversity_population(
-                epoch_elite_ratio,
-                epoch_population_metrics.unique_chromosomes,
-            );
-            if diversity_replacements > 0 {
-                self.calc_fitness();
-                epoch_population_metrics = population_metrics(&self.population);
+        let fixed_queens = &self.fixed_queens;
+        self.pending_evaluations.swap_delta += self
+            .population
+            .par_iter_mut()
+            .map(|chromosome| pin_queens(chromosome, fixed_queens))
+            .sum::<u64>();
+    }
+
+    pub fn get_population_size(&self) -> usize {
+        self.population.len()
+    }
+
+    // The setters below take effect from the next epoch and accept the same values as
+    // the matching `GaConfig` fields.
+    pub fn set_mutation_rate(&mut self, mutation_rate: f32) -> Result<(), GaConfigError> {
+        if !is_unit_interval(mutation_rate) {
+            return Err(GaConfigError::InvalidMutationRate);
+        }
+        self.mutation_rate = mutation_rate;
+        self.controller.set_base_mutation_rate(mutation_rate);
+        Ok(())
+    }
+
+    pub fn set_elite_ratio(&mut self, elite_ratio: f32) -> Result<(), GaConfigError> {
+        if !is_unit_interval(elite_ratio) {
+            return Err(GaConfigError::InvalidEliteRatio);
+        }
+        self.elite_ratio = elite_ratio;
+        Ok(())
+    }
+
+    pub fn set_offspring_ratio(&mut self, offspring_ratio: f32) -> Result<(), GaConfigError> {
+        if !is_unit_interval(offspring_ratio) {
+            return Err(GaConfigError::InvalidOffspringRatio);
+        }
+        self.offspring_ratio = offspring_ratio;
+        Ok(())
+    }
+
+    pub fn set_min_diversity_ratio(
+        &mut self,
+        min_diversity_ratio: f32,
+    ) -> Result<(), GaConfigError> {
+        if !is_unit_interval(min_diversity_ratio) {
+            return Err(GaConfigError::InvalidMinDiversityRatio);
+        }
+        self.min_diversity_ratio = min_diversity_ratio;
+        Ok(())
+    }
+
+    pub fn set_local_search(
+        &mut self,
+        local_search_rate: f32,
+        local_search_attempts: usize,
+    ) -> Result<(), GaConfigError> {
+        if !is_unit_interval(local_search_rate) {
+            return Err(GaConfigError::InvalidLocalSearchRate);
+        }
+        self.local_search_rate = local_search_rate;
+        self.local_search_attempts = local_search_attempts;
+        Ok(())
+    }
+
+    pub fn set_restart_policy(
+        &mut self,
+        restart_policy: RestartPolicy,
+    ) -> Result<(), GaConfigError> {
+        restart_policy.validate()?;
+        restart_kind_supports_symmetry(restart_policy.kind, self.symmetry)?;
+        parameter_control_supports_policy(self.parameter_control, restart_policy)?;
+        self.restart_policy = restart_policy;
+        self.sync_controller();
+        Ok(())
+    }
+
+    // Switching controllers starts the new one from the configured rates.
+    pub fn set_parameter_control(
+        &mut self,
+        parameter_control: ParameterControl,
+    ) -> Result<(), GaConfigError> {
+        parameter_control_supports_policy(parameter_control, self.restart_policy)?;
+        self.parameter_control = parameter_control;
+        self.sync_controller();
+        Ok(())
+    }
+
+    fn sync_controller(&mut self) {
+        let control = self
+            .restart_policy
+            .adaptive
+            .then_some(self.parameter_control);
+        let symmetric = self.symmetry != SymmetryConstraint::None;
+        if !self.controller.is_configured_for(control, symmetric) {
+            self.controller = Controller::new(control, symmetric, self.mutation_rate);
+            self.assign_missing_mutation_rates();
+        }
+    }
+
+    // Gives a self-adaptive rate to every chromosome still without one: the starting
+    // population and seeded boards, or a population that ran under another controller.
+    fn assign_missing_mutation_rates(&mut self) {
+        for chromosome in &mut self.population {
+            if chromosome.get_mutation_rate().is_none() {
+                chromosome.set_mutation_rate(self.controller.initial_mutation_rate(&mut self.rng));
             }
+        }
+    }
 
-            let epoch_best_conflicts_sum = epoch_population_metrics.best_conflicts_sum;
-            let population_size = epoch_population_metrics.population_size;
+    pub fn set_diversity_measure(&mut self, diversity_measure: DiversityMeasure) {
+        self.diversity_measure = diversity_measure;
+    }
 
-            let is_improvement = epoch_best_conflicts_sum < best_conflicts_sum;
-            if is_improvement {
-                self.update_best_chromosome(epoch_population_metrics);
-                best_conflicts_sum = epoch_best_conflicts_sum;
-                stagnation_epochs = 0;
-            } else {
-                stagnation_epochs += 1;
+    pub fn set_selection_strategy(&mut self, selection_strategy: SelectionStrategy) {
+        self.selection_strategy = selection_strategy;
+    }
+
+    pub fn set_tournament_size(&mut self, tournament_size: usize) -> Result<(), GaConfigError> {
+        if tournament_size == 0 {
+            return Err(GaConfigError::TournamentSizeZero);
+        }
+        self.tournament_size = tournament_size;
+        Ok(())
+    }
+
+    // Raising the limit lets a run that stopped at its epoch limit continue; lowering it
+    // below the current epoch ends the run.
+    pub fn set_max_epoch_count(&mut self, max_epoch_count: u32) -> Result<(), GaConfigError> {
+        if max_epoch_count == 0 {
+            return Err(GaConfigError::MaxEpochCountZero);
+        }
+        self.max_epoch_count = max_epoch_count;
+        self.refresh_finished();
+        Ok(())
+    }
+
+    // `None` removes the budget. As with the epoch limit, raising it lets a run that
+    // stopped at the budget continue; it cannot be lowered below what was already spent.
+    pub fn set_max_evaluations(
+        &mut self,
+        max_evaluations: Option<u64>,
+    ) -> Result<(), GaConfigError> {
+        if max_evaluations == Some(0) {
+            return Err(GaConfigError::MaxEvaluationsZero);
+        }
+        if max_evaluations.is_some_and(|max_evaluations| max_evaluations < self.evaluations_spent())
+        {
+            return Err(GaConfigError::MaxEvaluationsBelowSpent);
+        }
+        self.max_evaluations = max_evaluations;
+        self.refresh_finished();
+        Ok(())
+    }
+
+    fn refresh_finished(&mut self) {
+        let Some(mut state) = self.run_state.take() else {
+            return;
+        };
+        let solved = state.run_metrics.solved_epoch().is_some();
+        state.run_metrics.evaluation_budget_reached =
+            !solved && self.is_over_evaluation_budget(&state.run_metrics);
+        let was_finished = state.finished;
+        state.finished = self.population.is_empty()
+            || solved
+            || state.run_metrics.evaluation_budget_reached
+            || state.epoch >= self.max_epoch_count;
+        if state.finished && !was_finished {
+            self.complete_final_diversity(&mut state.run_metrics);
+        }
+        self.run_state = Some(state);
+    }
+
+    // Before `init` this is the starting population it is about to evaluate.
+    fn evaluations_spent(&self) -> u64 {
+        self.run_state
+            .as_ref()
+            .map_or(self.population.len() as u64, |state| {
+                state.run_metrics.evaluations_so_far() + self.pending_evaluations.total()
+            })
+    }
+
+    fn is_over_evaluation_budget(&self, run_metrics: &RunMetrics) -> bool {
+        self.max_evaluations
+            .is_some_and(|max_evaluations| run_metrics.evaluations_so_far() >= max_evaluations)
+    }
+
+    pub fn add_observer(&mut self, observer: impl GaObserver + 'static) {
+        self.observers.push(Box::new(observer));
+    }
+
+    // Drops every observer, including the default `LogObserver`.
+    pub fn clear_observers(&mut self) {
+        self.observers.clear();
+    }
+
+    fn emit(&mut self, event: GaEvent<'_>) {
+        for observer in &mut self.observers {
+            observer.on_event(&event);
+        }
+    }
+
+    pub fn run_algorithm(&mut self) -> RunMetrics {
+        self.run_algorithm_with_progress(|_| true)
+    }
+
+    pub fn run_algorithm_with_progress<F>(&mut self, mut on_epoch: F) -> RunMetrics
+    where
+        F: FnMut(&EpochSnapshot) -> bool,
+    {
+        self.init();
+        loop {
+            if !self.report_latest_epoch(&mut on_epoch) {
+                self.cancel();
+                break;
             }
+            if self.is_finished() {
+                break;
+            }
+            self.step();
+        }
+        self.take_run_metrics()
+    }
 
-            let mut epoch_record_metrics = epoch_population_metrics;
-            epoch_record_metrics.best_conflicts_sum = best_conflicts_sum;
-            run_metrics.record_epoch(
-                epoch_number,
-                epoch_record_metrics,
-                EpochRecordContext {
-                    mutation_rate: epoch_mutation_rate,
-                    elite_ratio: epoch_elite_ratio,
-                    offspring_count,
-                    local_search_improvements,
-                    stagnation_epochs,
-                    diversity_replacements,
-                    elapsed_ms: started_at.elapsed().as_millis(),
-                },
-            );
+    // Starts a run by evaluating the current population and recording it as epoch 0.
+    // Calling it again restarts the metrics from whatever population is in place.
+    pub fn init(&mut self) {
+        let started_at = Instant::now();
+        let mut state = RunState::default();
 
-            if best_conflicts_sum == 0 {
-                log::info!(
-                    "ga solved epoch={epoch_number} population_size={population_size} mutation_rate={epoch_mutation_rate:.4} elite_ratio={epoch_elite_ratio:.4} local_search_improvements={local_search_improvements}"
-                );
-                run_metrics.mark_solved(epoch_number);
-                run_metrics.set_total_elapsed_ms(started_at.elapsed().as_millis());
-                self.report_latest_epoch(&run_metrics, &mut on_epoch);
-                return run_metrics;
+        if self.population.is_empty() {
+            log::warn!("cannot run algorithm with empty population");
+            state.finished = true;
+            self.run_state = Some(state);
+            return;
+        }
+
+        self.controller = Controller::new(
+            self.restart_policy
+                .adaptive
+                .then_some(self.parameter_control),
+            self.symmetry != SymmetryConstraint::None,
+            self.mutation_rate,
+        );
+        self.assign_missing_mutation_rates();
+        // Each chromosome of the starting population was evaluated once when built.
+        self.pending_evaluations = EvaluationCounts {
+            full: self.population.len() as u64,
+            swap_delta: 0,
+        };
+        self.pin_fixed_queens();
+        let fitness_started = Instant::now();
+        self.calc_fitness();
+        let phase_timings = PhaseTimings {
+            fitness_us: elapsed_us(fitness_started),
+            ..PhaseTimings::default()
+        };
+        let initial_population_metrics = population_metrics(
+            &self.population,
+            self.count_symmetry_classes,
+            self.diversity_measures_for(0),
+        );
+        self.update_best_chromosome(initial_population_metrics);
+        state.best_conflicts_sum = self.get_best_chromosome().get_conflicts_sum();
+        let offspring_count =
+            offspring_count_for_population(self.target_population_size, self.offspring_ratio);
+        let mut initial_record_metrics = initial_population_metrics;
+        initial_record_metrics.best_conflicts_sum = state.best_conflicts_sum;
+        state.run_metrics.record_epoch(
+            0,
+            initial_record_metrics,
+            EpochRecordContext {
+                mutation_rate: self.mutation_rate,
+                elite_ratio: self.elite_ratio,
+                offspring_count,
+                local_search_improvements: 0,
+                stagnation_epochs: 0,
+                stagnation_reset: false,
+                diversity_replacements: 0,
+                phase_timings,
+                evaluations: std::mem::take(&mut self.pending_evaluations),
+                control: self.controller.initial_state(),
+                elapsed_ms: started_at.elapsed().as_millis(),
+            },
+        );
+
+        self.emit(GaEvent::RunStarted(RunStart {
+            max_epoch_count: self.max_epoch_count,
+            population_size: self.get_population_size(),
+            best_conflicts_sum: state.best_conflicts_sum,
+            mutation_rate: self.mutation_rate,
+            elite_ratio: self.elite_ratio,
+            offspring_ratio: self.offspring_ratio,
+            offspring_count,
+            selection_strategy: self.selection_strategy,
+            tournament_size: self.tournament_size,
+            local_search_rate: self.local_search_rate,
+            local_search_attempts: self.local_search_attempts,
+            stagnation_reset_interval: self.restart_policy.interval_for(self.max_epoch_count),
+            restart_kind: self.restart_policy.kind,
+            adaptive: self.restart_policy.adaptive,
+            parameter_control: self.parameter_control,
+        }));
+        if state.best_conflicts_sum == 0 {
+            state.run_metrics.mark_solved(0);
+            state.finished = true;
+        } else if self.is_over_evaluation_budget(&state.run_metrics) {
+            state.run_metrics.evaluation_budget_reached = true;
+            state.finished = true;
+        } else {
+            state.finished = self.max_epoch_count == 0;
+        }
+        if state.finished {
+            self.complete_final_diversity(&mut state.run_metrics);
+        }
+        if let Some(metrics) = state.run_metrics.epochs().last() {
+            self.emit(GaEvent::EpochFinished { metrics });
+            if state.best_conflicts_sum == 0 {
+                self.emit(GaEvent::Solved { metrics });
+            } else if state.run_metrics.evaluation_budget_reached {
+                self.emit(GaEvent::EvaluationBudgetReached { metrics });
             }
+        }
+
+        state.active_time = started_at.elapsed();
+        self.run_state = Some(state);
+    }
+
+    // Runs the next epoch, starting the run first if `init` has not been called.
+    pub fn step(&mut self) -> EpochOutcome {
+        if self.run_state.is_none() {
+            self.init();
+        }
+        let Some(mut state) = self.run_state.take() else {
+            return EpochOutcome::Finished;
+        };
+        if state.finished {
+            self.run_state = Some(state);
+            return EpochOutcome::Finished;
+        }
+
+        let started_at = Instant::now();
+        let epoch_number = state.epoch + 1;
+        let stagnation_reset_interval = self.restart_policy.interval_for(self.max_epoch_count);
+        let offspring_count =
+            offspring_count_for_population(self.target_population_size, self.offspring_ratio);
+
+        self.emit(GaEvent::EpochStarted {
+            epoch: epoch_number,
+        });
+
+        self.evaluation_budget = EvaluationBudget {
+            left: self.max_evaluations.map(|max_evaluations| {
+                max_evaluations.saturating_sub(
+                    state.run_metrics.evaluations_so_far() + self.pending_evaluations.total(),
+                )
+            }),
+            exhausted: false,
+        };
+        let mut phase_timings = PhaseTimings::default();
+        let stagnation_reset = state.stagnation_epochs >= stagnation_reset_interval;
+        if stagnation_reset {
+            let (_, reset_elite_ratio) =
+                self.epoch_parameters(state.stagnation_epochs, stagnation_reset_interval);
+            let replaced_count = self.soft_restart_population(reset_elite_ratio);
+            self.pin_fixed_queens();
+            let fitness_started = Instant::now();
+            self.calc_fitness();
+            phase_timings.fitness_us += elapsed_us(fitness_started);
+
+            let post_reset_population_metrics = population_metrics(
+                &self.population,
+                self.count_symmetry_classes,
+                DiversityMeasures::NONE,
+            );
+            self.update_best_chromosome(post_reset_population_metrics);
+            let post_reset_best_conflicts_sum = post_reset_population_metrics.best_conflicts_sum;
+            state.best_conflicts_sum = state
+                .best_conflicts_sum
+                .min(self.get_best_chromosome().get_conflicts_sum());
+
+            self.emit(GaEvent::StagnationReset {
+                epoch: epoch_number,
+                stagnant_epochs: state.stagnation_epochs,
+                replaced: replaced_count,
+                best_conflicts_sum: post_reset_best_conflicts_sum,
+                elite_ratio: reset_elite_ratio,
+                population_size: self.get_population_size(),
+            });
+
+            state.stagnation_epochs = 0;
+        }
+
+        let (epoch_mutation_rate, epoch_elite_ratio) =
+            self.epoch_parameters(state.stagnation_epochs, stagnation_reset_interval);
+
+        let phase_started = Instant::now();
+        self.mate_random_chromosomes(offspring_count);
+        phase_timings.mating_us = elapsed_us(phase_started);
+
+        let phase_started = Instant::now();
+        self.mutate_population(epoch_mutation_rate, epoch_elite_ratio);
+        phase_timings.mutation_us = elapsed_us(phase_started);
+
+        let phase_started = Instant::now();
+        let local_search_improvements =
+            self.improve_population_with_local_search(epoch_elite_ratio);
+        phase_timings.local_search_us = elapsed_us(phase_started);
+
+        let phase_started = Instant::now();
+        self.pin_fixed_queens();
+        self.select_survivors(epoch_elite_ratio);
+        phase_timings.selection_us = elapsed_us(phase_started);
+
+        let phase_started = Instant::now();
+        self.calc_fitness();
+        phase_timings.fitness_us += elapsed_us(phase_started);
+
+        let phase_started = Instant::now();
+        let diversity_measures = self.diversity_measures_for(epoch_number);
+        let mut epoch_population_metrics = population_metrics(
+            &self.population,
+            self.count_symmetry_classes,
+            diversity_measures,
+        );
+        let diversity = epoch_population_metrics.diversity(self.diversity_measure);
+        let diversity_replacements =
+            self.refresh_low_diversity_population(epoch_elite_ratio, &epoch_population_metrics);
+        if diversity_replacements > 0 {
+            self.pin_fixed_queens();
+            self.calc_fitness();
+            epoch_population_metrics = population_metrics(
+                &self.population,
+                self.count_symmetry_classes,
+                diversity_measures,
+            );
+        }
+        phase_timings.diversity_us = elapsed_us(phase_started);
+        if diversity_replacements > 0 {
+            self.emit(GaEvent::DiversityRefresh {
+                epoch: epoch_number,
+                measure: self.diversity_measure,
+                diversity,
+                min_diversity: self.min_diversity_ratio,
+                replaced: diversity_replacements,
+            });
+        }
+
+        let epoch_best_conflicts_sum = epoch_population_metrics.best_conflicts_sum;
+        let previous_best_conflicts_sum = state.best_conflicts_sum;
+
+        let is_improvement = epoch_best_conflicts_sum < state.best_conflicts_sum;
+        if is_improvement {
+            self.update_best_chromosome(epoch_population_metrics);
+            state.best_conflicts_sum = epoch_best_conflicts_sum;
+            state.stagnation_epochs = 0;
+        } else {
+            state.stagnation_epochs += 1;
+        }
 
+        let best_conflicts_sum = state.best_conflicts_sum;
+        let stagnation_epochs = state.stagnation_epochs;
+        let control = self.controller.finish_epoch();
+        let recorded_mutation_rate = if self.controller.is_self_adaptive() {
+            self.mean_chromosome_mutation_rate(epoch_mutation_rate)
+        } else {
+            epoch_mutation_rate
+        };
+        let mut epoch_record_metrics = epoch_population_metrics;
+        epoch_record_metrics.best_conflicts_sum = best_conflicts_sum;
+        state.run_metrics.record_epoch(
+            epoch_number,
+            epoch_record_metrics,
+            EpochRecordContext {
+                mutation_rate: recorded_mutation_rate,
+                elite_ratio: epoch_elite_ratio,
+                offspring_count,
+                local_search_improvements,
+                stagnation_epochs,
+                stagnation_reset,
+                diversity_replacements,
+                phase_timings,
+                evaluations: std::mem::take(&mut self.pending_evaluations),
+                control,
+                elapsed_ms: (state.active_time + started_at.elapsed()).as_millis(),
+            },
+        );
+
+        let outcome = if best_conflicts_sum == 0 {
+            state.run_metrics.mark_solved(epoch_number);
+            state.finished = true;
+            EpochOutcome::Solved
+        } else if self.evaluation_budget.exhausted
+            || self.is_over_evaluation_budget(&state.run_metrics)
+        {
+            state.run_metrics.evaluation_budget_reached = true;
+            state.finished = true;
+            EpochOutcome::EvaluationBudgetReached
+        } else if epoch_number >= self.max_epoch_count {
+            state.finished = true;
+            EpochOutcome::EpochLimitReached
+        } else if is_improvement {
+            EpochOutcome::Improved
+        } else {
+            EpochOutcome::Stagnant
+        };
+        if state.finished {
+            self.complete_final_diversity(&mut state.run_metrics);
+        }
+
+        if let Some(metrics) = state.run_metrics.epochs().last() {
             if is_improvement {
-                log::info!(
-                    "ga improvement epoch={epoch_number} best_conflicts_sum={best_conflicts_sum} population_size={population_size} mutation_rate={epoch_mutation_rate:.4} elite_ratio={epoch_elite_ratio:.4} local_search_improvements={local_search_improvements}",
-                );
-                if !self.report_latest_epoch(&run_metrics, &mut on_epoch) {
-                    run_metrics.set_total_elapsed_ms(started_at.elapsed().as_millis());
-                    return run_metrics;
+                self.emit(GaEvent::Improvement {
+                    metrics,
+                    previous_best_conflicts_sum,
+                });
+            }
+            self.emit(GaEvent::EpochFinished { metrics });
+            match outcome {
+                EpochOutcome::Solved => self.emit(GaEvent::Solved { metrics }),
+                EpochOutcome::EpochLimitReached => {
+                    self.emit(GaEvent::EpochLimitReached { metrics });
                 }
-                continue;
+                EpochOutcome::EvaluationBudgetReached => {
+                    self.emit(GaEvent::EvaluationBudgetReached { metrics });
+                }
+                _ => {}
             }
+        }
 
-            let is_periodic_log = epoch_number % progress_log_interval == 0;
-            let is_last_epoch = epoch_number == self.max_epoch_count;
-            if is_periodic_log || is_last_epoch {
-                log::info!(
-                    "ga progress epoch={epoch_number} best_conflicts_sum={best_conflicts_sum} population_size={population_size} stagnant_epochs={stagnation_epochs} mutation_rate={epoch_mutation_rate:.4} elite_ratio={epoch_elite_ratio:.4} local_search_improvements={local_search_improvements} diversity_replacements={diversity_replacements}",
-                );
-            }
+        state.epoch = epoch_number;
+        state.active_time += started_at.elapsed();
+        self.run_state = Some(state);
+        outcome
+    }
 
-            if !self.report_latest_epoch(&run_metrics, &mut on_epoch) {
-                run_metrics.set_total_elapsed_ms(started_at.elapsed().as_millis());
-                return run_metrics;
-            }
+    // Ends an unfinished run early; the recorded epochs are kept for `take_run_metrics`.
+    pub fn cancel(&mut self) {
+        let Some(mut state) = self.run_state.take() else {
+            return;
+        };
+        if !state.finished {
+            state.finished = true;
+            self.complete_final_diversity(&mut state.run_metrics);
+            self.emit(GaEvent::Cancelled { epoch: state.epoch });
         }
+        self.run_state = Some(state);
+    }
 
-        log::warn!(
-            "no solution found best_conflicts_sum={best_conflicts_sum} epochs={} population_size={}",
-            self.max_epoch_count,
-            self.get_population_size(),
-        );
+    fn diversity_measures_for(&self, epoch: u32) -> DiversityMeasures {
+        if self
+            .diversity_report_interval
+            .is_some_and(|interval| epoch.is_multiple_of(interval))
+        {
+            DiversityMeasures::ALL
+        } else {
+            DiversityMeasures::only(self.diversity_measure)
+        }
+    }
 
-        run_metrics.set_total_elapsed_ms(started_at.elapsed().as_millis());
+    // Fills in the measures the last recorded epoch skipped, taken from the population
+    // in place, so a finished run reports every measure for its final epoch.
+    fn complete_final_diversity(&self, run_metrics: &mut RunMetrics) {
+        let Some(metrics) = run_metrics.epochs.last_mut() else {
+            return;
+        };
+        let missing = DiversityMeasures::missing_from(metrics);
+        if missing == DiversityMeasures::NONE {
+            return;
+        }
+        let population_metrics = population_metrics(&self.population, false, missing);
+        metrics.gene_entropy = metrics.gene_entropy.or(population_metrics.gene_entropy);
+        metrics.mean_pairwise_distance = metrics
+            .mean_pairwise_distance
+            .or(population_metrics.mean_pairwise_distance);
+        metrics.mean_best_distance = metrics
+            .mean_best_distance
+            .or(population_metrics.mean_best_distance);
+    }
+
+    // True once the run has solved the board, used up its epochs, or been cancelled.
+    pub fn is_finished(&self) -> bool {
+        self.run_state.as_ref().is_some_and(|state| state.finished)
+    }
+
+    // The epochs recorded so far, finished or not; the total elapsed time counts only
+    // time spent inside `init` and `step`, so pauses between epochs are left out.
+    pub fn run_metrics(&self) -> RunMetrics {
+        let Some(state) = &self.run_state else {
+            return RunMetrics::default();
+        };
+        let mut run_metrics = state.run_metrics.clone();
+        run_metrics.set_total_elapsed_ms(state.active_time.as_millis());
         run_metrics
     }
 
-    fn report_latest_epoch<F>(&self, run_metrics: &RunMetrics, on_epoch: &mut F) -> bool
-    where
-        F: FnMut(&EpochSnapshot) -> bool,
-    {
-        let Some(metrics) = run_metrics.epochs().last() else {
-            return true;
+    // Ends the run and hands back its metrics; the next `step` starts a new run.
+    pub fn take_run_metrics(&mut self) -> RunMetrics {
+        let Some(state) = self.run_state.take() else {
+            return RunMetrics::default();
         };
+        let mut run_metrics = state.run_metrics;
+        run_metrics.set_total_elapsed_ms(state.active_time.as_millis());
+        run_metrics
+    }
 
+    // The latest recorded epoch with the best board found so far.
+    pub fn latest_snapshot(&self) -> Option<EpochSnapshot> {
+        let metrics = self.run_state.as_ref()?.run_metrics.epochs().last()?;
         let best_chromosome = self.get_best_chromosome();
-        let snapshot = EpochSnapshot {
+        Some(EpochSnapshot {
             metrics: metrics.clone(),
             best_positions: best_chromosome.get_positions().to_vec(),
             best_conflicts: best_chromosome.get_conflicts().to_vec(),
             best_conflicts_sum: best_chromosome.get_conflicts_sum(),
-        };
+            population_summary: self
+                .population_summary
+                .then(|| population::summarize(&self.population)),
+        })
+    }
 
-        on_epoch(&snapshot)
+    fn report_latest_epoch<F>(&self, on_epoch: &mut F) -> bool
+    where
+        F: FnMut(&EpochSnapshot) -> bool,
+    {
+        match self.latest_snapshot() {
+            Some(snapshot) => on_epoch(&snapshot),
+            None => true,
+        }
     }
 
     pub fn get_best_chromosome(&self) -> &Chromosome {
@@ -692,6 +1830,38 @@ impl GeneticAlgorithm {
         }
     }
 
+    fn epoch_parameters(
+        &self,
+        stagnation_epochs: u32,
+        stagnation_reset_interval: u32,
+    ) -> (f32, f32) {
+        match self.controller.control() {
+            Some(ParameterControl::Stagnation) => adaptive_ga_parameters(
+                self.mutation_rate,
+                self.elite_ratio,
+                stagnation_epochs,
+                stagnation_reset_interval,
+            ),
+            Some(ParameterControl::OneFifthRule) => {
+                (self.controller.mutation_rate(), self.elite_ratio)
+            }
+            _ => (self.mutation_rate, self.elite_ratio),
+        }
+    }
+
+    // The population's mean self-adaptive rate; chromosomes without one count as
+    // `fallback`.
+    fn mean_chromosome_mutation_rate(&self, fallback: f32) -> f32 {
+        if self.population.is_empty() {
+            return fallback;
+        }
+        self.population
+            .iter()
+            .map(|chromosome| chromosome.get_mutation_rate().unwrap_or(fallback))
+            .sum::<f32>()
+            / self.population.len() as f32
+    }
+
     fn calc_fitness(&mut self) {
         if self.population.is_empty() {
             return;
@@ -748,6 +1918,7 @@ impl GeneticAlgorithm {
             self.tournament_size,
         );
 
+        let repair_cost = self.fixed_queens.len() as u64;
         for _ in 0..offspring_count {
             let Some(parent_one_index) = self.select_parent_index(roulette_selection.as_ref().map(
                 |(cumulative_fitness, fitness_sum)| (cumulative_fitness.as_slice(), *fitness_sum),
@@ -760,14 +1931,34 @@ impl GeneticAlgorithm {
                 break;
             };
 
-            let population = &self.population;
+            if self.evaluation_budget.take(1, repair_cost + 1) == 0 {
+                break;
+            }
+
+            let operator = self.controller.select_crossover(&mut self.rng);
+            let parent_one = &self.population[parent_one_index];
+            let parent_two = &self.population[parent_two_index];
             let rng = &mut self.rng;
-            let child = mate_chromosomes(
-                population[parent_one_index].get_positions(),
-                population[parent_two_index].get_positions(),
+            let mut child = mate_chromosomes(
+                parent_one.get_positions(),
+                parent_two.get_positions(),
+                self.symmetry,
+                operator,
                 rng,
             );
+            let parents_conflicts_sum =
+                (parent_one.get_conflicts_sum() + parent_two.get_conflicts_sum()) as f32 / 2.0;
+            child.set_mutation_rate(self.controller.inherited_mutation_rate(
+                parent_one.get_mutation_rate(),
+                parent_two.get_mutation_rate(),
+            ));
+            self.controller.record_crossover(
+                operator,
+                parents_conflicts_sum,
+                child.get_conflicts_sum(),
+            );
             self.population.push(child);
+            self.pending_evaluations.full += 1;
         }
     }
 
@@ -865,15 +2056,27 @@ impl GeneticAlgorithm {
         );
         select_elites_to_front(&mut self.population, elite_count);
 
-        let mut planned_swaps = vec![None; self.population.len()];
+        if self.symmetry != SymmetryConstraint::None {
+            self.mutate_symmetric_population(mutation_rate, elite_count);
+            return;
+        }
+
+        let mut planned_mutations = vec![None; self.population.len()];
         let rng = &mut self.rng;
 
-        for (index, chromosome) in self.population.iter().enumerate() {
+        for (index, chromosome) in self.population.iter_mut().enumerate() {
             if index < elite_count {
                 continue;
             }
 
-            if rng.random::<f32>() < mutation_rate {
+            let own_mutation_rate = self
+                .controller
+                .self_adapted_mutation_rate(chromosome.get_mutation_rate(), rng);
+            chromosome.set_mutation_rate(own_mutation_rate);
+            let chromosome_mutation_rate = self
+                .controller
+                .chromosome_mutation_rate(own_mutation_rate, mutation_rate);
+            if rng.random::<f32>() < chromosome_mutation_rate {
                 let chromosome_size = chromosome.get_positions().len();
                 if chromosome_size < 2 {
                     continue;
@@ -885,18 +2088,107 @@ impl GeneticAlgorithm {
                     index_two += 1;
                 }
 
-                planned_swaps[index] = Some((index_one, index_two));
+                let operator = self.controller.select_mutation(rng);
+                planned_mutations[index] = Some((
+                    operator,
+                    index_one,
+                    index_two,
+                    chromosome.get_conflicts_sum(),
+                ));
             }
         }
 
+        limit_planned_work(
+            &mut planned_mutations,
+            &mut self.evaluation_budget,
+            self.fixed_queens.len() as u64 + 1,
+        );
+        // Swaps are scored incrementally; the other operators move several queens.
+        for (operator, ..) in planned_mutations.iter().flatten() {
+            match operator {
+                MutationOperator::Swap => self.pending_evaluations.swap_delta += 1,
+                _ => self.pending_evaluations.full += 1,
+            }
+        }
         self.population
             .par_iter_mut()
+            .zip(&planned_mutations)
+            .for_each(|(chromosome, planned)| {
+                if let Some((operator, index_one, index_two, _)) = *planned {
+                    apply_mutation(chromosome, operator, index_one, index_two);
+                }
+            });
+        for (chromosome, planned) in self.population.iter().zip(&planned_mutations) {
+            if let Some((operator, _, _, conflicts_sum_before)) = *planned {
+                self.controller.record_mutation(
+                    operator,
+                    conflicts_sum_before,
+                    chromosome.get_conflicts_sum(),
+                );
+            }
+        }
+    }
+
+    fn mutate_symmetric_population(&mut self, mutation_rate: f32, elite_count: usize) {
+        let symmetry = self.symmetry;
+        let controller = &self.controller;
+        let rng = &mut self.rng;
+        let mut planned_positions = self
+            .population
+            .iter_mut()
             .enumerate()
-            .for_each(|(index, chromosome)| {
-                if let Some((index_one, index_two)) = planned_swaps[index] {
-                    chromosome.mutate_swap_at(index_one, index_two);
+            .map(|(index, chromosome)| {
+                if index < elite_count {
+                    return None;
+                }
+                let own_mutation_rate =
+                    controller.self_adapted_mutation_rate(chromosome.get_mutation_rate(), rng);
+                chromosome.set_mutation_rate(own_mutation_rate);
+                let chromosome_mutation_rate =
+                    controller.chromosome_mutation_rate(own_mutation_rate, mutation_rate);
+                (rng.random::<f32>() < chromosome_mutation_rate)
+                    .then(|| symmetry::mutate_positions(chromosome.get_positions(), symmetry, rng))
+            })
+            .collect::<Vec<_>>();
+
+        limit_planned_work(&mut planned_positions, &mut self.evaluation_budget, 1);
+        self.pending_evaluations.full += planned_positions
+            .iter()
+            .filter(|positions| positions.is_some())
+            .count() as u64;
+        let conflicts_sums_before = self
+            .population
+            .iter()
+            .map(Chromosome::get_conflicts_sum)
+            .collect::<Vec<_>>();
+        let mutated = planned_positions
+            .iter()
+            .map(Option::is_some)
+            .collect::<Vec<_>>();
+        self.population
+            .par_iter_mut()
+            .zip(planned_positions)
+            .for_each(|(chromosome, positions)| {
+                if let Some(positions) = positions {
+                    let mutation_rate = chromosome.get_mutation_rate();
+                    *chromosome = Chromosome::new(positions);
+                    chromosome.set_mutation_rate(mutation_rate);
                 }
             });
+        for ((chromosome, conflicts_sum_before), mutated) in self
+            .population
+            .iter()
+            .zip(conflicts_sums_before)
+            .zip(mutated)
+        {
+            if mutated {
+                self.controller.record_mutation(
+                    MutationOperator::Swap,
+                    conflicts_sum_before,
+                    chromosome.get_conflicts_sum(),
+                );
+            }
+        }
     }
 
     fn improve_population_with_local_search(&mut self, elite_ratio: f32) -> usize {
@@ -925,15 +2217,38 @@ impl GeneticAlgorithm {
         candidate_indices.shuffle(&mut self.rng);
         candidate_indices.truncate(candidate_count);
 
-        let attempts = self.local_search_attempts;
+        let repair_cost = self.fixed_queens.len() as u64;
+        let symmetry = self.symmetry;
         let rng = &mut self.rng;
         let population = &mut self.population;
-        candidate_indices
-            .into_iter()
-            .filter(|&index| {
-                improve_chromosome_with_local_search(&mut population[index], attempts, rng)
-            })
-            .count()
+        let evaluations = &mut self.pending_evaluations;
+        let budget = &mut self.evaluation_budget;
+        let mut improvements = 0;
+        for index in candidate_indices {
+            if budget.take(1, repair_cost) == 0 {
+                break;
+            }
+            let attempts = budget.take(self.local_search_attempts, 1);
+            if attempts == 0 {
+                break;
+            }
+
+            let evaluations_before = evaluations.total();
+            if improve_chromosome_with_local_search(
+                &mut population[index],
+                attempts,
+                symmetry,
+                rng,
+                evaluations,
+            ) {
+                improvements += 1;
+            }
+            let used = evaluations.total() - evaluations_before;
+            // A candidate that was never changed needs no fixed-queen repairs either.
+            let unused_repairs = if used == 0 { repair_cost } else { 0 };
+            budget.refund(attempts as u64 - used + unused_repairs);
+        }
+        improvements
     }
 
     fn select_survivors(&mut self, elite_ratio: f32) {
@@ -967,15 +2282,24 @@ impl GeneticAlgorithm {
     fn refresh_low_diversity_population(
         &mut self,
         elite_ratio: f32,
-        unique_chromosomes: usize,
+        population_metrics: &PopulationMetrics,
     ) -> usize {
         if self.population.is_empty() || self.min_diversity_ratio <= 0.0 {
             return 0;
         }
 
-        let min_unique_chromosomes =
-            minimum_unique_chromosomes(self.target_population_size, self.min_diversity_ratio);
-        if unique_chromosomes >= min_unique_chromosomes {
+        let deficit = match self.diversity_measure {
+            DiversityMeasure::UniqueRatio => {
+                minimum_unique_chromosomes(self.target_population_size, self.min_diversity_ratio)
+                    .saturating_sub(population_metrics.unique_chromosomes)
+            }
+            measure => {
+                let diversity = population_metrics.diversity(measure);
+                let missing = (self.min_diversity_ratio - diversity).max(0.0);
+                (missing * self.target_population_size as f32).ceil() as usize
+            }
+        };
+        if deficit == 0 {
             return 0;
         }
 
@@ -986,8 +2310,10 @@ impl GeneticAlgorithm {
             elite_ratio,
         );
         let replaceable_count = self.population.len().saturating_sub(elite_count);
-        let replacement_count =
-            (min_unique_chromosomes - unique_chromosomes).min(replaceable_count);
+        let replacement_count = self.evaluation_budget.take(
+            deficit.min(replaceable_count),
+            self.fixed_queens.len() as u64 + 1,
+        );
         if replacement_count == 0 {
             return 0;
         }
@@ -1003,14 +2329,11 @@ impl GeneticAlgorithm {
         let board_size = u16::try_from(board_size).expect("board size should fit into u16");
 
         for chromosome in self.population.iter_mut().rev().take(replacement_count) {
-            let positions =
-                chromosome::generate_distinct_random_values_with_rng(board_size, &mut self.rng);
+            let positions = random_positions(board_size, self.symmetry, &mut self.rng);
             *chromosome = Chromosome::new(positions);
+            chromosome.set_mutation_rate(self.controller.initial_mutation_rate(&mut self.rng));
         }
-
-        log::info!(
-            "ga diversity refresh unique_chromosomes={unique_chromosomes} min_unique_chromosomes={min_unique_chromosomes} replaced={replacement_count}"
-        );
+        self.pending_evaluations.full += replacement_count as u64;
 
         replacement_count
     }
@@ -1033,7 +2356,7 @@ impl GeneticAlgorithm {
         let board_size = u16::try_from(board_size).expect("board size should fit into u16");
 
         let mut elite_count =
-            ((self.target_population_size as f32) * elite_ratio * SOFT_RESTART_ELITE_RATIO_SCALE)
+            ((self.target_population_size as f32) * elite_ratio * self.restart_policy.keep_ratio)
                 .round() as usize;
         elite_count = elite_count
             .max(1)
@@ -1046,47 +2369,173 @@ impl GeneticAlgorithm {
 
         self.population.truncate(elite_count);
 
+        let best_positions = self.population[0].get_positions().to_vec();
+        let replacement_count = self.evaluation_budget.take(
+            self.target_population_size - self.population.len(),
+            self.fixed_queens.len() as u64 + 1,
+        );
         let mut replaced_count = 0;
-        while self.population.len() < self.target_population_size {
-            let positions =
-                chromosome::generate_distinct_random_values_with_rng(board_size, &mut self.rng);
-            self.population.push(Chromosome::new(positions));
+        while replaced_count < replacement_count {
+            let positions = match self.restart_policy.kind {
+                RestartKind::Random => random_positions(board_size, self.symmetry, &mut self.rng),
+                RestartKind::PerturbBest => {
+                    perturbed_positions(&best_positions, self.symmetry, &mut self.rng)
+                }
+                RestartKind::Constructive => constructive_positions(board_size, &mut self.rng),
+            };
+            let mut chromosome = Chromosome::new(positions);
+            chromosome.set_mutation_rate(self.controller.initial_mutation_rate(&mut self.rng));
+            self.population.push(chromosome);
             replaced_count += 1;
         }
+        self.pending_evaluations.full += replaced_count as u64;
+
+        replaced_count
+    }
+}
+
+pub fn build_genetic_algorithm(config: GaConfig) -> Result<GeneticAlgorithm, GaConfigError> {
+    config.validate()?;
+
+    let target_population_size = config.initial_population;
+    let mut rng = StdRng::seed_from_u64(config.seed);
+    let mut population: Vec<Chromosome> = Vec::with_capacity(target_population_size);
+
+    for _ in 0..target_population_size {
+        let positions = random_positions(config.size, config.symmetry, &mut rng);
+        let chromosome = Chromosome::new(positions);
+        population.push(chromosome);
+    }
+
+    Ok(GeneticAlgorithm::new(
+        population,
+        rng,
+        GeneticAlgorithmParams {
+            target_population_size,
+            max_epoch_count: config.max_epoch_count,
+            mutation_rate: config.mutation_rate,
+            elite_ratio: config.elite_ratio,
+            offspring_ratio: config.offspring_ratio,
+            min_diversity_ratio: config.min_diversity_ratio,
+            diversity_measure: config.diversity_measure,
+            selection_strategy: config.selection_strategy,
+            tournament_size: config.tournament_size,
+            local_search_rate: config.local_search_rate,
+            local_search_attempts: config.local_search_attempts,
+            symmetry: config.symmetry,
+            count_symmetry_classes: config.count_symmetry_classes,
+            population_summary: config.population_summary,
+            diversity_report_interval: config.diversity_report_interval,
+            max_evaluations: config.max_evaluations,
+            restart_policy: config.restart_policy,
+            parameter_control: config.parameter_control,
+        },
+    ))
+}
+
+// Returns how many swaps the repair made.
+fn pin_queens(chromosome: &mut Chromosome, fixed_queens: &[(usize, u16)]) -> u64 {
+    let mut swaps = 0;
+    for &(column, row) in fixed_queens {
+        // Fixed queens never share a row, so this swap cannot move one pinned earlier.
+        if let Some(index) = chromosome
+            .get_positions()
+            .iter()
+            .position(|&position| position == row)
+            && index != column
+        {
+            chromosome.mutate_swap_at(column, index);
+            swaps += 1;
+        }
+    }
+    swaps
+}
+
+fn random_positions(size: u16, symmetry: SymmetryConstraint, rng: &mut impl Rng) -> Vec<u16> {
+    match symmetry {
+        SymmetryConstraint::None => chromosome::generate_distinct_random_values_with_rng(size, rng),
+        _ => symmetry::random_positions(size, symmetry, rng),
+    }
+}
+
+// A copy of `positions` with between one swap and a quarter of its queens swapped.
+fn perturbed_positions(
+    positions: &[u16],
+    symmetry: SymmetryConstraint,
+    rng: &mut impl Rng,
+) -> Vec<u16> {
+    let max_swaps = (positions.len() / RESTART_PERTURBATION_DIVISOR).max(1);
+    let swaps = rng.random_range(1..=max_swaps);
+    let mut perturbed = positions.to_vec();
+    if symmetry != SymmetryConstraint::None {
+        for _ in 0..swaps {
+            perturbed = symmetry::mutate_positions(&perturbed, symmetry, rng);
+        }
+        return perturbed;
+    }
+
+    if perturbed.len() < 2 {
+        return perturbed;
+    }
+    for _ in 0..swaps {
+        let index_one = rng.random_range(0..perturbed.len());
+        let mut index_two = rng.random_range(0..(perturbed.len() - 1));
+        if index_two >= index_one {
+            index_two += 1;
+        }
+        perturbed.swap(index_one, index_two);
+    }
+    perturbed
+}
 
-        replaced_count
+// The greedy construction places queens one column at a time, which would break a
+// symmetric board, so `GaConfig::validate` rejects the combination.
+fn restart_kind_supports_symmetry(
+    kind: RestartKind,
+    symmetry: SymmetryConstraint,
+) -> Result<(), GaConfigError> {
+    if kind == RestartKind::Constructive && symmetry != SymmetryConstraint::None {
+        return Err(GaConfigError::ConstructiveRestartWithSymmetry);
     }
+    Ok(())
 }
 
-pub fn build_genetic_algorithm(config: GaConfig) -> Result<GeneticAlgorithm, GaConfigError> {
-    config.validate()?;
+// Only stagnation control has a meaning with adaptation off, where its rates stay fixed;
+// choosing another controller and turning adaptation off would silently ignore it.
+fn parameter_control_supports_policy(
+    parameter_control: ParameterControl,
+    restart_policy: RestartPolicy,
+) -> Result<(), GaConfigError> {
+    if !restart_policy.adaptive && parameter_control != ParameterControl::Stagnation {
+        return Err(GaConfigError::ParameterControlWithoutAdaptation);
+    }
+    Ok(())
+}
 
-    let target_population_size = config.initial_population;
-    let mut rng = StdRng::seed_from_u64(config.seed);
-    let mut population: Vec<Chromosome> = Vec::with_capacity(target_population_size);
+fn constructive_positions(size: u16, rng: &mut impl Rng) -> Vec<u16> {
+    let board_size = usize::from(size);
+    let mut free_rows = (0..size).collect::<Vec<_>>();
+    let mut diagonals = vec![0u32; 2 * board_size];
+    let mut anti_diagonals = vec![0u32; 2 * board_size];
+    let mut positions = Vec::with_capacity(board_size);
+    for column in 0..board_size {
+        let mut best: Option<(u32, usize)> = None;
+        for _ in 0..free_rows.len().min(CONSTRUCTIVE_RESTART_CANDIDATES) {
+            let index = rng.random_range(0..free_rows.len());
+            let row = usize::from(free_rows[index]);
+            let attacks = diagonals[column + row] + anti_diagonals[column + board_size - 1 - row];
+            if best.is_none_or(|(best_attacks, _)| attacks < best_attacks) {
+                best = Some((attacks, index));
+            }
+        }
 
-    for _ in 0..target_population_size {
-        let positions = chromosome::generate_distinct_random_values_with_rng(config.size, &mut rng);
-        let chromosome = Chromosome::new(positions);
-        population.push(chromosome);
+        let (_, index) = best.expect("a free row remains for every column");
+        let row = free_rows.swap_remove(index);
+        diagonals[column + usize::from(row)] += 1;
+        anti_diagonals[column + board_size - 1 - usize::from(row)] += 1;
+        positions.push(row);
     }
-
-    Ok(GeneticAlgorithm::new(
-        population,
-        rng,
-        GeneticAlgorithmParams {
-            target_population_size,
-            max_epoch_count: config.max_epoch_count,
-            mutation_rate: config.mutation_rate,
-            elite_ratio: config.elite_ratio,
-            offspring_ratio: config.offspring_ratio,
-            min_diversity_ratio: config.min_diversity_ratio,
-            selection_strategy: config.selection_strategy,
-            tournament_size: config.tournament_size,
-            local_search_rate: config.local_search_rate,
-            local_search_attempts: config.local_search_attempts,
-        },
-    ))
+    positions
 }
 
 fn offspring_count_for_population(target_population_size: usize, offspring_ratio: f32) -> usize {
@@ -1099,7 +2548,11 @@ fn offspring_count_for_population(target_population_size: usize, offspring_ratio
         .max(1.0) as usize
 }
 
-fn population_metrics(population: &[Chromosome]) -> PopulationMetrics {
+fn population_metrics(
+    population: &[Chromosome],
+    count_symmetry_classes: bool,
+    measures: DiversityMeasures,
+) -> PopulationMetrics {
     if population.is_empty() {
         return PopulationMetrics {
             population_size: 0,
@@ -1107,6 +2560,10 @@ fn population_metrics(population: &[Chromosome]) -> PopulationMetrics {
             best_conflicts_sum: 0,
             average_conflicts_sum: 0.0,
             unique_chromosomes: 0,
+            unique_symmetry_classes: count_symmetry_classes.then_some(0),
+            gene_entropy: measures.gene_entropy.then_some(0.0),
+            mean_pairwise_distance: measures.pairwise_distance.then_some(0.0),
+            mean_best_distance: measures.best_distance.then_some(0.0),
         };
     }
 
@@ -1125,15 +2582,121 @@ fn population_metrics(population: &[Chromosome]) -> PopulationMetrics {
         unique_chromosomes.insert(chromosome.get_positions());
     }
 
+    let best_positions = best_index.map_or(&[][..], |index| population[index].get_positions());
     PopulationMetrics {
         population_size: population.len(),
         best_index,
         best_conflicts_sum,
         average_conflicts_sum: total_conflicts_sum as f32 / population.len() as f32,
+        unique_symmetry_classes: count_symmetry_classes
+            .then(|| count_unique_symmetry_classes(unique_chromosomes.iter().copied())),
         unique_chromosomes: unique_chromosomes.len(),
+        gene_entropy: measures.gene_entropy.then(|| gene_entropy(population)),
+        mean_pairwise_distance: measures
+            .pairwise_distance
+            .then(|| mean_pairwise_distance(population)),
+        mean_best_distance: measures
+            .best_distance
+            .then(|| mean_distance_to(population, best_positions)),
     }
 }
 
+// Counts each column's rows in one pass over the population. Large boards are split
+// into blocks of columns so the count table stays at most `ENTROPY_COUNT_LIMIT` cells.
+fn gene_entropy(population: &[Chromosome]) -> f32 {
+    let board_size = population
+        .first()
+        .map_or(0, |chromosome| chromosome.get_positions().len());
+    // A column can hold at most `min(population, board size)` distinct rows.
+    let max_entropy = (population.len().min(board_size) as f32).ln();
+    if max_entropy <= 0.0 {
+        return 0.0;
+    }
+
+    let population_size = population.len() as f32;
+    let block_columns = (ENTROPY_COUNT_LIMIT / board_size).clamp(1, board_size);
+    let entropy_sum = (0..board_size)
+        .into_par_iter()
+        .step_by(block_columns)
+        .map(|first_column| {
+            let columns = first_column..(first_column + block_columns).min(board_size);
+            let mut row_counts = vec![0u32; columns.len() * board_size];
+            for chromosome in population {
+                for (offset, &row) in chromosome.get_positions()[columns.clone()]
+                    .iter()
+                    .enumerate()
+                {
+                    row_counts[offset * board_size + usize::from(row)] += 1;
+                }
+            }
+            row_counts
+                .iter()
+                .filter(|&&count| count > 0)
+                .map(|&count| {
+                    let share = count as f32 / population_size;
+                    -share * share.ln()
+                })
+                .sum::<f32>()
+        })
+        .sum::<f32>();
+    (entropy_sum / board_size as f32 / max_entropy).clamp(0.0, 1.0)
+}
+
+// Compares an evenly spaced sample so the cost stays bounded for large populations
+// without drawing from the run's random number generator.
+fn mean_pairwise_distance(population: &[Chromosome]) -> f32 {
+    let sample_size = population.len().min(DIVERSITY_SAMPLE_SIZE);
+    if sample_size < 2 {
+        return 0.0;
+    }
+
+    let sample = (0..sample_size)
+        .map(|index| population[index * population.len() / sample_size].get_positions())
+        .collect::<Vec<_>>();
+    let distance_sum = (0..sample_size)
+        .into_par_iter()
+        .map(|row| {
+            sample[row + 1..]
+                .iter()
+                .map(|other| normalized_distance(sample[row], other))
+                .sum::<f32>()
+        })
+        .sum::<f32>();
+    let pair_count = sample_size * (sample_size - 1) / 2;
+    distance_sum / pair_count as f32
+}
+
+fn mean_distance_to(population: &[Chromosome], positions: &[u16]) -> f32 {
+    if population.is_empty() || positions.is_empty() {
+        return 0.0;
+    }
+
+    population
+        .par_iter()
+        .map(|chromosome| normalized_distance(chromosome.get_positions(), positions))
+        .sum::<f32>()
+        / population.len() as f32
+}
+
+fn normalized_distance(one: &[u16], two: &[u16]) -> f32 {
+    if one.is_empty() {
+        return 0.0;
+    }
+
+    one.iter()
+        .zip(two)
+        .filter(|(left, right)| left != right)
+        .count() as f32
+        / one.len() as f32
+}
+
+fn count_unique_symmetry_classes<'a>(positions: impl Iterator<Item = &'a [u16]>) -> usize {
+    positions
+        .map(symmetry::canonical_positions)
+        .collect::<HashSet<_>>()
+        .len()
+}
+
 fn minimum_unique_chromosomes(target_population_size: usize, min_diversity_ratio: f32) -> usize {
     if target_population_size == 0 || min_diversity_ratio <= 0.0 || !min_diversity_ratio.is_finite()
     {
@@ -1176,10 +2739,25 @@ fn local_search_candidate_count(non_elite_count: usize, local_search_rate: f32)
         .min(non_elite_count)
 }
 
+// Drops the planned changes, last first, that the evaluation budget cannot cover.
+fn limit_planned_work<T>(planned: &mut [Option<T>], budget: &mut EvaluationBudget, cost: u64) {
+    let planned_count = planned.iter().flatten().count();
+    let granted = budget.take(planned_count, cost);
+    for plan in planned
+        .iter_mut()
+        .filter(|plan| plan.is_some())
+        .skip(granted)
+    {
+        *plan = None;
+    }
+}
+
 fn improve_chromosome_with_local_search(
     chromosome: &mut Chromosome,
     attempts: usize,
+    symmetry: SymmetryConstraint,
     rng: &mut impl Rng,
+    evaluations: &mut EvaluationCounts,
 ) -> bool {
     let chromosome_size = chromosome.get_positions().len();
     if chromosome_size < 2 || attempts == 0 || chromosome.get_conflicts_sum() == 0 {
@@ -1193,6 +2771,21 @@ fn improve_chromosome_with_local_search(
             break;
         }
 
+        if symmetry != SymmetryConstraint::None {
+            let mut candidate = Chromosome::new(symmetry::mutate_positions(
+                chromosome.get_positions(),
+                symmetry,
+                rng,
+            ));
+            candidate.set_mutation_rate(chromosome.get_mutation_rate());
+            evaluations.full += 1;
+            if candidate.get_conflicts_sum() < current_conflicts_sum {
+                *chromosome = candidate;
+                improved = true;
+            }
+            continue;
+        }
+
         let index_one = rng.random_range(0..chromosome_size);
         let mut index_two = rng.random_range(0..(chromosome_size - 1));
         if index_two >= index_one {
@@ -1200,6 +2793,7 @@ fn improve_chromosome_with_local_search(
         }
 
         chromosome.mutate_swap_at(index_one, index_two);
+        evaluations.swap_delta += 1;
         if chromosome.get_conflicts_sum() < current_conflicts_sum {
             improved = true;
         } else {
@@ -1234,6 +2828,10 @@ fn cumulative_fitness(population: &[Chromosome]) -> Vec<f32> {
         .collect()
 }
 
+fn elapsed_us(started_at: Instant) -> u64 {
+    u64::try_from(started_at.elapsed().as_micros()).unwrap_or(u64::MAX)
+}
+
 fn is_unit_interval(value: f32) -> bool {
     value.is_finite() && (0.0..=1.0).contains(&value)
 }
@@ -1280,12 +2878,30 @@ fn stagnation_reset_interval(max_epoch_count: u32) -> u32 {
         .clamp(MIN_STAGNATION_RESET_EPOCHS, MAX_STAGNATION_RESET_EPOCHS)
 }
 
-fn mate_chromosomes(parent_one: &[u16], parent_two: &[u16], rng: &mut impl Rng) -> Chromosome {
-    log::trace!("mate chromosomes");
+// Symmetric boards always use the symmetric crossover, whatever `operator` is.
+fn mate_chromosomes(
+    parent_one: &[u16],
+    parent_two: &[u16],
+    symmetry: SymmetryConstraint,
+    operator: CrossoverOperator,
+    rng: &mut impl Rng,
+) -> Chromosome {
+    log::trace!("mate chromosomes [operator={operator}]");
     log::trace!("parent_one={parent_one:?}");
     log::trace!("parent_two={parent_two:?}");
 
-    let child_genes = pmx(parent_one, parent_two, rng);
+    let child_genes = match (symmetry, operator) {
+        (SymmetryConstraint::None, CrossoverOperator::PartiallyMapped) => {
+            pmx(parent_one, parent_two, rng)
+        }
+        (SymmetryConstraint::None, CrossoverOperator::Order) => {
+            order_crossover(parent_one, parent_two, rng)
+        }
+        (SymmetryConstraint::None, CrossoverOperator::Cycle) => {
+            cycle_crossover(parent_one, parent_two, rng)
+        }
+        _ => symmetric_crossover(parent_one, parent_two, symmetry, rng),
+    };
     let child = Chromosome::new(child_genes);
 
     log::trace!("child={child:?}");
@@ -1311,6 +2927,131 @@ fn pmx(parent_one: &[u16], parent_two: &[u16], rng: &mut impl Rng) -> Vec<u16> {
     pmx_with_crossover_points(parent_one, parent_two, point_one, point_two_exclusive)
 }
 
+// OX1: the child keeps a slice of parent one in place and fills the other columns,
+// starting after the slice and wrapping around, with parent two's remaining rows in
+// parent two's order from the same point.
+fn order_crossover(parent_one: &[u16], parent_two: &[u16], rng: &mut impl Rng) -> Vec<u16> {
+    debug_assert_eq!(parent_one.len(), parent_two.len());
+
+    let chromosome_size = parent_one.len();
+    if chromosome_size <= 1 {
+        return parent_one.to_vec();
+    }
+
+    let chromosome_half_size = chromosome_size / 2;
+    let point_one = rng.random_range(0..chromosome_half_size);
+    let point_two_exclusive = rng.random_range(chromosome_half_size..=chromosome_size);
+
+    let mut child_genes = parent_one.to_vec();
+    let mut child_used = vec![false; chromosome_size];
+    for &gene in &parent_one[point_one..point_two_exclusive] {
+        child_used[usize::from(gene)] = true;
+    }
+
+    let mut remaining = (0..chromosome_size)
+        .map(|offset| parent_two[(point_two_exclusive + offset) % chromosome_size])
+        .filter(|&gene| !child_used[usize::from(gene)]);
+    for offset in 0..(chromosome_size - (point_two_exclusive - point_one)) {
+        let index = (point_two_exclusive + offset) % chromosome_size;
+        child_genes[index] = remaining
+            .next()
+            .expect("parent two holds every row missing from the slice");
+    }
+    child_genes
+}
+
+// CX: the columns split into cycles where the parents' rows map onto each other, and
+// the child takes alternate cycles from each parent, starting with a random one.
+fn cycle_crossover(parent_one: &[u16], parent_two: &[u16], rng: &mut impl Rng) -> Vec<u16> {
+    debug_assert_eq!(parent_one.len(), parent_two.len());
+
+    let chromosome_size = parent_one.len();
+    let mut parent_one_positions = vec![0; chromosome_size];
+    for (index, &gene) in parent_one.iter().enumerate() {
+        parent_one_positions[usize::from(gene)] = index;
+    }
+
+    let mut child_genes = vec![None; chromosome_size];
+    let mut from_parent_one = rng.random::<bool>();
+    for start in 0..chromosome_size {
+        if child_genes[start].is_some() {
+            continue;
+        }
+
+        let source = if from_parent_one {
+            parent_one
+        } else {
+            parent_two
+        };
+        let mut index = start;
+        while child_genes[index].is_none() {
+            child_genes[index] = Some(source[index]);
+            index = parent_one_positions[usize::from(parent_two[index])];
+        }
+        from_parent_one = !from_parent_one;
+    }
+
+    child_genes
+        .into_iter()
+        .map(|gene| gene.expect("every column belongs to a cycle"))
+        .collect()
+}
+
+// Inversion reverses the queens between the two columns; insertion moves the queen in
+// `index_one` to `index_two` and shifts the queens in between.
+fn apply_mutation(
+    chromosome: &mut Chromosome,
+    operator: MutationOperator,
+    index_one: usize,
+    index_two: usize,
+) {
+    if operator == MutationOperator::Swap {
+        chromosome.mutate_swap_at(index_one, index_two);
+        return;
+    }
+
+    let mut positions = chromosome.get_positions().to_vec();
+    match operator {
+        MutationOperator::Inversion => {
+            positions[index_one.min(index_two)..=index_one.max(index_two)].reverse();
+        }
+        _ => {
+            let row = positions.remove(index_one);
+            positions.insert(index_two, row);
+        }
+    }
+    let mutation_rate = chromosome.get_mutation_rate();
+    *chromosome = Chromosome::new(positions);
+    chromosome.set_mutation_rate(mutation_rate);
+}
+
+fn symmetric_crossover(
+    parent_one: &[u16],
+    parent_two: &[u16],
+    symmetry: SymmetryConstraint,
+    rng: &mut impl Rng,
+) -> Vec<u16> {
+    let size = u16::try_from(parent_one.len()).expect("board size should fit into u16");
+    let genome_one = symmetry::reduce(parent_one, symmetry);
+    let genome_two = symmetry::reduce(parent_two, symmetry);
+
+    let pairs = pmx(&genome_one.pairs, &genome_two.pairs, rng);
+    let flips = genome_one
+        .flips
+        .iter()
+        .zip(&genome_two.flips)
+        .map(|(&flip_one, &flip_two)| {
+            if rng.random::<bool>() {
+                flip_one
+            } else {
+                flip_two
+            }
+        })
+        .collect();
+
+    symmetry::expand(size, symmetry, &symmetry::ReducedGenome { pairs, flips })
+}
+
 fn pmx_with_crossover_points(
     parent_one: &[u16],
     parent_two: &[u16],
@@ -1397,16 +3138,26 @@ fn find_position(
 
 #[cfg(test)]
 mod tests {
+    use std::sync::{Arc, Mutex};
+
     use proptest::prelude::*;
     use rand::{SeedableRng, rngs::StdRng, seq::SliceRandom};
 
     use super::{
+        BoardSeedError, DEFAULT_COUNT_SYMMETRY_CLASSES, DEFAULT_DIVERSITY_MEASURE,
         DEFAULT_ELITE_RATIO, DEFAULT_LOCAL_SEARCH_ATTEMPTS, DEFAULT_LOCAL_SEARCH_RATE,
         DEFAULT_MIN_DIVERSITY_RATIO, DEFAULT_MUTATION_RATE, DEFAULT_OFFSPRING_RATIO,
-        DEFAULT_SELECTION_STRATEGY, DEFAULT_TOURNAMENT_SIZE, GaConfig, GaConfigError,
-        GeneticAlgorithm, GeneticAlgorithmParams, SelectionStrategy, build_genetic_algorithm,
-        chromosome::Chromosome, pmx,
+        DEFAULT_PARAMETER_CONTROL, DEFAULT_POPULATION_SUMMARY, DEFAULT_RESTART_POLICY,
+        DEFAULT_SELECTION_STRATEGY, DEFAULT_SYMMETRY_CONSTRAINT, DEFAULT_TOURNAMENT_SIZE,
+        DiversityMeasure, DiversityMeasures, ENTROPY_COUNT_LIMIT, EpochOutcome, EvaluationCounts,
+        GaConfig, GaConfigError, GeneticAlgorithm, GeneticAlgorithmParams, PhaseTimings,
+        RestartKind, RunMetrics, SelectionStrategy, build_genetic_algorithm,
+        chromosome::{Chromosome, ChromosomeError},
+        control::{CrossoverOperator, MutationOperator, ParameterControl},
+        observer::GaEvent,
+        pmx,
     };
+    use crate::symmetry::SymmetryConstraint;
 
     fn build_test_algorithm(population: Vec<Chromosome>) -> GeneticAlgorithm {
         let target_population_size = population.len().max(1);
@@ -1420,10 +3171,18 @@ mod tests {
                 elite_ratio: DEFAULT_ELITE_RATIO,
                 offspring_ratio: DEFAULT_OFFSPRING_RATIO,
                 min_diversity_ratio: DEFAULT_MIN_DIVERSITY_RATIO,
+                diversity_measure: DEFAULT_DIVERSITY_MEASURE,
                 selection_strategy: DEFAULT_SELECTION_STRATEGY,
                 tournament_size: DEFAULT_TOURNAMENT_SIZE,
                 local_search_rate: DEFAULT_LOCAL_SEARCH_RATE,
                 local_search_attempts: DEFAULT_LOCAL_SEARCH_ATTEMPTS,
+                symmetry: DEFAULT_SYMMETRY_CONSTRAINT,
+                count_symmetry_classes: DEFAULT_COUNT_SYMMETRY_CLASSES,
+                population_summary: DEFAULT_POPULATION_SUMMARY,
+                diversity_report_interval: None,
+                max_evaluations: None,
+                restart_policy: DEFAULT_RESTART_POLICY,
+                parameter_control: DEFAULT_PARAMETER_CONTROL,
             },
         )
     }
@@ -1511,6 +3270,18 @@ mod tests {
                 .validate(),
             Err(GaConfigError::TournamentSizeZero)
         );
+        assert_eq!(
+            GaConfig::new(6, 32, 100, 42)
+                .with_symmetry(SymmetryConstraint::Rotation90)
+                .validate(),
+            Err(GaConfigError::UnsupportedSymmetry)
+        );
+        assert_eq!(
+            GaConfig::new(8, 32, 100, 42)
+                .with_symmetry(SymmetryConstraint::Reflection)
+                .validate(),
+            Err(GaConfigError::UnsupportedSymmetry)
+        );
     }
 
     #[test]
@@ -1638,6 +3409,284 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_stepping_matches_full_run_for_same_seed() {
+        let config = GaConfig::new(8, 20, 40, 7);
+        let full_run = build_genetic_algorithm(config)
+            .expect("valid config should build")
+            .run_algorithm();
+        let mut genetic_algorithm =
+            build_genetic_algorithm(config).expect("valid config should build");
+
+        genetic_algorithm.init();
+        let mut outcomes = Vec::new();
+        while !genetic_algorithm.is_finished() {
+            outcomes.push(genetic_algorithm.step());
+        }
+        let stepped_run = genetic_algorithm.take_run_metrics();
+
+        let best_sums = |run: &RunMetrics| -> Vec<u32> {
+            run.epochs()
+                .iter()
+                .map(|metrics| metrics.best_conflicts_sum())
+                .collect()
+        };
+        assert_eq!(best_sums(&stepped_run), best_sums(&full_run));
+        assert_eq!(stepped_run.solved_epoch(), full_run.solved_epoch());
+        assert_eq!(outcomes.len() + 1, stepped_run.epochs().len());
+        let last_outcome = outcomes.last().copied();
+        if stepped_run.solved_epoch().is_some() {
+            assert_eq!(last_outcome, Some(EpochOutcome::Solved));
+        } else {
+            assert_eq!(last_outcome, Some(EpochOutcome::EpochLimitReached));
+        }
+    }
+
+    #[test]
+    fn test_step_reports_finished_after_run_ends() {
+        let mut genetic_algorithm =
+            build_genetic_algorithm(GaConfig::new(4, 4, 2, 3)).expect("valid config should build");
+        assert!(!genetic_algorithm.is_finished());
+        assert!(genetic_algorithm.latest_snapshot().is_none());
+
+        let first = genetic_algorithm.step();
+        assert_ne!(first, EpochOutcome::Finished);
+        assert!(genetic_algorithm.latest_snapshot().is_some());
+        while !genetic_algorithm.is_finished() {
+            genetic_algorithm.step();
+        }
+
+        assert_eq!(genetic_algorithm.step(), EpochOutcome::Finished);
+        let epochs_recorded = genetic_algorithm.run_metrics().epochs().len();
+        assert!(epochs_recorded <= 3);
+        assert_eq!(
+            genetic_algorithm.take_run_metrics().epochs().len(),
+            epochs_recorded
+        );
+        assert!(!genetic_algorithm.is_finished());
+    }
+
+    #[test]
+    fn test_evaluation_counts_accumulate_over_epochs() {
+        let mut genetic_algorithm = build_genetic_algorithm(
+            GaConfig::new(12, 20, 6, 3)
+                .with_local_search_rate(0.50)
+                .with_local_search_attempts(4),
+        )
+        .unwrap();
+
+        let run_metrics = genetic_algorithm.run_algorithm();
+        let epochs = run_metrics.epochs();
+
+        assert_eq!(epochs[0].evaluations().full, 20);
+        let mut expected_total = 0;
+        for metrics in epochs {
+            expected_total += metrics.evaluations().total();
+            assert_eq!(metrics.total_evaluations(), expected_total);
+            if metrics.epoch() > 0 {
+                assert!(metrics.evaluations().full >= metrics.offspring_count() as u64);
+                assert!(metrics.evaluations().swap_delta > 0);
+            }
+        }
+        assert_eq!(run_metrics.total_evaluations().total(), expected_total);
+    }
+
+    #[test]
+    fn test_evaluation_budget_ends_run_at_first_epoch_reaching_it() {
+        let mut genetic_algorithm = build_genetic_algorithm(
+            GaConfig::new(40, 30, 1_000, 5).with_max_evaluations(Some(300)),
+        )
+        .unwrap();
+
+        let run_metrics = genetic_algorithm.run_algorithm();
+        let epochs = run_metrics.epochs();
+        let last = epochs.last().unwrap();
+
+        assert!(run_metrics.evaluation_budget_reached());
+        assert_eq!(run_metrics.solved_epoch(), None);
+        assert_eq!(last.total_evaluations(), 300);
+        assert_eq!(run_metrics.total_evaluations().total(), 300);
+        assert!(epochs[epochs.len() - 2].total_evaluations() < 300);
+        assert!(last.epoch() < 1_000);
+        assert_eq!(
+            GaConfig::new(8, 10, 10, 1)
+                .with_max_evaluations(Some(0))
+                .validate(),
+            Err(GaConfigError::MaxEvaluationsZero)
+        );
+        assert_eq!(
+            GaConfig::new(8, 10, 10, 1)
+                .with_max_evaluations(Some(9))
+                .validate(),
+            Err(GaConfigError::MaxEvaluationsBelowSpent)
+        );
+    }
+
+    #[test]
+    fn test_evaluation_budget_is_never_exceeded() {
+        for (budget, seed) in [(41, 1), (137, 2), (1_001, 3), (2_500, 4)] {
+            let mut genetic_algorithm = build_genetic_algorithm(
+                GaConfig::new(40, 30, 1_000, seed)
+                    .with_max_evaluations(Some(budget))
+                    .with_local_search_rate(0.5)
+                    .with_local_search_attempts(7)
+                    .with_min_diversity_ratio(0.9)
+                    .with_restart_policy(DEFAULT_RESTART_POLICY.with_interval(Some(2))),
+            )
+            .unwrap();
+            genetic_algorithm.fix_queens(&[(0, 0), (5, 7)]).unwrap();
+
+            let run_metrics = genetic_algorithm.run_algorithm();
+
+            assert!(run_metrics.evaluation_budget_reached());
+            assert!(run_metrics.total_evaluations().total() <= budget);
+            for metrics in run_metrics.epochs() {
+                assert!(metrics.total_evaluations() <= budget);
+            }
+        }
+    }
+
+    #[test]
+    fn test_removing_evaluation_budget_resumes_run() {
+        let mut genetic_algorithm = build_genetic_algorithm(
+            GaConfig::new(40, 30, 1_000, 5).with_max_evaluations(Some(300)),
+        )
+        .unwrap();
+        genetic_algorithm.init();
+        while genetic_algorithm.step() != EpochOutcome::EvaluationBudgetReached {}
+        let epoch = genetic_algorithm.run_metrics().epochs().len();
+        assert!(genetic_algorithm.is_finished());
+
+        genetic_algorithm.set_max_evaluations(None).unwrap();
+
+        assert!(!genetic_algorithm.is_finished());
+        assert!(!genetic_algorithm.run_metrics().evaluation_budget_reached());
+        assert_ne!(genetic_algorithm.step(), EpochOutcome::Finished);
+        assert_eq!(genetic_algorithm.run_metrics().epochs().len(), epoch + 1);
+        assert_eq!(
+            genetic_algorithm.set_max_evaluations(Some(0)),
+            Err(GaConfigError::MaxEvaluationsZero)
+        );
+        assert_eq!(
+            genetic_algorithm.set_max_evaluations(Some(299)),
+            Err(GaConfigError::MaxEvaluationsBelowSpent)
+        );
+    }
+
+    fn event_name(event: &GaEvent<'_>) -> &'static str {
+        match event {
+            GaEvent::RunStarted(_) => "run_started",
+            GaEvent::EpochStarted { .. } => "epoch_started",
+            GaEvent::StagnationReset { .. } => "stagnation_reset",
+            GaEvent::DiversityRefresh { .. } => "diversity_refresh",
+            GaEvent::Improvement { .. } => "improvement",
+            GaEvent::EpochFinished { .. } => "epoch_finished",
+            GaEvent::Solved { .. } => "solved",
+            GaEvent::EpochLimitReached { .. } => "epoch_limit_reached",
+            GaEvent::EvaluationBudgetReached { .. } => "evaluation_budget_reached",
+            GaEvent::Cancelled { .. } => "cancelled",
+        }
+    }
+
+    fn recording_observer(
+        events: &Arc<Mutex<Vec<&'static str>>>,
+    ) -> impl FnMut(&GaEvent<'_>) + Send + 'static {
+        let events = Arc::clone(events);
+        move |event: &GaEvent<'_>| {
+            events
+                .lock()
+                .expect("event log lock should not be poisoned")
+                .push(event_name(event));
+        }
+    }
+
+    #[test]
+    fn test_observers_receive_lifecycle_events_in_order() {
+        let mut genetic_algorithm = build_genetic_algorithm(
+            GaConfig::new(10, 6, 3, 11)
+                .with_mutation_rate(0.0)
+                .with_offspring_ratio(0.0),
+        )
+        .expect("valid config should build");
+        let first = Arc::new(Mutex::new(Vec::new()));
+        let second = Arc::new(Mutex::new(Vec::new()));
+        genetic_algorithm.clear_observers();
+        genetic_algorithm.add_observer(recording_observer(&first));
+        genetic_algorithm.add_observer(recording_observer(&second));
+
+        let run_metrics = genetic_algorithm.run_algorithm();
+
+        let events = first.lock().expect("event log lock should not be poisoned");
+        assert_eq!(
+            *events,
+            *second
+                .lock()
+                .expect("event log lock should not be poisoned")
+        );
+        assert_eq!(events[..2], ["run_started", "epoch_finished"]);
+        assert_eq!(
+            events
+                .iter()
+                .filter(|name| **name == "epoch_started")
+                .count(),
+            run_metrics.epochs().len() - 1
+        );
+        assert_eq!(
+            events
+                .iter()
+                .filter(|name| **name == "epoch_finished")
+                .count(),
+            run_metrics.epochs().len()
+        );
+        let last = *events.last().expect("run should emit events");
+        if run_metrics.solved_epoch().is_some() {
+            assert_eq!(last, "solved");
+        } else {
+            assert_eq!(last, "epoch_limit_reached");
+        }
+    }
+
+    #[test]
+    fn test_cancelling_a_run_notifies_observers_once() {
+        let mut genetic_algorithm = build_genetic_algorithm(GaConfig::new(12, 8, 50, 2))
+            .expect("valid config should build");
+        let events = Arc::new(Mutex::new(Vec::new()));
+        genetic_algorithm.add_observer(recording_observer(&events));
+
+        let run_metrics = genetic_algorithm
+            .run_algorithm_with_progress(|snapshot| snapshot.metrics().epoch() < 2);
+        genetic_algorithm.cancel();
+
+        let events = events
+            .lock()
+            .expect("event log lock should not be poisoned");
+        assert_eq!(run_metrics.epochs().len(), 3);
+        assert_eq!(events.last(), Some(&"cancelled"));
+        assert_eq!(
+            events.iter().filter(|name| **name == "cancelled").count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_population_metrics_optionally_count_symmetry_classes() {
+        let solution = vec![0, 4, 7, 5, 2, 6, 1, 3];
+        let population = crate::symmetry::dihedral_transforms(&solution)
+            .into_iter()
+            .chain([vec![0, 1, 2, 3, 4, 5, 6, 7]])
+            .map(Chromosome::new)
+            .collect::<Vec<_>>();
+
+        let counted = super::population_metrics(&population, true, DiversityMeasures::NONE);
+        assert_eq!(counted.unique_chromosomes, 9);
+        assert_eq!(counted.unique_symmetry_classes, Some(2));
+        assert_eq!(
+            super::population_metrics(&population, false, DiversityMeasures::NONE)
+                .unique_symmetry_classes,
+            None
+        );
+    }
+
     #[test]
     fn test_run_metrics_include_adaptive_epoch_details() {
         let mut genetic_algorithm = build_genetic_algorithm(
@@ -1663,9 +3712,56 @@ mod tests {
         assert!(first_epoch.average_conflicts_sum() >= first_epoch.best_conflicts_sum() as f32);
         assert!(first_epoch.unique_chromosomes() > 0);
 
-        let second_epoch = &run_metrics.epochs()[2];
-        assert_eq!(second_epoch.stagnation_epochs(), 2);
-        assert!(second_epoch.elite_ratio() < first_epoch.elite_ratio());
+        let second_epoch = &run_metrics.epochs()[2];
+        assert_eq!(second_epoch.stagnation_epochs(), 2);
+        assert!(second_epoch.elite_ratio() < first_epoch.elite_ratio());
+    }
+
+    #[test]
+    fn test_run_metrics_record_phase_timings() {
+        let mut genetic_algorithm =
+            build_genetic_algorithm(GaConfig::new(16, 40, 5, 8).with_local_search_rate(1.0))
+                .expect("valid config should build");
+
+        let run_metrics = genetic_algorithm.run_algorithm();
+
+        let initial = run_metrics.epochs()[0].phase_timings();
+        assert_eq!(initial.mating_us, 0);
+        assert_eq!(initial.local_search_us, 0);
+        assert_eq!(initial.total_us(), initial.fitness_us);
+        let mut total = PhaseTimings::default();
+        for metrics in run_metrics.epochs() {
+            total += metrics.phase_timings();
+        }
+        assert_eq!(run_metrics.total_phase_timings(), total);
+        assert!(
+            total.total_us() <= u64::try_from(run_metrics.total_elapsed_ms() + 1).unwrap() * 1_000
+        );
+    }
+
+    #[test]
+    fn test_run_metrics_flag_stagnation_resets() {
+        let max_epochs = 120;
+        let mut genetic_algorithm = build_genetic_algorithm(
+            GaConfig::new(3, 8, max_epochs, 42)
+                .with_mutation_rate(0.0)
+                .with_offspring_ratio(0.0),
+        )
+        .expect("valid config should build");
+        let reset_interval = super::stagnation_reset_interval(max_epochs);
+
+        let run_metrics = genetic_algorithm.run_algorithm();
+
+        let epochs = run_metrics.epochs();
+        assert!(!epochs[0].stagnation_reset());
+        assert!(epochs.iter().any(|metrics| metrics.stagnation_reset()));
+        for pair in epochs.windows(2) {
+            let reset_due = pair[0].stagnation_epochs() >= reset_interval;
+            assert_eq!(pair[1].stagnation_reset(), reset_due);
+            if pair[1].stagnation_reset() {
+                assert!(pair[1].stagnation_epochs() <= 1);
+            }
+        }
     }
 
     #[test]
@@ -1698,6 +3794,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_symmetric_run_keeps_population_invariant() {
+        for symmetry in [
+            SymmetryConstraint::Rotation180,
+            SymmetryConstraint::Rotation90,
+        ] {
+            let mut genetic_algorithm = build_genetic_algorithm(
+                GaConfig::new(12, 64, 200, 42)
+                    .with_local_search_rate(0.25)
+                    .with_symmetry(symmetry),
+            )
+            .expect("valid config should build");
+
+            let run_metrics = genetic_algorithm.run_algorithm();
+
+            assert!(run_metrics.solved_epoch().is_some());
+            assert_eq!(
+                genetic_algorithm.get_best_chromosome().get_conflicts_sum(),
+                0
+            );
+            assert!(genetic_algorithm.population.iter().all(|chromosome| {
+                crate::symmetry::is_invariant(chromosome.get_positions(), symmetry)
+            }));
+        }
+    }
+
     #[test]
     fn test_run_metrics_mark_initial_solve_epoch() {
         let mut genetic_algorithm = build_genetic_algorithm(
@@ -1781,11 +3903,20 @@ mod tests {
         let mut chromosome = Chromosome::new(vec![0, 1, 2, 3, 4, 5, 6, 7]);
         let initial_conflicts_sum = chromosome.get_conflicts_sum();
         let mut rng = StdRng::seed_from_u64(7);
-
-        let improved = super::improve_chromosome_with_local_search(&mut chromosome, 200, &mut rng);
+        let mut evaluations = EvaluationCounts::default();
+
+        let improved = super::improve_chromosome_with_local_search(
+            &mut chromosome,
+            200,
+            DEFAULT_SYMMETRY_CONSTRAINT,
+            &mut rng,
+            &mut evaluations,
+        );
 
         assert!(improved);
         assert!(chromosome.get_conflicts_sum() < initial_conflicts_sum);
+        assert_eq!(evaluations.full, 0);
+        assert!((1..=200).contains(&evaluations.swap_delta));
 
         let mut positions = chromosome.get_positions().to_vec();
         positions.sort_unstable();
@@ -1809,10 +3940,18 @@ mod tests {
                 elite_ratio: 0.20,
                 offspring_ratio: DEFAULT_OFFSPRING_RATIO,
                 min_diversity_ratio: DEFAULT_MIN_DIVERSITY_RATIO,
+                diversity_measure: DEFAULT_DIVERSITY_MEASURE,
                 selection_strategy: DEFAULT_SELECTION_STRATEGY,
                 tournament_size: DEFAULT_TOURNAMENT_SIZE,
                 local_search_rate: 1.0,
                 local_search_attempts: 200,
+                symmetry: DEFAULT_SYMMETRY_CONSTRAINT,
+                count_symmetry_classes: DEFAULT_COUNT_SYMMETRY_CLASSES,
+                population_summary: DEFAULT_POPULATION_SUMMARY,
+                diversity_report_interval: None,
+                max_evaluations: None,
+                restart_policy: DEFAULT_RESTART_POLICY,
+                parameter_control: DEFAULT_PARAMETER_CONTROL,
             },
         );
 
@@ -1873,10 +4012,18 @@ mod tests {
                 elite_ratio: DEFAULT_ELITE_RATIO,
                 offspring_ratio: DEFAULT_OFFSPRING_RATIO,
                 min_diversity_ratio: DEFAULT_MIN_DIVERSITY_RATIO,
+                diversity_measure: DEFAULT_DIVERSITY_MEASURE,
                 selection_strategy: SelectionStrategy::Tournament,
                 tournament_size: 3,
                 local_search_rate: DEFAULT_LOCAL_SEARCH_RATE,
                 local_search_attempts: DEFAULT_LOCAL_SEARCH_ATTEMPTS,
+                symmetry: DEFAULT_SYMMETRY_CONSTRAINT,
+                count_symmetry_classes: DEFAULT_COUNT_SYMMETRY_CLASSES,
+                population_summary: DEFAULT_POPULATION_SUMMARY,
+                diversity_report_interval: None,
+                max_evaluations: None,
+                restart_policy: DEFAULT_RESTART_POLICY,
+                parameter_control: DEFAULT_PARAMETER_CONTROL,
             },
         );
 
@@ -1934,17 +4081,28 @@ mod tests {
                 elite_ratio: 0.25,
                 offspring_ratio: DEFAULT_OFFSPRING_RATIO,
                 min_diversity_ratio: 0.50,
+                diversity_measure: DEFAULT_DIVERSITY_MEASURE,
                 selection_strategy: DEFAULT_SELECTION_STRATEGY,
                 tournament_size: DEFAULT_TOURNAMENT_SIZE,
                 local_search_rate: DEFAULT_LOCAL_SEARCH_RATE,
                 local_search_attempts: DEFAULT_LOCAL_SEARCH_ATTEMPTS,
+                symmetry: DEFAULT_SYMMETRY_CONSTRAINT,
+                count_symmetry_classes: DEFAULT_COUNT_SYMMETRY_CLASSES,
+                population_summary: DEFAULT_POPULATION_SUMMARY,
+                diversity_report_interval: None,
+                max_evaluations: None,
+                restart_policy: DEFAULT_RESTART_POLICY,
+                parameter_control: DEFAULT_PARAMETER_CONTROL,
             },
         );
 
-        let unique_chromosomes =
-            super::population_metrics(&genetic_algorithm.population).unique_chromosomes;
+        let population_metrics = super::population_metrics(
+            &genetic_algorithm.population,
+            false,
+            DiversityMeasures::NONE,
+        );
         let replaced_count =
-            genetic_algorithm.refresh_low_diversity_population(0.25, unique_chromosomes);
+            genetic_algorithm.refresh_low_diversity_population(0.25, &population_metrics);
         let positions = genetic_algorithm
             .population
             .iter()
@@ -1953,7 +4111,149 @@ mod tests {
 
         assert_eq!(replaced_count, 2);
         assert!(positions.contains(&solution));
-        assert!(super::population_metrics(&genetic_algorithm.population).unique_chromosomes >= 2);
+        assert!(
+            super::population_metrics(
+                &genetic_algorithm.population,
+                false,
+                DiversityMeasures::NONE
+            )
+            .unique_chromosomes
+                >= 2
+        );
+    }
+
+    #[test]
+    fn test_diversity_metrics_are_zero_for_identical_population() {
+        let population = (0..6)
+            .map(|_| Chromosome::new(vec![0, 1, 2, 3, 4, 5, 6, 7]))
+            .collect::<Vec<_>>();
+
+        let metrics = super::population_metrics(&population, false, DiversityMeasures::ALL);
+
+        assert_eq!(metrics.gene_entropy, Some(0.0));
+        assert_eq!(metrics.mean_pairwise_distance, Some(0.0));
+        assert_eq!(metrics.mean_best_distance, Some(0.0));
+    }
+
+    #[test]
+    fn test_diversity_metrics_reach_one_for_disjoint_population() {
+        // Every chromosome is a distinct cyclic shift, so no two share a row in any column.
+        let population = (0..8u16)
+            .map(|shift| Chromosome::new((0..8).map(|column| (column + shift) % 8).collect()))
+            .collect::<Vec<_>>();
+
+        let metrics = super::population_metrics(&population, false, DiversityMeasures::ALL);
+
+        assert!((metrics.diversity(DiversityMeasure::GeneEntropy) - 1.0).abs() < 1e-5);
+        assert!((metrics.diversity(DiversityMeasure::PairwiseDistance) - 1.0).abs() < 1e-5);
+        assert!((metrics.diversity(DiversityMeasure::BestDistance) - 7.0 / 8.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_gene_entropy_spans_column_blocks_on_large_boards() {
+        let board_size = 2_048u16;
+        assert!(usize::from(board_size) * usize::from(board_size) > ENTROPY_COUNT_LIMIT);
+        let shifted = (0..4u16)
+            .map(|shift| {
+                Chromosome::new(
+                    (0..board_size)
+                        .map(|column| (column + shift) % board_size)
+                        .collect(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let identical = vec![shifted[0].clone(); 4];
+
+        assert!((super::gene_entropy(&shifted) - 1.0).abs() < 1e-5);
+        assert_eq!(super::gene_entropy(&identical), 0.0);
+    }
+
+    #[test]
+    fn test_unreported_epochs_compute_only_the_configured_measure() {
+        let config = GaConfig::new(24, 32, 6, 3)
+            .with_min_diversity_ratio(0.0)
+            .with_diversity_measure(DiversityMeasure::PairwiseDistance);
+        let run_metrics = build_genetic_algorithm(config)
+            .expect("valid config should build")
+            .run_algorithm();
+        let (last, earlier) = run_metrics
+            .epochs()
+            .split_last()
+            .expect("run should record epochs");
+
+        assert_eq!(last.epoch(), 6);
+        assert!(earlier.iter().all(|metrics| {
+            metrics.mean_pairwise_distance().is_some()
+                && metrics.gene_entropy().is_none()
+                && metrics.mean_best_distance().is_none()
+        }));
+        assert!(last.gene_entropy().is_some());
+        assert!(last.mean_pairwise_distance().is_some());
+        assert!(last.mean_best_distance().is_some());
+
+        let reported = build_genetic_algorithm(config.with_diversity_report_interval(Some(2)))
+            .expect("valid config should build")
+            .run_algorithm();
+        for metrics in reported.epochs() {
+            let reported_epoch =
+                metrics.epoch() % 2 == 0 || metrics.epoch() == reported.epochs().len() as u32 - 1;
+            assert_eq!(metrics.gene_entropy().is_some(), reported_epoch);
+            assert_eq!(metrics.mean_best_distance().is_some(), reported_epoch);
+        }
+        assert_eq!(
+            GaConfig::new(8, 32, 6, 3)
+                .with_diversity_report_interval(Some(0))
+                .validate(),
+            Err(GaConfigError::DiversityReportIntervalZero)
+        );
+    }
+
+    #[test]
+    fn test_entropy_measure_triggers_refresh_on_converged_population() {
+        let base = vec![0, 1, 2, 3, 4, 5, 6, 7];
+        let mut swapped = base.clone();
+        swapped.swap(0, 1);
+        // Two distinct chromosomes pass a 0.25 unique ratio for eight slots but leave
+        // almost no per-column entropy.
+        let mut population = vec![Chromosome::new(swapped)];
+        population.extend((0..7).map(|_| Chromosome::new(base.clone())));
+        let build = |diversity_measure| {
+            GeneticAlgorithm::new(
+                population.clone(),
+                StdRng::seed_from_u64(7),
+                GeneticAlgorithmParams {
+                    target_population_size: 8,
+                    max_epoch_count: 10,
+                    mutation_rate: DEFAULT_MUTATION_RATE,
+                    elite_ratio: 0.25,
+                    offspring_ratio: DEFAULT_OFFSPRING_RATIO,
+                    min_diversity_ratio: 0.25,
+                    diversity_measure,
+                    selection_strategy: DEFAULT_SELECTION_STRATEGY,
+                    tournament_size: DEFAULT_TOURNAMENT_SIZE,
+                    local_search_rate: DEFAULT_LOCAL_SEARCH_RATE,
+                    local_search_attempts: DEFAULT_LOCAL_SEARCH_ATTEMPTS,
+                    symmetry: DEFAULT_SYMMETRY_CONSTRAINT,
+                    count_symmetry_classes: DEFAULT_COUNT_SYMMETRY_CLASSES,
+                    population_summary: DEFAULT_POPULATION_SUMMARY,
+                    diversity_report_interval: None,
+                    max_evaluations: None,
+                    restart_policy: DEFAULT_RESTART_POLICY,
+                    parameter_control: DEFAULT_PARAMETER_CONTROL,
+                },
+            )
+        };
+        let mut unique_algorithm = build(DiversityMeasure::UniqueRatio);
+        let mut entropy_algorithm = build(DiversityMeasure::GeneEntropy);
+
+        let metrics =
+            super::population_metrics(&unique_algorithm.population, false, DiversityMeasures::ALL);
+        assert_eq!(
+            unique_algorithm.refresh_low_diversity_population(0.25, &metrics),
+            0
+        );
+        assert!(metrics.diversity(DiversityMeasure::GeneEntropy) < 0.25);
+        assert!(entropy_algorithm.refresh_low_diversity_population(0.25, &metrics) > 0);
     }
 
     #[test]
@@ -1974,10 +4274,18 @@ mod tests {
                 elite_ratio: DEFAULT_ELITE_RATIO,
                 offspring_ratio: DEFAULT_OFFSPRING_RATIO,
                 min_diversity_ratio: DEFAULT_MIN_DIVERSITY_RATIO,
+                diversity_measure: DEFAULT_DIVERSITY_MEASURE,
                 selection_strategy: DEFAULT_SELECTION_STRATEGY,
                 tournament_size: DEFAULT_TOURNAMENT_SIZE,
                 local_search_rate: DEFAULT_LOCAL_SEARCH_RATE,
                 local_search_attempts: DEFAULT_LOCAL_SEARCH_ATTEMPTS,
+                symmetry: DEFAULT_SYMMETRY_CONSTRAINT,
+                count_symmetry_classes: DEFAULT_COUNT_SYMMETRY_CLASSES,
+                population_summary: DEFAULT_POPULATION_SUMMARY,
+                diversity_report_interval: None,
+                max_evaluations: None,
+                restart_policy: DEFAULT_RESTART_POLICY,
+                parameter_control: DEFAULT_PARAMETER_CONTROL,
             },
         );
 
@@ -1991,6 +4299,288 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_perturb_best_restart_stays_near_best_chromosome() {
+        let spread = (0..32u16)
+            .map(|column| (column * 5) % 32)
+            .collect::<Vec<_>>();
+        let mut population = vec![Chromosome::new(spread)];
+        population.extend((0..15).map(|seed| Chromosome::new(shuffled_values(32, seed))));
+        let mut genetic_algorithm = build_test_algorithm(population);
+        genetic_algorithm.restart_policy =
+            DEFAULT_RESTART_POLICY.with_kind(RestartKind::PerturbBest);
+        let best = genetic_algorithm
+            .population
+            .iter()
+            .min_by_key(|chromosome| chromosome.get_conflicts_sum())
+            .unwrap()
+            .get_positions()
+            .to_vec();
+
+        let replaced_count = genetic_algorithm.soft_restart_population(DEFAULT_ELITE_RATIO);
+
+        assert_eq!(replaced_count, 15);
+        for chromosome in &genetic_algorithm.population {
+            let changed = chromosome
+                .get_positions()
+                .iter()
+                .zip(&best)
+                .filter(|(left, right)| left != right)
+                .count();
+            assert!(changed <= 2 * 32 / super::RESTART_PERTURBATION_DIVISOR);
+        }
+    }
+
+    #[test]
+    fn test_constructive_positions_beat_random_boards() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut constructive_conflicts = 0;
+        let mut random_conflicts = 0;
+        for _ in 0..20 {
+            let constructive = super::constructive_positions(64, &mut rng);
+            let random = super::random_positions(64, SymmetryConstraint::None, &mut rng);
+            constructive_conflicts += Chromosome::new(constructive).get_conflicts_sum();
+            random_conflicts += Chromosome::new(random).get_conflicts_sum();
+        }
+
+        assert!(constructive_conflicts < random_conflicts);
+    }
+
+    #[test]
+    fn test_restart_policy_sets_interval_and_disables_adaptation() {
+        let mut genetic_algorithm = build_genetic_algorithm(
+            GaConfig::new(3, 8, 40, 42)
+                .with_mutation_rate(0.05)
+                .with_offspring_ratio(0.0)
+                .with_restart_policy(
+                    DEFAULT_RESTART_POLICY
+                        .with_interval(Some(5))
+                        .with_adaptation(false),
+                ),
+        )
+        .unwrap();
+
+        let run_metrics = genetic_algorithm.run_algorithm();
+
+        let epochs = run_metrics.epochs();
+        assert!(epochs.iter().any(|metrics| metrics.stagnation_reset()));
+        for pair in epochs.windows(2) {
+            assert_eq!(pair[1].stagnation_reset(), pair[0].stagnation_epochs() >= 5);
+        }
+        assert!(epochs.iter().all(|metrics| metrics.mutation_rate() == 0.05));
+        assert!(
+            epochs
+                .iter()
+                .all(|metrics| metrics.elite_ratio() == DEFAULT_ELITE_RATIO)
+        );
+        assert_eq!(
+            GaConfig::new(8, 8, 10, 1)
+                .with_restart_policy(DEFAULT_RESTART_POLICY.with_interval(Some(0)))
+                .validate(),
+            Err(GaConfigError::RestartIntervalZero)
+        );
+        assert_eq!(
+            genetic_algorithm.set_restart_policy(DEFAULT_RESTART_POLICY.with_keep_ratio(1.5)),
+            Err(GaConfigError::InvalidRestartKeepRatio)
+        );
+        assert_eq!(
+            GaConfig::new(8, 8, 10, 1)
+                .with_symmetry(SymmetryConstraint::Rotation180)
+                .with_restart_policy(DEFAULT_RESTART_POLICY.with_kind(RestartKind::Constructive))
+                .validate(),
+            Err(GaConfigError::ConstructiveRestartWithSymmetry)
+        );
+    }
+
+    #[test]
+    fn test_controllers_record_state_per_epoch() {
+        let run = |parameter_control| {
+            build_genetic_algorithm(
+                GaConfig::new(12, 40, 30, 11)
+                    .with_mutation_rate(0.2)
+                    .with_parameter_control(parameter_control),
+            )
+            .unwrap()
+            .run_algorithm()
+        };
+
+        let one_fifth = run(ParameterControl::OneFifthRule);
+        let one_fifth_epochs = one_fifth.epochs();
+        assert!(
+            one_fifth_epochs
+                .iter()
+                .any(|metrics| metrics.mutation_rate() != 0.2)
+        );
+        for pair in one_fifth_epochs[1..].windows(2) {
+            let ratio = pair[0].control().mutation_success_ratio;
+            let expected = if ratio > 0.2 {
+                pair[0].mutation_rate() * 1.22
+            } else {
+                pair[0].mutation_rate() / 1.22
+            };
+            assert!((pair[1].mutation_rate() - expected.clamp(0.005, 0.6)).abs() < 1e-5);
+        }
+
+        let self_adaptive = run(ParameterControl::SelfAdaptive);
+        assert!(
+            self_adaptive
+                .epochs()
+                .iter()
+                .skip(1)
+                .any(|metrics| metrics.mutation_rate() != 0.2)
+        );
+
+        let bandit = run(ParameterControl::OperatorBandit);
+        let last = bandit.epochs().last().unwrap().control();
+        assert!((last.crossover_probabilities.iter().sum::<f32>() - 1.0).abs() < 1e-5);
+        assert!((last.mutation_probabilities.iter().sum::<f32>() - 1.0).abs() < 1e-5);
+        assert_ne!(last.crossover_probabilities, [1.0, 0.0, 0.0]);
+
+        let stagnation = run(ParameterControl::Stagnation);
+        assert!(
+            stagnation
+                .epochs()
+                .iter()
+                .all(|metrics| metrics.control().crossover_probabilities == [1.0, 0.0, 0.0])
+        );
+    }
+
+    #[test]
+    fn test_stagnation_control_matches_runs_without_a_controller_choice() {
+        let run = |config: GaConfig| {
+            build_genetic_algorithm(config)
+                .unwrap()
+                .run_algorithm()
+                .epochs()
+                .iter()
+                .map(|metrics| metrics.best_conflicts_sum())
+                .collect::<Vec<_>>()
+        };
+        let config = GaConfig::new(10, 30, 40, 4);
+
+        assert_eq!(
+            run(config),
+            run(config.with_parameter_control(ParameterControl::Stagnation))
+        );
+        assert_eq!(
+            config
+                .with_parameter_control(ParameterControl::OperatorBandit)
+                .with_restart_policy(DEFAULT_RESTART_POLICY.with_adaptation(false))
+                .validate(),
+            Err(GaConfigError::ParameterControlWithoutAdaptation)
+        );
+        let mut genetic_algorithm = build_genetic_algorithm(
+            config.with_restart_policy(DEFAULT_RESTART_POLICY.with_adaptation(false)),
+        )
+        .unwrap();
+        assert_eq!(
+            genetic_algorithm.set_parameter_control(ParameterControl::SelfAdaptive),
+            Err(GaConfigError::ParameterControlWithoutAdaptation)
+        );
+    }
+
+    #[test]
+    fn test_self_adaptive_rates_cover_every_chromosome() {
+        let mut genetic_algorithm = build_genetic_algorithm(
+            GaConfig::new(16, 30, 12, 6)
+                .with_parameter_control(ParameterControl::SelfAdaptive)
+                .with_min_diversity_ratio(0.95)
+                .with_restart_policy(DEFAULT_RESTART_POLICY.with_interval(Some(1))),
+        )
+        .unwrap();
+        let mut positions = (0..16).collect::<Vec<u16>>();
+        positions.reverse();
+        genetic_algorithm.seed_population(&positions).unwrap();
+        genetic_algorithm.init();
+        let has_own_rates = |genetic_algorithm: &GeneticAlgorithm| {
+            genetic_algorithm
+                .population
+                .iter()
+                .all(|chromosome| chromosome.get_mutation_rate().is_some())
+        };
+        assert!(has_own_rates(&genetic_algorithm));
+
+        while !genetic_algorithm.is_finished() {
+            genetic_algorithm.step();
+            genetic_algorithm.inject_chromosome(&positions).unwrap();
+            assert!(has_own_rates(&genetic_algorithm));
+        }
+        assert!(
+            genetic_algorithm
+                .run_metrics()
+                .epochs()
+                .iter()
+                .any(|metrics| metrics.stagnation_reset())
+        );
+
+        let mut switched = build_genetic_algorithm(GaConfig::new(16, 30, 12, 6)).unwrap();
+        switched.init();
+        switched.step();
+        switched
+            .set_parameter_control(ParameterControl::SelfAdaptive)
+            .unwrap();
+        assert!(has_own_rates(&switched));
+    }
+
+    #[test]
+    fn test_alternative_operators_return_valid_permutations() {
+        let mut rng = StdRng::seed_from_u64(2027);
+        let expected_values = (0u16..16).collect::<Vec<_>>();
+
+        for _ in 0..300 {
+            let mut parent_one = expected_values.clone();
+            let mut parent_two = expected_values.clone();
+            parent_one.shuffle(&mut rng);
+            parent_two.shuffle(&mut rng);
+
+            for operator in [CrossoverOperator::Order, CrossoverOperator::Cycle] {
+                let child = super::mate_chromosomes(
+                    &parent_one,
+                    &parent_two,
+                    SymmetryConstraint::None,
+                    operator,
+                    &mut rng,
+                );
+                let mut child_sorted = child.get_positions().to_vec();
+                child_sorted.sort_unstable();
+                assert_eq!(child_sorted, expected_values);
+            }
+
+            for operator in [MutationOperator::Inversion, MutationOperator::Insertion] {
+                let mut chromosome = Chromosome::new(parent_one.clone());
+                chromosome.set_mutation_rate(Some(0.3));
+                super::apply_mutation(&mut chromosome, operator, 3, 9);
+                let mut sorted = chromosome.get_positions().to_vec();
+                sorted.sort_unstable();
+                assert_eq!(sorted, expected_values);
+                assert_eq!(chromosome.get_mutation_rate(), Some(0.3));
+                assert_eq!(
+                    chromosome.get_conflicts_sum(),
+                    Chromosome::new(chromosome.get_positions().to_vec()).get_conflicts_sum()
+                );
+            }
+        }
+
+        let parent_one = vec![0, 1, 2, 3, 4, 5, 6, 7];
+        let parent_two = vec![1, 0, 3, 2, 5, 4, 7, 6];
+        let child = super::cycle_crossover(&parent_one, &parent_two, &mut rng);
+        // Each pair of columns is its own cycle, and cycles alternate between parents.
+        let from_parent_one = child
+            .chunks(2)
+            .zip(parent_one.chunks(2))
+            .map(|(pair, parent_pair)| pair == parent_pair)
+            .collect::<Vec<_>>();
+        for pair in from_parent_one.windows(2) {
+            assert_ne!(pair[0], pair[1]);
+        }
+        let mut inverted = Chromosome::new(parent_one.clone());
+        super::apply_mutation(&mut inverted, MutationOperator::Inversion, 5, 2);
+        assert_eq!(inverted.get_positions(), &[0, 1, 5, 4, 3, 2, 6, 7]);
+        let mut inserted = Chromosome::new(parent_one);
+        super::apply_mutation(&mut inserted, MutationOperator::Insertion, 1, 4);
+        assert_eq!(inserted.get_positions(), &[0, 2, 3, 4, 1, 5, 6, 7]);
+    }
+
     #[test]
     fn test_soft_restart_replaces_at_least_one_chromosome() {
         let population = (0..4)
@@ -2007,10 +4597,18 @@ mod tests {
                 elite_ratio: 1.0,
                 offspring_ratio: DEFAULT_OFFSPRING_RATIO,
                 min_diversity_ratio: DEFAULT_MIN_DIVERSITY_RATIO,
+                diversity_measure: DEFAULT_DIVERSITY_MEASURE,
                 selection_strategy: DEFAULT_SELECTION_STRATEGY,
                 tournament_size: DEFAULT_TOURNAMENT_SIZE,
                 local_search_rate: DEFAULT_LOCAL_SEARCH_RATE,
                 local_search_attempts: DEFAULT_LOCAL_SEARCH_ATTEMPTS,
+                symmetry: DEFAULT_SYMMETRY_CONSTRAINT,
+                count_symmetry_classes: DEFAULT_COUNT_SYMMETRY_CLASSES,
+                population_summary: DEFAULT_POPULATION_SUMMARY,
+                diversity_report_interval: None,
+                max_evaluations: None,
+                restart_policy: DEFAULT_RESTART_POLICY,
+                parameter_control: DEFAULT_PARAMETER_CONTROL,
             },
         );
 
@@ -2056,6 +4654,230 @@ mod tests {
         assert_eq!(child_sorted, parent_one);
     }
 
+    #[test]
+    fn test_seed_population_places_board_in_population() {
+        let solution = vec![0, 4, 7, 5, 2, 6, 1, 3];
+        let mut genetic_algorithm = build_genetic_algorithm(GaConfig::new(8, 16, 10, 3))
+            .expect("valid config should build");
+
+        genetic_algorithm
+            .seed_population(&solution)
+            .expect("a valid board should seed the population");
+        let run_metrics = genetic_algorithm.run_algorithm();
+
+        assert_eq!(run_metrics.solved_epoch(), Some(0));
+        assert_eq!(
+            genetic_algorithm.get_best_chromosome().get_positions(),
+            solution
+        );
+        assert_eq!(
+            genetic_algorithm.seed_population(&[0, 1, 2]),
+            Err(BoardSeedError::SizeMismatch {
+                expected: 8,
+                actual: 3
+            })
+        );
+        assert_eq!(
+            genetic_algorithm.seed_population(&[0, 0, 1, 2, 3, 4, 5, 6]),
+            Err(BoardSeedError::InvalidBoard(
+                ChromosomeError::DuplicatePosition
+            ))
+        );
+    }
+
+    #[test]
+    fn test_injected_solution_is_found_on_next_step() {
+        let solution = vec![1, 3, 5, 7, 9, 11, 0, 2, 4, 6, 8, 10];
+        let mut genetic_algorithm = build_genetic_algorithm(
+            GaConfig::new(12, 8, 50, 5)
+                .with_mutation_rate(0.0)
+                .with_offspring_ratio(0.0),
+        )
+        .expect("valid config should build");
+
+        genetic_algorithm.init();
+        assert_ne!(genetic_algorithm.step(), EpochOutcome::Solved);
+        let population_size = genetic_algorithm.get_population_size();
+        genetic_algorithm
+            .inject_chromosome(&solution)
+            .expect("a valid board should be injected");
+
+        assert_eq!(genetic_algorithm.get_population_size(), population_size);
+        assert_eq!(genetic_algorithm.step(), EpochOutcome::Solved);
+        assert_eq!(
+            genetic_algorithm.get_best_chromosome().get_positions(),
+            solution
+        );
+        assert_eq!(
+            genetic_algorithm.inject_chromosome(&[0, 1]),
+            Err(BoardSeedError::SizeMismatch {
+                expected: 12,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_injection_is_rejected_once_the_budget_is_spent() {
+        let board = (0..12).collect::<Vec<u16>>();
+        let mut genetic_algorithm =
+            build_genetic_algorithm(GaConfig::new(12, 8, 50, 5).with_max_evaluations(Some(9)))
+                .expect("valid config should build");
+
+        genetic_algorithm.init();
+        genetic_algorithm
+            .inject_chromosome(&board)
+            .expect("the budget should cover one more board");
+        assert_eq!(
+            genetic_algorithm.inject_chromosome(&board),
+            Err(BoardSeedError::EvaluationBudgetExhausted)
+        );
+        assert_eq!(
+            genetic_algorithm.step(),
+            EpochOutcome::EvaluationBudgetReached
+        );
+        assert_eq!(
+            genetic_algorithm.run_metrics().total_evaluations().total(),
+            9
+        );
+        assert_eq!(
+            genetic_algorithm.inject_chromosome(&board),
+            Err(BoardSeedError::EvaluationBudgetExhausted)
+        );
+    }
+
+    #[test]
+    fn test_parameter_setters_validate_and_extend_the_run() {
+        let mut genetic_algorithm = build_genetic_algorithm(
+            GaConfig::new(10, 4, 2, 9)
+                .with_mutation_rate(0.0)
+                .with_offspring_ratio(0.0),
+        )
+        .expect("valid config should build");
+
+        assert_eq!(
+            genetic_algorithm.set_mutation_rate(1.5),
+            Err(GaConfigError::InvalidMutationRate)
+        );
+        assert_eq!(
+            genetic_algorithm.set_elite_ratio(f32::NAN),
+            Err(GaConfigError::InvalidEliteRatio)
+        );
+        assert_eq!(
+            genetic_algorithm.set_tournament_size(0),
+            Err(GaConfigError::TournamentSizeZero)
+        );
+        assert_eq!(
+            genetic_algorithm.set_max_epoch_count(0),
+            Err(GaConfigError::MaxEpochCountZero)
+        );
+
+        while !genetic_algorithm.is_finished() {
+            genetic_algorithm.step();
+        }
+        if genetic_algorithm.run_metrics().solved_epoch().is_some() {
+            return;
+        }
+        genetic_algorithm
+            .set_max_epoch_count(4)
+            .expect("a positive epoch count is valid");
+        genetic_algorithm
+            .set_mutation_rate(0.5)
+            .expect("a unit interval rate is valid");
+        assert!(!genetic_algorithm.is_finished());
+        while !genetic_algorithm.is_finished() {
+            genetic_algorithm.step();
+        }
+
+        let run_metrics = genetic_algorithm.take_run_metrics();
+        let last_epoch = run_metrics.epochs().last().expect("run records epochs");
+        assert!(last_epoch.epoch() > 2);
+        assert!(last_epoch.mutation_rate() > 0.0);
+
+        genetic_algorithm
+            .set_max_epoch_count(1)
+            .expect("a positive epoch count is valid");
+        genetic_algorithm.init();
+        genetic_algorithm.step();
+        assert!(genetic_algorithm.is_finished());
+    }
+
+    #[test]
+    fn test_population_summary_is_attached_only_when_requested() {
+        let mut summarized =
+            build_genetic_algorithm(GaConfig::new(8, 32, 5, 9).with_population_summary(true))
+                .expect("valid config should build");
+        summarized.run_algorithm_with_progress(|snapshot| {
+            let summary = snapshot
+                .population_summary()
+                .expect("summary should be attached to every snapshot");
+            assert_eq!(summary.board_size(), 8);
+            assert_eq!(
+                summary
+                    .conflict_histogram()
+                    .iter()
+                    .map(|&(_, count)| count as usize)
+                    .sum::<usize>(),
+                summary.population_size()
+            );
+            true
+        });
+
+        let mut plain =
+            build_genetic_algorithm(GaConfig::new(8, 32, 5, 9)).expect("valid config should build");
+        plain.run_algorithm_with_progress(|snapshot| {
+            assert!(snapshot.population_summary().is_none());
+            true
+        });
+    }
+
+    #[test]
+    fn test_fixed_queens_stay_in_place_for_the_whole_run() {
+        let fixed_queens = [(0, 0), (1, 4)];
+        let mut genetic_algorithm = build_genetic_algorithm(
+            GaConfig::new(8, 64, 200, 11).with_selection_strategy(SelectionStrategy::Tournament),
+        )
+        .expect("valid config should build");
+        genetic_algorithm
+            .fix_queens(&fixed_queens)
+            .expect("non-attacking queens should be fixable");
+
+        let mut checked_epochs = 0;
+        genetic_algorithm.run_algorithm_with_progress(|snapshot| {
+            for &(column, row) in &fixed_queens {
+                assert_eq!(snapshot.best_positions()[column], row);
+            }
+            checked_epochs += 1;
+            true
+        });
+
+        assert!(checked_epochs > 0);
+        for chromosome in &genetic_algorithm.population {
+            assert_eq!(chromosome.get_positions()[0], 0);
+            assert_eq!(chromosome.get_positions()[1], 4);
+        }
+        assert_eq!(
+            genetic_algorithm.fix_queens(&[(0, 0), (1, 1)]),
+            Err(BoardSeedError::FixedQueensShareLine {
+                column_one: 0,
+                column_two: 1
+            })
+        );
+        assert_eq!(
+            genetic_algorithm.fix_queens(&[(8, 0)]),
+            Err(BoardSeedError::FixedQueenOutOfBounds { column: 8, row: 0 })
+        );
+
+        let mut symmetric_algorithm = build_genetic_algorithm(
+            GaConfig::new(8, 16, 10, 3).with_symmetry(SymmetryConstraint::Rotation180),
+        )
+        .expect("valid symmetric config should build");
+        assert_eq!(
+            symmetric_algorithm.fix_queens(&[(0, 0)]),
+            Err(BoardSeedError::FixedQueensWithSymmetry)
+        );
+    }
+
     proptest! {
         #![proptest_config(ProptestConfig::with_cases(128))]
 
@@ -7,6 +7,9 @@ pub struct Chromosome {
     conflicts: OnceLock<Vec<u32>>,
     conflicts_sum: u32,
     fitness: f32,
+    // The chromosome's own mutation rate under `ParameterControl::SelfAdaptive`; `None`
+    // uses the run's rate.
+    mutation_rate: Option<f32>,
 }
 
 impl Clone for Chromosome {
@@ -21,6 +24,7 @@ impl Clone for Chromosome {
             conflicts,
             conflicts_sum: self.conflicts_sum,
             fitness: self.fitness,
+            mutation_rate: self.mutation_rate,
         }
     }
 }
@@ -78,6 +82,7 @@ impl Chromosome {
             conflicts: OnceLock::new(),
             conflicts_sum,
             fitness: 0.0,
+            mutation_rate: None,
         }
     }
 
@@ -124,6 +129,10 @@ impl Chromosome {
         self.conflicts_sum
     }
 
+    pub fn get_attacking_pairs(&self) -> Vec<(usize, usize)> {
+        attacking_pairs(&self.positions)
+    }
+
     pub fn get_fitness(&self) -> f32 {
         self.fitness
     }
@@ -132,6 +141,14 @@ impl Chromosome {
         self.fitness = fitness;
     }
 
+    pub fn get_mutation_rate(&self) -> Option<f32> {
+        self.mutation_rate
+    }
+
+    pub fn set_mutation_rate(&mut self, mutation_rate: Option<f32>) {
+        self.mutation_rate = mutation_rate;
+    }
+
     fn recalculate_conflicts_after_swap(
         &mut self,
         index_one: usize,
@@ -261,6 +278,38 @@ fn count_conflicts_sum(positions: &[u16]) -> u32 {
         .sum()
 }
 
+fn attacking_pairs(positions: &[u16]) -> Vec<(usize, usize)> {
+    let size = positions.len();
+    if size < 2 {
+        return Vec::new();
+    }
+
+    let diagonal_span = size * 2 - 1;
+    let diagonal_offset = size - 1;
+    let mut descending_diagonals = vec![Vec::new(); diagonal_span];
+    let mut ascending_diagonals = vec![Vec::new(); diagonal_span];
+
+    for (x, &y) in positions.iter().enumerate() {
+        let y = usize::from(y);
+        descending_diagonals[x + diagonal_offset - y].push(x);
+        ascending_diagonals[x + y].push(x);
+    }
+
+    let mut pairs = descending_diagonals
+        .iter()
+        .chain(&ascending_diagonals)
+        .flat_map(|columns| {
+            columns.iter().enumerate().flat_map(|(index, &x_one)| {
+                columns[index + 1..]
+                    .iter()
+                    .map(move |&x_two| (x_one, x_two))
+            })
+        })
+        .collect::<Vec<_>>();
+    pairs.sort_unstable();
+    pairs
+}
+
 fn conflicting_pair_count(count: u32) -> u32 {
     count.saturating_sub(1) * count / 2
 }
@@ -375,6 +424,17 @@ mod tests {
         assert_eq!(conflicts_sum, 2);
     }
 
+    #[test]
+    fn test_attacking_pairs_match_conflicts() {
+        let chromosome = Chromosome::new(vec![0, 2, 4, 6, 1, 3, 5, 7]);
+        assert_eq!(chromosome.get_attacking_pairs(), vec![(0, 7)]);
+
+        let chromosome = Chromosome::new(vec![0, 1, 2, 3]);
+        let pairs = chromosome.get_attacking_pairs();
+        assert_eq!(pairs.len(), chromosome.get_conflicts_sum() as usize);
+        assert_eq!(pairs, vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+    }
+
     #[test]
     fn test_conflicts_counter_small_boards() {
         let single_queen = Chromosome::new(vec![0]);
@@ -0,0 +1,508 @@
+use std::fmt;
+
+use rand::{Rng, RngExt};
+
+use super::MAX_ADAPTIVE_MUTATION_RATE;
+
+const ONE_FIFTH_SUCCESS_RATIO: f32 = 0.2;
+const ONE_FIFTH_STEP_FACTOR: f32 = 1.22;
+const MIN_CONTROLLED_MUTATION_RATE: f32 = 0.005;
+const SELF_ADAPTIVE_LEARNING_RATE: f32 = 0.3;
+const OPERATOR_ADAPTATION_RATE: f32 = 0.3;
+const MIN_OPERATOR_PROBABILITY: f32 = 0.05;
+pub const CROSSOVER_OPERATORS: [CrossoverOperator; 3] = [
+    CrossoverOperator::PartiallyMapped,
+    CrossoverOperator::Order,
+    CrossoverOperator::Cycle,
+];
+pub const MUTATION_OPERATORS: [MutationOperator; 3] = [
+    MutationOperator::Swap,
+    MutationOperator::Inversion,
+    MutationOperator::Insertion,
+];
+
+// How the mutation rate, elite ratio, and operators change while a run goes on. None of
+// them runs when `RestartPolicy::adaptive` is off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParameterControl {
+    // The mutation rate rises and the elite ratio falls linearly with stagnant epochs.
+    Stagnation,
+    // After each epoch the mutation rate grows when more than a fifth of the mutations
+    // lowered a chromosome's conflicts and shrinks otherwise.
+    OneFifthRule,
+    // Every chromosome carries its own mutation rate, which children inherit as the mean
+    // of their parents' rates. A rate gets a log-normal perturbation just before it
+    // decides whether its chromosome mutates.
+    SelfAdaptive,
+    // Each child picks its crossover and mutation operators by probability matching on
+    // how much they lowered conflicts so far. Under a symmetry constraint only the
+    // symmetric operators exist, so the configured rates and operators are kept.
+    OperatorBandit,
+}
+
+impl fmt::Display for ParameterControl {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Stagnation => formatter.write_str("stagnation"),
+            Self::OneFifthRule => formatter.write_str("one-fifth"),
+            Self::SelfAdaptive => formatter.write_str("self-adaptive"),
+            Self::OperatorBandit => formatter.write_str("bandit"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrossoverOperator {
+    PartiallyMapped,
+    Order,
+    Cycle,
+}
+
+impl fmt::Display for CrossoverOperator {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PartiallyMapped => formatter.write_str("pmx"),
+            Self::Order => formatter.write_str("order"),
+            Self::Cycle => formatter.write_str("cycle"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MutationOperator {
+    Swap,
+    Inversion,
+    Insertion,
+}
+
+impl fmt::Display for MutationOperator {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Swap => formatter.write_str("swap"),
+            Self::Inversion => formatter.write_str("inversion"),
+            Self::Insertion => formatter.write_str("insertion"),
+        }
+    }
+}
+
+// What the controller did during one epoch. The probabilities are the chances each
+// operator in `CROSSOVER_OPERATORS` and `MUTATION_OPERATORS` had of being picked; without
+// operator selection PMX and swap are always used.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ControlState {
+    // Fraction of the epoch's mutations that lowered a chromosome's conflicts.
+    pub mutation_success_ratio: f32,
+    pub crossover_probabilities: [f32; 3],
+    pub mutation_probabilities: [f32; 3],
+}
+
+impl Default for ControlState {
+    fn default() -> Self {
+        Self {
+            mutation_success_ratio: 0.0,
+            crossover_probabilities: [1.0, 0.0, 0.0],
+            mutation_probabilities: [1.0, 0.0, 0.0],
+        }
+    }
+}
+
+// Probability matching: each operator's quality follows the mean reward of its uses
+// per epoch, and its chance of being picked is its share of the total quality, with a
+// floor so no operator stops being tried.
+#[derive(Debug, Clone, Copy)]
+struct OperatorSelector<const N: usize> {
+    qualities: [f32; N],
+    probabilities: [f32; N],
+    rewards: [f32; N],
+    uses: [u32; N],
+}
+
+impl<const N: usize> OperatorSelector<N> {
+    fn new() -> Self {
+        Self {
+            qualities: [1.0; N],
+            probabilities: [1.0 / N as f32; N],
+            rewards: [0.0; N],
+            uses: [0; N],
+        }
+    }
+
+    fn select(&self, rng: &mut impl Rng) -> usize {
+        let spin = rng.random::<f32>();
+        let mut cumulative = 0.0;
+        for (index, probability) in self.probabilities.iter().enumerate() {
+            cumulative += probability;
+            if spin < cumulative {
+                return index;
+            }
+        }
+        N - 1
+    }
+
+    fn reward(&mut self, index: usize, reward: f32) {
+        self.rewards[index] += reward;
+        self.uses[index] += 1;
+    }
+
+    fn update(&mut self) {
+        for index in 0..N {
+            if self.uses[index] > 0 {
+                let mean_reward = self.rewards[index] / self.uses[index] as f32;
+                self.qualities[index] +=
+                    OPERATOR_ADAPTATION_RATE * (mean_reward - self.qualities[index]);
+            }
+        }
+        self.rewards = [0.0; N];
+        self.uses = [0; N];
+
+        let total_quality = self.qualities.iter().sum::<f32>();
+        let spread = 1.0 - N as f32 * MIN_OPERATOR_PROBABILITY;
+        for index in 0..N {
+            self.probabilities[index] = if total_quality <= f32::EPSILON {
+                1.0 / N as f32
+            } else {
+                MIN_OPERATOR_PROBABILITY + spread * self.qualities[index] / total_quality
+            };
+        }
+    }
+}
+
+// The running state of the configured `ParameterControl`; `None` means fixed rates and
+// operators.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Controller {
+    control: Option<ParameterControl>,
+    symmetric: bool,
+    base_mutation_rate: f32,
+    mutation_rate: f32,
+    mutation_trials: u32,
+    mutation_successes: u32,
+    crossover: OperatorSelector<3>,
+    mutation: OperatorSelector<3>,
+}
+
+impl Controller {
+    pub(crate) fn new(
+        control: Option<ParameterControl>,
+        symmetric: bool,
+        base_mutation_rate: f32,
+    ) -> Self {
+        Self {
+            control,
+            symmetric,
+            base_mutation_rate,
+            mutation_rate: base_mutation_rate,
+            mutation_trials: 0,
+            mutation_successes: 0,
+            crossover: OperatorSelector::new(),
+            mutation: OperatorSelector::new(),
+        }
+    }
+
+    pub(crate) fn control(&self) -> Option<ParameterControl> {
+        self.control
+    }
+
+    pub(crate) fn is_configured_for(
+        &self,
+        control: Option<ParameterControl>,
+        symmetric: bool,
+    ) -> bool {
+        self.control == control && self.symmetric == symmetric
+    }
+
+    // Also restarts the one-fifth rule from the new rate.
+    pub(crate) fn set_base_mutation_rate(&mut self, base_mutation_rate: f32) {
+        self.base_mutation_rate = base_mutation_rate;
+        self.mutation_rate = base_mutation_rate;
+    }
+
+    // The mutation rate chosen by the one-fifth rule for the next epoch.
+    pub(crate) fn mutation_rate(&self) -> f32 {
+        self.mutation_rate
+    }
+
+    pub(crate) fn is_self_adaptive(&self) -> bool {
+        self.control == Some(ParameterControl::SelfAdaptive)
+    }
+
+    fn selects_operators(&self) -> bool {
+        self.control == Some(ParameterControl::OperatorBandit) && !self.symmetric
+    }
+
+    // Draws from `rng` only when operators are being selected, so the other controllers
+    // replay the same runs for a given seed.
+    pub(crate) fn select_crossover(&self, rng: &mut impl Rng) -> CrossoverOperator {
+        if !self.selects_operators() {
+            return CrossoverOperator::PartiallyMapped;
+        }
+        CROSSOVER_OPERATORS[self.crossover.select(rng)]
+    }
+
+    pub(crate) fn select_mutation(&self, rng: &mut impl Rng) -> MutationOperator {
+        if !self.selects_operators() {
+            return MutationOperator::Swap;
+        }
+        MUTATION_OPERATORS[self.mutation.select(rng)]
+    }
+
+    // The rate a chromosome mutates at in an epoch whose rate is `epoch_mutation_rate`.
+    pub(crate) fn chromosome_mutation_rate(
+        &self,
+        chromosome_mutation_rate: Option<f32>,
+        epoch_mutation_rate: f32,
+    ) -> f32 {
+        match chromosome_mutation_rate {
+            Some(mutation_rate) if self.is_self_adaptive() => mutation_rate,
+            _ => epoch_mutation_rate,
+        }
+    }
+
+    // The mutation rate a new chromosome starts with: the configured rate with a
+    // log-normal change, or `None` when rates are not self-adaptive.
+    pub(crate) fn initial_mutation_rate(&self, rng: &mut impl Rng) -> Option<f32> {
+        self.is_self_adaptive()
+            .then(|| self.perturbed_rate(self.base_mutation_rate, rng))
+    }
+
+    // A child's own mutation rate, the mean of its parents', or `None` when rates are not
+    // self-adaptive.
+    pub(crate) fn inherited_mutation_rate(
+        &self,
+        parent_one: Option<f32>,
+        parent_two: Option<f32>,
+    ) -> Option<f32> {
+        if !self.is_self_adaptive() {
+            return None;
+        }
+
+        Some(
+            (parent_one.unwrap_or(self.base_mutation_rate)
+                + parent_two.unwrap_or(self.base_mutation_rate))
+                / 2.0,
+        )
+    }
+
+    // Applies the log-normal change to a chromosome's own rate just before that rate
+    // decides whether the chromosome mutates. Other controllers keep `rate` as it is.
+    pub(crate) fn self_adapted_mutation_rate(
+        &self,
+        rate: Option<f32>,
+        rng: &mut impl Rng,
+    ) -> Option<f32> {
+        if !self.is_self_adaptive() {
+            return rate;
+        }
+        Some(self.perturbed_rate(rate.unwrap_or(self.base_mutation_rate), rng))
+    }
+
+    fn perturbed_rate(&self, rate: f32, rng: &mut impl Rng) -> f32 {
+        let perturbation = (SELF_ADAPTIVE_LEARNING_RATE * standard_normal(rng)).exp();
+        (rate * perturbation).clamp(self.min_mutation_rate(), self.max_mutation_rate())
+    }
+
+    // `parents_conflicts_sum` is the mean of the two parents' conflicts.
+    pub(crate) fn record_crossover(
+        &mut self,
+        operator: CrossoverOperator,
+        parents_conflicts_sum: f32,
+        child_conflicts_sum: u32,
+    ) {
+        if !self.selects_operators() {
+            return;
+        }
+        let reward = relative_improvement(parents_conflicts_sum, child_conflicts_sum as f32);
+        self.crossover
+            .reward(operator_index(&CROSSOVER_OPERATORS, operator), reward);
+    }
+
+    pub(crate) fn record_mutation(
+        &mut self,
+        operator: MutationOperator,
+        conflicts_sum_before: u32,
+        conflicts_sum_after: u32,
+    ) {
+        self.mutation_trials += 1;
+        if conflicts_sum_after < conflicts_sum_before {
+            self.mutation_successes += 1;
+        }
+        if self.selects_operators() {
+            let reward =
+                relative_improvement(conflicts_sum_before as f32, conflicts_sum_after as f32);
+            self.mutation
+                .reward(operator_index(&MUTATION_OPERATORS, operator), reward);
+        }
+    }
+
+    // Reports the epoch that just ran and adapts the rate and operator odds for the next.
+    pub(crate) fn finish_epoch(&mut self) -> ControlState {
+        let mutation_success_ratio = if self.mutation_trials == 0 {
+            0.0
+        } else {
+            self.mutation_successes as f32 / self.mutation_trials as f32
+        };
+        let state = self.state(mutation_success_ratio);
+
+        if self.control == Some(ParameterControl::OneFifthRule) && self.mutation_trials > 0 {
+            let step = if mutation_success_ratio > ONE_FIFTH_SUCCESS_RATIO {
+                ONE_FIFTH_STEP_FACTOR
+            } else {
+                ONE_FIFTH_STEP_FACTOR.recip()
+            };
+            self.mutation_rate = (self.mutation_rate * step)
+                .clamp(self.min_mutation_rate(), self.max_mutation_rate());
+        }
+        if self.selects_operators() {
+            self.crossover.update();
+            self.mutation.update();
+        }
+        self.mutation_trials = 0;
+        self.mutation_successes = 0;
+
+        state
+    }
+
+    // The state recorded for epoch 0, before any operator has run.
+    pub(crate) fn initial_state(&self) -> ControlState {
+        self.state(0.0)
+    }
+
+    fn state(&self, mutation_success_ratio: f32) -> ControlState {
+        if !self.selects_operators() {
+            return ControlState {
+                mutation_success_ratio,
+                ..ControlState::default()
+            };
+        }
+        ControlState {
+            mutation_success_ratio,
+            crossover_probabilities: self.crossover.probabilities,
+            mutation_probabilities: self.mutation.probabilities,
+        }
+    }
+
+    fn min_mutation_rate(&self) -> f32 {
+        MIN_CONTROLLED_MUTATION_RATE.min(self.base_mutation_rate)
+    }
+
+    fn max_mutation_rate(&self) -> f32 {
+        MAX_ADAPTIVE_MUTATION_RATE.max(self.base_mutation_rate)
+    }
+}
+
+fn operator_index<T: PartialEq>(operators: &[T], operator: T) -> usize {
+    operators
+        .iter()
+        .position(|candidate| *candidate == operator)
+        .expect("every operator is listed")
+}
+
+// Share of `before` that `after` removed, or zero when nothing improved.
+fn relative_improvement(before: f32, after: f32) -> f32 {
+    if before <= 0.0 {
+        return 0.0;
+    }
+    ((before - after) / before).clamp(0.0, 1.0)
+}
+
+// Box-Muller transform of two uniform draws.
+fn standard_normal(rng: &mut impl Rng) -> f32 {
+    let radius = (-2.0 * rng.random::<f32>().max(f32::MIN_POSITIVE).ln()).sqrt();
+    let angle = std::f32::consts::TAU * rng.random::<f32>();
+    radius * angle.cos()
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use super::{Controller, CrossoverOperator, MutationOperator, ParameterControl};
+
+    #[test]
+    fn test_one_fifth_rule_follows_mutation_success() {
+        let mut controller = Controller::new(Some(ParameterControl::OneFifthRule), false, 0.1);
+        for _ in 0..5 {
+            controller.record_mutation(MutationOperator::Swap, 10, 8);
+        }
+        let state = controller.finish_epoch();
+        assert_eq!(state.mutation_success_ratio, 1.0);
+        assert!(controller.mutation_rate() > 0.1);
+
+        let raised = controller.mutation_rate();
+        for _ in 0..10 {
+            controller.record_mutation(MutationOperator::Swap, 10, 12);
+        }
+        controller.record_mutation(MutationOperator::Swap, 10, 9);
+        controller.finish_epoch();
+        assert!(controller.mutation_rate() < raised);
+    }
+
+    #[test]
+    fn test_bandit_favors_operators_that_improve_children() {
+        let mut controller = Controller::new(Some(ParameterControl::OperatorBandit), false, 0.1);
+        let initial = controller.initial_state();
+        assert!((initial.crossover_probabilities.iter().sum::<f32>() - 1.0).abs() < 1e-6);
+
+        for _ in 0..10 {
+            for _ in 0..4 {
+                controller.record_crossover(CrossoverOperator::Order, 10.0, 5);
+                controller.record_crossover(CrossoverOperator::PartiallyMapped, 10.0, 12);
+                controller.record_crossover(CrossoverOperator::Cycle, 10.0, 12);
+                controller.record_mutation(MutationOperator::Insertion, 10, 4);
+                controller.record_mutation(MutationOperator::Swap, 10, 10);
+                controller.record_mutation(MutationOperator::Inversion, 10, 11);
+            }
+            controller.finish_epoch();
+        }
+
+        let state = controller.finish_epoch();
+        assert!(state.crossover_probabilities[1] > state.crossover_probabilities[0]);
+        assert!(state.crossover_probabilities[1] > state.crossover_probabilities[2]);
+        assert!(state.mutation_probabilities[2] > state.mutation_probabilities[0]);
+        assert!(
+            state
+                .mutation_probabilities
+                .iter()
+                .all(|&probability| probability >= super::MIN_OPERATOR_PROBABILITY)
+        );
+    }
+
+    #[test]
+    fn test_symmetric_bandit_keeps_default_operators() {
+        let controller = Controller::new(Some(ParameterControl::OperatorBandit), true, 0.1);
+        let mut rng = StdRng::seed_from_u64(3);
+        assert_eq!(
+            controller.select_crossover(&mut rng),
+            CrossoverOperator::PartiallyMapped
+        );
+        assert_eq!(controller.select_mutation(&mut rng), MutationOperator::Swap);
+        assert_eq!(
+            controller.initial_state().crossover_probabilities,
+            [1.0, 0.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn test_self_adaptive_rates_stay_within_bounds() {
+        let controller = Controller::new(Some(ParameterControl::SelfAdaptive), false, 0.1);
+        let mut rng = StdRng::seed_from_u64(9);
+        for _ in 0..200 {
+            let rate = controller
+                .self_adapted_mutation_rate(Some(0.59), &mut rng)
+                .unwrap();
+            assert!(
+                (super::MIN_CONTROLLED_MUTATION_RATE..=super::MAX_ADAPTIVE_MUTATION_RATE)
+                    .contains(&rate)
+            );
+        }
+
+        let fixed = Controller::new(Some(ParameterControl::Stagnation), false, 0.1);
+        assert_eq!(fixed.inherited_mutation_rate(Some(0.3), None), None);
+        assert_eq!(fixed.initial_mutation_rate(&mut rng), None);
+        assert_eq!(
+            fixed.self_adapted_mutation_rate(Some(0.3), &mut rng),
+            Some(0.3)
+        );
+        assert_eq!(fixed.chromosome_mutation_rate(Some(0.3), 0.1), 0.1);
+    }
+}
@@ -0,0 +1,203 @@
+use super::{
+    DiversityMeasure, EpochMetrics, RestartKind, SelectionStrategy, control::ParameterControl,
+    epoch_progress_log_interval,
+};
+
+// Receives every lifecycle event of a run. Any `FnMut(&GaEvent)` closure is an observer,
+// and `GeneticAlgorithm::add_observer` attaches as many as needed.
+pub trait GaObserver: Send {
+    fn on_event(&mut self, event: &GaEvent<'_>);
+}
+
+impl<F> GaObserver for F
+where
+    F: FnMut(&GaEvent<'_>) + Send,
+{
+    fn on_event(&mut self, event: &GaEvent<'_>) {
+        self(event);
+    }
+}
+
+// Within an epoch, events arrive in the order listed here: reset and refresh while the
+// epoch runs, then `Improvement`, `EpochFinished`, and `Solved`, `EvaluationBudgetReached`,
+// or `EpochLimitReached` if the run ends. `init` sends `RunStarted` and the `EpochFinished` of epoch 0.
+#[derive(Debug, Clone, Copy)]
+pub enum GaEvent<'a> {
+    RunStarted(RunStart),
+    EpochStarted {
+        epoch: u32,
+    },
+    StagnationReset {
+        epoch: u32,
+        stagnant_epochs: u32,
+        replaced: usize,
+        best_conflicts_sum: u32,
+        elite_ratio: f32,
+        population_size: usize,
+    },
+    DiversityRefresh {
+        epoch: u32,
+        measure: DiversityMeasure,
+        diversity: f32,
+        min_diversity: f32,
+        replaced: usize,
+    },
+    Improvement {
+        metrics: &'a EpochMetrics,
+        previous_best_conflicts_sum: u32,
+    },
+    EpochFinished {
+        metrics: &'a EpochMetrics,
+    },
+    Solved {
+        metrics: &'a EpochMetrics,
+    },
+    EpochLimitReached {
+        metrics: &'a EpochMetrics,
+    },
+    EvaluationBudgetReached {
+        metrics: &'a EpochMetrics,
+    },
+    Cancelled {
+        epoch: u32,
+    },
+}
+
+// The parameters a run starts with, after its initial population is evaluated.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RunStart {
+    pub max_epoch_count: u32,
+    pub population_size: usize,
+    pub best_conflicts_sum: u32,
+    pub mutation_rate: f32,
+    pub elite_ratio: f32,
+    pub offspring_ratio: f32,
+    pub offspring_count: usize,
+    pub selection_strategy: SelectionStrategy,
+    pub tournament_size: usize,
+    pub local_search_rate: f32,
+    pub local_search_attempts: usize,
+    pub stagnation_reset_interval: u32,
+    pub restart_kind: RestartKind,
+    pub adaptive: bool,
+    pub parameter_control: ParameterControl,
+}
+
+// Writes run progress through the `log` crate. Every `GeneticAlgorithm` starts with one
+// attached; `GeneticAlgorithm::clear_observers` removes it.
+#[derive(Debug, Clone, Default)]
+pub struct LogObserver {
+    max_epoch_count: u32,
+}
+
+impl GaObserver for LogObserver {
+    fn on_event(&mut self, event: &GaEvent<'_>) {
+        match *event {
+            GaEvent::RunStarted(start) => {
+                self.max_epoch_count = start.max_epoch_count;
+                if start.best_conflicts_sum == 0 {
+                    return;
+                }
+                log::info!(
+                    "running ga epochs={} population_size={} progress_log_interval={} stagnation_reset_interval={} initial_best_conflicts_sum={} base_mutation_rate={} base_elite_ratio={} offspring_ratio={} offspring_count={} selection_strategy={} tournament_size={} local_search_rate={} local_search_attempts={} restart_kind={} adaptive={} parameter_control={}",
+                    start.max_epoch_count,
+                    start.population_size,
+                    epoch_progress_log_interval(start.max_epoch_count),
+                    start.stagnation_reset_interval,
+                    start.best_conflicts_sum,
+                    start.mutation_rate,
+                    start.elite_ratio,
+                    start.offspring_ratio,
+                    start.offspring_count,
+                    start.selection_strategy,
+                    start.tournament_size,
+                    start.local_search_rate,
+                    start.local_search_attempts,
+                    start.restart_kind,
+                    start.adaptive,
+                    start.parameter_control,
+                );
+            }
+            GaEvent::EpochStarted { .. } => {}
+            GaEvent::StagnationReset {
+                epoch,
+                stagnant_epochs,
+                replaced,
+                best_conflicts_sum,
+                elite_ratio,
+                population_size,
+            } => log::info!(
+                "ga stagnation reset epoch={epoch} stagnant_epochs={stagnant_epochs} replaced={replaced} best_conflicts_sum={best_conflicts_sum} elite_ratio={elite_ratio:.4} population_size={population_size}"
+            ),
+            GaEvent::DiversityRefresh {
+                epoch,
+                measure,
+                diversity,
+                min_diversity,
+                replaced,
+            } => log::info!(
+                "ga diversity refresh epoch={epoch} measure={measure} diversity={diversity:.4} min_diversity={min_diversity:.4} replaced={replaced}"
+            ),
+            GaEvent::Improvement { metrics, .. } => {
+                if metrics.best_conflicts_sum() > 0 {
+                    log::info!(
+                        "ga improvement epoch={} best_conflicts_sum={} population_size={} mutation_rate={:.4} elite_ratio={:.4} local_search_improvements={}",
+                        metrics.epoch(),
+                        metrics.best_conflicts_sum(),
+                        metrics.population_size(),
+                        metrics.mutation_rate(),
+                        metrics.elite_ratio(),
+                        metrics.local_search_improvements(),
+                    );
+                }
+            }
+            GaEvent::EpochFinished { metrics } => {
+                let epoch = metrics.epoch();
+                let is_stagnant = epoch > 0
+                    && metrics.best_conflicts_sum() > 0
+                    && metrics.stagnation_epochs() > 0;
+                let is_periodic_log =
+                    epoch % epoch_progress_log_interval(self.max_epoch_count) == 0;
+                if is_stagnant && (is_periodic_log || epoch == self.max_epoch_count) {
+                    log::info!(
+                        "ga progress epoch={epoch} best_conflicts_sum={} population_size={} stagnant_epochs={} mutation_rate={:.4} elite_ratio={:.4} local_search_improvements={} diversity_replacements={}",
+                        metrics.best_conflicts_sum(),
+                        metrics.population_size(),
+                        metrics.stagnation_epochs(),
+                        metrics.mutation_rate(),
+                        metrics.elite_ratio(),
+                        metrics.local_search_improvements(),
+                        metrics.diversity_replacements(),
+                    );
+                }
+            }
+            GaEvent::Solved { metrics } => {
+                if metrics.epoch() == 0 {
+                    log::info!("ga solved in initial population");
+                } else {
+                    log::info!(
+                        "ga solved epoch={} population_size={} mutation_rate={:.4} elite_ratio={:.4} local_search_improvements={}",
+                        metrics.epoch(),
+                        metrics.population_size(),
+                        metrics.mutation_rate(),
+                        metrics.elite_ratio(),
+                        metrics.local_search_improvements(),
+                    );
+                }
+            }
+            GaEvent::EpochLimitReached { metrics } => log::warn!(
+                "no solution found best_conflicts_sum={} epochs={} population_size={}",
+                metrics.best_conflicts_sum(),
+                metrics.epoch(),
+                metrics.population_size(),
+            ),
+            GaEvent::EvaluationBudgetReached { metrics } => log::warn!(
+                "evaluation budget reached best_conflicts_sum={} epochs={} evaluations={}",
+                metrics.best_conflicts_sum(),
+                metrics.epoch(),
+                metrics.total_evaluations(),
+            ),
+            GaEvent::Cancelled { epoch } => log::info!("ga cancelled epoch={epoch}"),
+        }
+    }
+}
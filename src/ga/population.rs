@@ -0,0 +1,203 @@
+use std::collections::BTreeMap;
+
+use super::chromosome::Chromosome;
+
+pub const MAX_HEATMAP_RESOLUTION: usize = 64;
+pub const PROJECTION_SAMPLE_SIZE: usize = 200;
+
+// Where the population puts its queens, how its conflict sums are spread, and a 2D
+// layout of a sample of chromosomes that keeps their pairwise distances.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PopulationSummary {
+    board_size: usize,
+    population_size: usize,
+    heatmap_resolution: usize,
+    queen_counts: Vec<u32>,
+    conflict_histogram: Vec<(u32, u32)>,
+    projection: Vec<ProjectedChromosome>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProjectedChromosome {
+    pub x: f32,
+    pub y: f32,
+    pub conflicts_sum: u32,
+}
+
+impl PopulationSummary {
+    pub fn board_size(&self) -> usize {
+        self.board_size
+    }
+
+    pub fn population_size(&self) -> usize {
+        self.population_size
+    }
+
+    // Side of the square heatmap. Boards larger than `MAX_HEATMAP_RESOLUTION` are
+    // bucketed so each heatmap cell covers a block of board cells.
+    pub fn heatmap_resolution(&self) -> usize {
+        self.heatmap_resolution
+    }
+
+    // Queens placed in the heatmap cell at `(column, row)` across the whole population.
+    pub fn queen_count(&self, column: usize, row: usize) -> u32 {
+        self.queen_counts
+            .get(column * self.heatmap_resolution + row)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn max_queen_count(&self) -> u32 {
+        self.queen_counts.iter().copied().max().unwrap_or_default()
+    }
+
+    // `(conflicts_sum, chromosome count)` pairs in ascending conflict order.
+    pub fn conflict_histogram(&self) -> &[(u32, u32)] {
+        &self.conflict_histogram
+    }
+
+    pub fn projection(&self) -> &[ProjectedChromosome] {
+        &self.projection
+    }
+}
+
+pub(crate) fn summarize(population: &[Chromosome]) -> PopulationSummary {
+    let board_size = population
+        .first()
+        .map_or(0, |chromosome| chromosome.get_positions().len());
+    let heatmap_resolution = board_size.min(MAX_HEATMAP_RESOLUTION);
+    let mut queen_counts = vec![0; heatmap_resolution * heatmap_resolution];
+    let mut conflict_counts = BTreeMap::new();
+
+    for chromosome in population {
+        for (column, &row) in chromosome.get_positions().iter().enumerate() {
+            let bucket_column = column * heatmap_resolution / board_size;
+            let bucket_row = usize::from(row) * heatmap_resolution / board_size;
+            queen_counts[bucket_column * heatmap_resolution + bucket_row] += 1;
+        }
+        *conflict_counts
+            .entry(chromosome.get_conflicts_sum())
+            .or_insert(0) += 1;
+    }
+
+    PopulationSummary {
+        board_size,
+        population_size: population.len(),
+        heatmap_resolution,
+        queen_counts,
+        conflict_histogram: conflict_counts.into_iter().collect(),
+        projection: project_sample(population),
+    }
+}
+
+// FastMap over an evenly strided sample: each axis is spanned by two far-apart pivot
+// chromosomes, and every chromosome is placed by its Hamming distance to both pivots.
+fn project_sample(population: &[Chromosome]) -> Vec<ProjectedChromosome> {
+    let stride = population.len().div_ceil(PROJECTION_SAMPLE_SIZE).max(1);
+    let sample = population.iter().step_by(stride).collect::<Vec<_>>();
+    if sample.is_empty() {
+        return Vec::new();
+    }
+
+    let base_distances = (0..sample.len())
+        .map(|row| {
+            (0..sample.len())
+                .map(|column| {
+                    hamming_distance(sample[row].get_positions(), sample[column].get_positions())
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let mut coordinates = vec![[0.0_f32; 2]; sample.len()];
+
+    for axis in 0..2 {
+        let distance = |one: usize, two: usize| {
+            let projected = (0..axis)
+                .map(|previous| (coordinates[one][previous] - coordinates[two][previous]).powi(2))
+                .sum::<f32>();
+            (base_distances[one][two].powi(2) - projected).max(0.0)
+        };
+        let farthest_from = |pivot: usize| {
+            (0..sample.len())
+                .max_by(|&one, &two| distance(pivot, one).total_cmp(&distance(pivot, two)))
+                .unwrap_or(pivot)
+        };
+        let pivot_two = farthest_from(0);
+        let pivot_one = farthest_from(pivot_two);
+        let pivot_distance = distance(pivot_one, pivot_two);
+        if pivot_distance <= f32::EPSILON {
+            break;
+        }
+
+        let axis_values = (0..sample.len())
+            .map(|index| {
+                (distance(pivot_one, index) + pivot_distance - distance(pivot_two, index))
+                    / (2.0 * pivot_distance.sqrt())
+            })
+            .collect::<Vec<_>>();
+        for (coordinate, value) in coordinates.iter_mut().zip(axis_values) {
+            coordinate[axis] = value;
+        }
+    }
+
+    sample
+        .iter()
+        .zip(coordinates)
+        .map(|(chromosome, [x, y])| ProjectedChromosome {
+            x,
+            y,
+            conflicts_sum: chromosome.get_conflicts_sum(),
+        })
+        .collect()
+}
+
+fn hamming_distance(one: &[u16], two: &[u16]) -> f32 {
+    one.iter()
+        .zip(two)
+        .filter(|(left, right)| left != right)
+        .count() as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MAX_HEATMAP_RESOLUTION, summarize};
+    use crate::ga::chromosome::Chromosome;
+
+    #[test]
+    fn test_summary_counts_queens_and_conflicts() {
+        let population = vec![
+            Chromosome::new(vec![0, 1, 2, 3]),
+            Chromosome::new(vec![1, 3, 0, 2]),
+            Chromosome::new(vec![1, 3, 0, 2]),
+        ];
+
+        let summary = summarize(&population);
+
+        assert_eq!(summary.board_size(), 4);
+        assert_eq!(summary.population_size(), 3);
+        assert_eq!(summary.heatmap_resolution(), 4);
+        assert_eq!(summary.queen_count(0, 0), 1);
+        assert_eq!(summary.queen_count(0, 1), 2);
+        assert_eq!(summary.max_queen_count(), 2);
+        assert_eq!(summary.conflict_histogram(), [(0, 2), (6, 1)]);
+
+        let projection = summary.projection();
+        assert_eq!(projection.len(), 3);
+        assert_eq!(projection[1].x, projection[2].x);
+        assert_eq!(projection[1].y, projection[2].y);
+        assert!((projection[0].x - projection[1].x).abs() > 1.0);
+    }
+
+    #[test]
+    fn test_summary_buckets_large_boards() {
+        let size = MAX_HEATMAP_RESOLUTION as u16 * 2;
+        let population = vec![Chromosome::new((0..size).collect())];
+
+        let summary = summarize(&population);
+
+        assert_eq!(summary.heatmap_resolution(), MAX_HEATMAP_RESOLUTION);
+        assert_eq!(summary.queen_count(0, 0), 2);
+        assert_eq!(summary.queen_count(0, 1), 0);
+        assert!(summary.projection().len() <= super::PROJECTION_SAMPLE_SIZE);
+    }
+}
@@ -0,0 +1,628 @@
+use std::{
+    error::Error,
+    fmt,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    path::Path,
+};
+
+use serde_json::{Map, Value, json};
+
+use super::{
+    EpochMetrics, EpochSnapshot, EvaluationCounts, GaConfig, PhaseTimings, RunMetrics,
+    control::ControlState,
+};
+
+pub const RUN_LOG_FORMAT: &str = "n_queens_run_log";
+pub const RUN_LOG_VERSION: u64 = 1;
+
+#[derive(Debug)]
+pub enum RunLogError {
+    Io(io::Error),
+    InvalidJson { line: usize },
+    InvalidRecord { line: usize, field: &'static str },
+    ColumnCount { line: usize },
+    UnknownEvent { line: usize, event: String },
+    UnsupportedVersion(u64),
+    NoEpochs,
+}
+
+impl fmt::Display for RunLogError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(formatter, "failed to read run log: {error}"),
+            Self::InvalidJson { line } => write!(formatter, "line {line} is not valid JSON"),
+            Self::InvalidRecord { line, field } => {
+                write!(formatter, "line {line} has a missing or invalid `{field}`")
+            }
+            Self::ColumnCount { line } => {
+                write!(
+                    formatter,
+                    "line {line} does not match the CSV header columns"
+                )
+            }
+            Self::UnknownEvent { line, event } => {
+                write!(formatter, "line {line} has unknown event `{event}`")
+            }
+            Self::UnsupportedVersion(version) => {
+                write!(formatter, "unsupported run log version {version}")
+            }
+            Self::NoEpochs => formatter.write_str("run log contains no epochs"),
+        }
+    }
+}
+
+impl Error for RunLogError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for RunLogError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RunLogHeader {
+    pub seed: u64,
+    pub board_size: u16,
+    pub target_population: usize,
+    pub max_epochs: u32,
+}
+
+impl RunLogHeader {
+    pub fn from_config(config: &GaConfig) -> Self {
+        Self {
+            seed: config.seed,
+            board_size: config.size,
+            target_population: config.initial_population,
+            max_epochs: config.max_epoch_count,
+        }
+    }
+}
+
+// A recorded run: one JSON object per line, a `start` header, an `epoch` record per
+// `EpochSnapshot`, and a closing `finish` record. Streams written by
+// `--progress-format ndjson` read back the same way, without the header.
+#[derive(Debug, Clone, Default)]
+pub struct RunLog {
+    header: Option<RunLogHeader>,
+    seed: Option<u64>,
+    snapshots: Vec<EpochSnapshot>,
+    solved_epoch: Option<u32>,
+    total_elapsed_ms: Option<u128>,
+}
+
+impl RunLog {
+    // Reads a run log, an ndjson progress stream, or a `--metrics-csv` file (by its
+    // `.csv` extension).
+    pub fn load(path: &Path) -> Result<Self, RunLogError> {
+        let reader = BufReader::new(File::open(path)?);
+        let is_csv = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("csv"));
+        if is_csv {
+            Self::read_metrics_csv(reader)
+        } else {
+            Self::read(reader)
+        }
+    }
+
+    pub fn read(reader: impl BufRead) -> Result<Self, RunLogError> {
+        let mut run_log = Self::default();
+        for (index, line) in reader.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let record = serde_json::from_str::<Value>(&line)
+                .ok()
+                .and_then(|value| match value {
+                    Value::Object(record) => Some(record),
+                    _ => None,
+                })
+                .ok_or(RunLogError::InvalidJson { line: line_number })?;
+            run_log.read_record(&record, line_number)?;
+        }
+
+        if run_log.snapshots.is_empty() {
+            return Err(RunLogError::NoEpochs);
+        }
+        Ok(run_log)
+    }
+
+    // The metrics CSV has no boards, so its snapshots carry empty best positions and only
+    // the charted metrics. The solved epoch is inferred from a final zero-conflict row.
+    pub fn read_metrics_csv(reader: impl BufRead) -> Result<Self, RunLogError> {
+        let mut lines = reader.lines().enumerate();
+        let columns = match lines.next() {
+            Some((_, header)) => header?
+                .split(',')
+                .map(|column| column.trim().to_owned())
+                .collect::<Vec<_>>(),
+            None => return Err(RunLogError::NoEpochs),
+        };
+
+        let mut run_log = Self::default();
+        for (index, line) in lines {
+            let line_number = index + 1;
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let values = line.split(',').collect::<Vec<_>>();
+            if values.len() != columns.len() {
+                return Err(RunLogError::ColumnCount { line: line_number });
+            }
+            let mut record = Map::new();
+            for (column, value) in columns.iter().zip(values) {
+                let field = match column.as_str() {
+                    // The run's base rates; the adaptive per-epoch rates are charted instead.
+                    "mutation_rate" | "elite_ratio" => continue,
+                    "epoch_mutation_rate" => "mutation_rate",
+                    "epoch_elite_ratio" => "elite_ratio",
+                    column => column,
+                };
+                record.insert(field.to_owned(), csv_value(value.trim()));
+            }
+            record.insert("best_positions".to_owned(), json!([]));
+            record.insert("best_conflicts".to_owned(), json!([]));
+
+            let fields = RecordFields {
+                record: &record,
+                line: line_number,
+            };
+            if run_log.header.is_none()
+                && let Ok(seed) = fields.u64("seed")
+            {
+                run_log.seed = Some(seed);
+                run_log.header = Some(RunLogHeader {
+                    seed,
+                    board_size: fields.number("board_size")?,
+                    target_population: fields.number("target_population")?,
+                    max_epochs: fields.number("max_epochs")?,
+                });
+            }
+            run_log.snapshots.push(fields.snapshot()?);
+        }
+
+        let Some(last) = run_log.snapshots.last() else {
+            return Err(RunLogError::NoEpochs);
+        };
+        run_log.solved_epoch = (last.best_conflicts_sum() == 0).then(|| last.metrics().epoch());
+        run_log.total_elapsed_ms = Some(last.metrics().elapsed_ms());
+        Ok(run_log)
+    }
+
+    fn read_record(&mut self, record: &Map<String, Value>, line: usize) -> Result<(), RunLogError> {
+        let fields = RecordFields { record, line };
+        match fields.str("event")? {
+            "start" => {
+                let version = fields.u64("version")?;
+                if version != RUN_LOG_VERSION {
+                    return Err(RunLogError::UnsupportedVersion(version));
+                }
+                let seed = fields.u64("seed")?;
+                self.seed = Some(seed);
+                self.header = Some(RunLogHeader {
+                    seed,
+                    board_size: fields.number("board_size")?,
+                    target_population: fields.number("target_population")?,
+                    max_epochs: fields.number("max_epochs")?,
+                });
+            }
+            "epoch" => {
+                // `--progress-format ndjson` streams carry the seed on every epoch instead.
+                if self.seed.is_none() {
+                    self.seed = fields.record.get("seed").and_then(Value::as_u64);
+                }
+                self.snapshots.push(fields.snapshot()?);
+            }
+            "finish" | "summary" => {
+                self.solved_epoch = fields.optional_number("solved_epoch")?;
+                self.total_elapsed_ms = fields.optional_number("elapsed_ms")?;
+            }
+            event => {
+                return Err(RunLogError::UnknownEvent {
+                    line,
+                    event: event.to_owned(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    pub fn header(&self) -> Option<&RunLogHeader> {
+        self.header.as_ref()
+    }
+
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    pub fn snapshots(&self) -> &[EpochSnapshot] {
+        &self.snapshots
+    }
+
+    pub fn solved_epoch(&self) -> Option<u32> {
+        self.solved_epoch.or_else(|| {
+            self.snapshots
+                .iter()
+                .find(|snapshot| snapshot.best_conflicts_sum() == 0)
+                .map(|snapshot| snapshot.metrics().epoch())
+        })
+    }
+
+    pub fn total_elapsed_ms(&self) -> Option<u128> {
+        self.total_elapsed_ms.or_else(|| {
+            self.snapshots
+                .last()
+                .map(|snapshot| snapshot.metrics().elapsed_ms())
+        })
+    }
+}
+
+pub struct RunLogWriter<W: Write> {
+    writer: W,
+}
+
+impl RunLogWriter<BufWriter<File>> {
+    pub fn create(path: &Path, header: &RunLogHeader) -> io::Result<Self> {
+        if let Some(parent) = path.parent().filter(|path| !path.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+
+        Self::new(BufWriter::new(File::create(path)?), header)
+    }
+}
+
+impl<W: Write> RunLogWriter<W> {
+    pub fn new(mut writer: W, header: &RunLogHeader) -> io::Result<Self> {
+        let record = json!({
+            "event": "start",
+            "format": RUN_LOG_FORMAT,
+            "version": RUN_LOG_VERSION,
+            "seed": header.seed,
+            "board_size": header.board_size,
+            "target_population": header.target_population,
+            "max_epochs": header.max_epochs,
+        });
+        writeln!(writer, "{record}")?;
+        Ok(Self { writer })
+    }
+
+    pub fn record(&mut self, snapshot: &EpochSnapshot) -> io::Result<()> {
+        writeln!(self.writer, "{}", snapshot_json(snapshot))
+    }
+
+    pub fn finish(mut self, run_metrics: &RunMetrics) -> io::Result<W> {
+        let record = json!({
+            "event": "finish",
+            "solved_epoch": run_metrics.solved_epoch(),
+            "elapsed_ms": run_metrics.total_elapsed_ms(),
+        });
+        writeln!(self.writer, "{record}")?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+// The `epoch` record shared by run logs and `--progress-format ndjson`.
+pub fn snapshot_json(snapshot: &EpochSnapshot) -> Value {
+    let metrics = snapshot.metrics();
+    let timings = metrics.phase_timings();
+    let control = metrics.control();
+    json!({
+        "event": "epoch",
+        "epoch": metrics.epoch(),
+        "elapsed_ms": metrics.elapsed_ms(),
+        "best_conflicts_sum": snapshot.best_conflicts_sum(),
+        "average_conflicts_sum": json_f32(metrics.average_conflicts_sum()),
+        "population_size": metrics.population_size(),
+        "unique_chromosomes": metrics.unique_chromosomes(),
+        "diversity_ratio": json_f32(metrics.diversity_ratio()),
+        "unique_symmetry_classes": metrics.unique_symmetry_classes(),
+        "gene_entropy": metrics.gene_entropy().map(json_f32),
+        "mean_pairwise_distance": metrics.mean_pairwise_distance().map(json_f32),
+        "mean_best_distance": metrics.mean_best_distance().map(json_f32),
+        "mutation_rate": json_f32(metrics.mutation_rate()),
+        "elite_ratio": json_f32(metrics.elite_ratio()),
+        "offspring_count": metrics.offspring_count(),
+        "local_search_improvements": metrics.local_search_improvements(),
+        "stagnation_epochs": metrics.stagnation_epochs(),
+        "stagnation_reset": metrics.stagnation_reset(),
+        "diversity_replacements": metrics.diversity_replacements(),
+        "mating_us": timings.mating_us,
+        "mutation_us": timings.mutation_us,
+        "local_search_us": timings.local_search_us,
+        "selection_us": timings.selection_us,
+        "fitness_us": timings.fitness_us,
+        "diversity_us": timings.diversity_us,
+        "full_evaluations": metrics.evaluations().full,
+        "swap_evaluations": metrics.evaluations().swap_delta,
+        "evaluations": metrics.total_evaluations(),
+        "mutation_success_ratio": json_f32(control.mutation_success_ratio),
+        "pmx_probability": json_f32(control.crossover_probabilities[0]),
+        "order_probability": json_f32(control.crossover_probabilities[1]),
+        "cycle_probability": json_f32(control.crossover_probabilities[2]),
+        "swap_probability": json_f32(control.mutation_probabilities[0]),
+        "inversion_probability": json_f32(control.mutation_probabilities[1]),
+        "insertion_probability": json_f32(control.mutation_probabilities[2]),
+        "best_positions": snapshot.best_positions(),
+        "best_conflicts": snapshot.best_conflicts(),
+    })
+}
+
+// Shortest decimal that reads back as the same `f32`, so replayed metrics and reloaded
+// configuration values match exactly.
+pub fn json_f32(value: f32) -> f64 {
+    value.to_string().parse().unwrap_or(f64::NAN)
+}
+
+// Empty cells, such as `unique_symmetry_classes` when classes are not counted, read
+// back as missing.
+fn csv_value(value: &str) -> Value {
+    if value.is_empty() {
+        Value::Null
+    } else if let Ok(number) = value.parse::<u64>() {
+        Value::from(number)
+    } else if let Ok(number) = value.parse::<f64>() {
+        Value::from(number)
+    } else {
+        Value::from(value)
+    }
+}
+
+struct RecordFields<'a> {
+    record: &'a Map<String, Value>,
+    line: usize,
+}
+
+impl RecordFields<'_> {
+    fn invalid(&self, field: &'static str) -> RunLogError {
+        RunLogError::InvalidRecord {
+            line: self.line,
+            field,
+        }
+    }
+
+    fn str(&self, field: &'static str) -> Result<&str, RunLogError> {
+        self.record
+            .get(field)
+            .and_then(Value::as_str)
+            .ok_or_else(|| self.invalid(field))
+    }
+
+    fn u64(&self, field: &'static str) -> Result<u64, RunLogError> {
+        self.record
+            .get(field)
+            .and_then(Value::as_u64)
+            .ok_or_else(|| self.invalid(field))
+    }
+
+    fn number<T: TryFrom<u64>>(&self, field: &'static str) -> Result<T, RunLogError> {
+        T::try_from(self.u64(field)?).map_err(|_| self.invalid(field))
+    }
+
+    fn optional_number<T: TryFrom<u64>>(
+        &self,
+        field: &'static str,
+    ) -> Result<Option<T>, RunLogError> {
+        match self.record.get(field) {
+            None | Some(Value::Null) => Ok(None),
+            Some(_) => self.number(field).map(Some),
+        }
+    }
+
+    // Absent in logs written before the field existed and in metrics CSV files.
+    fn optional_bool(&self, field: &'static str) -> Result<bool, RunLogError> {
+        match self.record.get(field) {
+            None | Some(Value::Null) => Ok(false),
+            Some(value) => value.as_bool().ok_or_else(|| self.invalid(field)),
+        }
+    }
+
+    fn f32(&self, field: &'static str) -> Result<f32, RunLogError> {
+        self.record
+            .get(field)
+            .and_then(Value::as_f64)
+            .map(|value| value as f32)
+            .ok_or_else(|| self.invalid(field))
+    }
+
+    // Missing for epochs that skipped the measure and in logs written before it existed.
+    fn optional_f32(&self, field: &'static str) -> Result<Option<f32>, RunLogError> {
+        match self.record.get(field) {
+            None | Some(Value::Null) => Ok(None),
+            Some(_) => self.f32(field).map(Some),
+        }
+    }
+
+    fn numbers<T: TryFrom<u64>>(&self, field: &'static str) -> Result<Vec<T>, RunLogError> {
+        self.record
+            .get(field)
+            .and_then(Value::as_array)
+            .ok_or_else(|| self.invalid(field))?
+            .iter()
+            .map(|value| {
+                value
+                    .as_u64()
+                    .and_then(|value| T::try_from(value).ok())
+                    .ok_or_else(|| self.invalid(field))
+            })
+            .collect()
+    }
+
+    // Logs written before controller state was recorded read back as PMX and swap only.
+    fn control_state(&self) -> Result<ControlState, RunLogError> {
+        if !self.record.contains_key("mutation_success_ratio") {
+            return Ok(ControlState::default());
+        }
+        Ok(ControlState {
+            mutation_success_ratio: self.f32("mutation_success_ratio")?,
+            crossover_probabilities: [
+                self.f32("pmx_probability")?,
+                self.f32("order_probability")?,
+                self.f32("cycle_probability")?,
+            ],
+            mutation_probabilities: [
+                self.f32("swap_probability")?,
+                self.f32("inversion_probability")?,
+                self.f32("insertion_probability")?,
+            ],
+        })
+    }
+
+    fn snapshot(&self) -> Result<EpochSnapshot, RunLogError> {
+        let best_conflicts_sum = self.number("best_conflicts_sum")?;
+        let best_positions = self.numbers("best_positions")?;
+        let best_conflicts = self.numbers("best_conflicts")?;
+        if best_conflicts.len() != best_positions.len() {
+            return Err(self.invalid("best_conflicts"));
+        }
+
+        Ok(EpochSnapshot {
+            metrics: EpochMetrics {
+                epoch: self.number("epoch")?,
+                best_conflicts_sum,
+                population_size: self.number("population_size")?,
+                elapsed_ms: u128::from(self.u64("elapsed_ms")?),
+                average_conflicts_sum: self.f32("average_conflicts_sum")?,
+                unique_chromosomes: self.number("unique_chromosomes")?,
+                unique_symmetry_classes: self.optional_number("unique_symmetry_classes")?,
+                gene_entropy: self.optional_f32("gene_entropy")?,
+                mean_pairwise_distance: self.optional_f32("mean_pairwise_distance")?,
+                mean_best_distance: self.optional_f32("mean_best_distance")?,
+                mutation_rate: self.f32("mutation_rate")?,
+                elite_ratio: self.f32("elite_ratio")?,
+                offspring_count: self.number("offspring_count")?,
+                local_search_improvements: self.number("local_search_improvements")?,
+                stagnation_epochs: self.number("stagnation_epochs")?,
+                stagnation_reset: self.optional_bool("stagnation_reset")?,
+                diversity_replacements: self.number("diversity_replacements")?,
+                // Logs written before phase timing was recorded read back as zero.
+                phase_timings: PhaseTimings {
+                    mating_us: self.optional_number("mating_us")?.unwrap_or_default(),
+                    mutation_us: self.optional_number("mutation_us")?.unwrap_or_default(),
+                    local_search_us: self.optional_number("local_search_us")?.unwrap_or_default(),
+                    selection_us: self.optional_number("selection_us")?.unwrap_or_default(),
+                    fitness_us: self.optional_number("fitness_us")?.unwrap_or_default(),
+                    diversity_us: self.optional_number("diversity_us")?.unwrap_or_default(),
+                },
+                evaluations: EvaluationCounts {
+                    full: self
+                        .optional_number("full_evaluations")?
+                        .unwrap_or_default(),
+                    swap_delta: self
+                        .optional_number("swap_evaluations")?
+                        .unwrap_or_default(),
+                },
+                total_evaluations: self.optional_number("evaluations")?.unwrap_or_default(),
+                control: self.control_state()?,
+            },
+            best_positions,
+            best_conflicts,
+            best_conflicts_sum,
+            population_summary: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ga::{GaConfig, build_genetic_algorithm};
+
+    #[test]
+    fn test_run_log_round_trips_every_snapshot() {
+        let config = GaConfig::new(8, 64, 40, 5);
+        let mut algorithm = build_genetic_algorithm(config).unwrap();
+        let mut writer =
+            RunLogWriter::new(Vec::new(), &RunLogHeader::from_config(&config)).unwrap();
+        let mut recorded = Vec::new();
+        let run_metrics = algorithm.run_algorithm_with_progress(|snapshot| {
+            writer.record(snapshot).unwrap();
+            recorded.push(snapshot.clone());
+            true
+        });
+        let bytes = writer.finish(&run_metrics).unwrap();
+
+        let run_log = RunLog::read(bytes.as_slice()).unwrap();
+        assert_eq!(run_log.header(), Some(&RunLogHeader::from_config(&config)));
+        assert_eq!(run_log.snapshots().len(), recorded.len());
+        assert_eq!(run_log.solved_epoch(), run_metrics.solved_epoch());
+        for (replayed, recorded) in run_log.snapshots().iter().zip(&recorded) {
+            assert_eq!(snapshot_json(replayed), snapshot_json(recorded));
+            assert_eq!(
+                replayed.metrics().average_conflicts_sum(),
+                recorded.metrics().average_conflicts_sum()
+            );
+        }
+    }
+
+    #[test]
+    fn test_metrics_csv_reads_as_run_log_without_boards() {
+        let csv = "seed,board_size,target_population,max_epochs,mutation_rate,elite_ratio,offspring_ratio,min_diversity_ratio,selection_strategy,tournament_size,local_search_rate,local_search_attempts,epoch,best_conflicts_sum,population_size,elapsed_ms,average_conflicts_sum,unique_chromosomes,diversity_ratio,epoch_mutation_rate,epoch_elite_ratio,offspring_count,local_search_improvements,stagnation_epochs,diversity_replacements
+7,8,64,40,0.08,0.1,0.1,0.1,roulette,3,0,8,0,3,64,1,7.25,60,0.9375,0.08,0.1,6,0,0,0
+7,8,64,40,0.08,0.1,0.1,0.1,roulette,3,0,8,1,0,64,2,5.5,58,0.90625,0.12,0.05,6,0,0,0
+";
+
+        let run_log = RunLog::read_metrics_csv(csv.as_bytes()).unwrap();
+
+        assert_eq!(
+            run_log.header(),
+            Some(&RunLogHeader {
+                seed: 7,
+                board_size: 8,
+                target_population: 64,
+                max_epochs: 40,
+            })
+        );
+        assert_eq!(run_log.snapshots().len(), 2);
+        assert_eq!(run_log.solved_epoch(), Some(1));
+        assert_eq!(run_log.total_elapsed_ms(), Some(2));
+        let last = &run_log.snapshots()[1];
+        assert!(last.best_positions().is_empty());
+        assert_eq!(last.metrics().mutation_rate(), 0.12);
+        assert_eq!(last.metrics().elite_ratio(), 0.05);
+        assert_eq!(last.metrics().average_conflicts_sum(), 5.5);
+
+        assert!(matches!(
+            RunLog::read_metrics_csv("epoch,best_conflicts_sum\n1\n".as_bytes()),
+            Err(RunLogError::ColumnCount { line: 2 })
+        ));
+        assert!(matches!(
+            RunLog::read_metrics_csv("size,solve_rate\n8,0.5\n".as_bytes()),
+            Err(RunLogError::InvalidRecord { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn test_run_log_rejects_malformed_records() {
+        assert!(matches!(
+            RunLog::read("".as_bytes()),
+            Err(RunLogError::NoEpochs)
+        ));
+        assert!(matches!(
+            RunLog::read("not json\n".as_bytes()),
+            Err(RunLogError::InvalidJson { line: 1 })
+        ));
+        assert!(matches!(
+            RunLog::read("\n{\"event\": \"epoch\", \"epoch\": 1}\n".as_bytes()),
+            Err(RunLogError::InvalidRecord { line: 2, .. })
+        ));
+        assert!(matches!(
+            RunLog::read("{\"event\": \"restart\"}".as_bytes()),
+            Err(RunLogError::UnknownEvent { line: 1, .. })
+        ));
+    }
+}
@@ -1,17 +1,35 @@
 use std::{
+    fs, io,
+    path::Path,
     sync::{
         Arc,
         atomic::{AtomicBool, Ordering},
-        mpsc::{self, Receiver},
+        mpsc::{self, Receiver, RecvTimeoutError, Sender, TryRecvError},
     },
     thread,
     time::Duration,
 };
 
 use eframe::egui::{self, Align2, Color32, FontId, Pos2, Rect, RichText, Sense, Stroke, Vec2};
-use rand::RngExt;
+use rand::{RngExt, seq::SliceRandom};
+
+use crate::{
+    board,
+    ga::{
+        self, DiversityMeasure, EpochSnapshot, GaConfig, PhaseTimings, RestartKind, RestartPolicy,
+        RunMetrics, SelectionStrategy,
+        chromosome::Chromosome,
+        control::{ControlState, ParameterControl},
+        population::PopulationSummary,
+        run_log::{RunLog, RunLogHeader, RunLogWriter},
+    },
+    render::chart::{self, Chart, ChartSeries},
+    symmetry::SymmetryConstraint,
+};
 
-use crate::ga::{self, EpochSnapshot, GaConfig, RunMetrics, SelectionStrategy};
+mod settings;
+
+use self::settings::{BuiltinPreset, CustomPreset};
 
 pub fn run() -> eframe::Result<()> {
     let native_options = eframe::NativeOptions {
@@ -33,15 +51,23 @@ struct GuiConfig {
     board_size: u16,
     population_size: u32,
     max_epochs: u32,
+    max_evaluations: Option<u64>,
     seed: u64,
     mutation_rate: f32,
     elite_ratio: f32,
     offspring_ratio: f32,
     min_diversity_ratio: f32,
+    diversity_measure: DiversityMeasure,
     selection_strategy: SelectionStrategy,
     tournament_size: u32,
     local_search_rate: f32,
     local_search_attempts: u32,
+    symmetry: SymmetryConstraint,
+    restart_interval: Option<u32>,
+    restart_keep_ratio: f32,
+    restart_kind: RestartKind,
+    adaptive: bool,
+    parameter_control: ParameterControl,
 }
 
 impl Default for GuiConfig {
@@ -50,15 +76,23 @@ impl Default for GuiConfig {
             board_size: 18,
             population_size: 40_000,
             max_epochs: 5_000,
+            max_evaluations: None,
             seed: 42,
             mutation_rate: ga::DEFAULT_MUTATION_RATE,
             elite_ratio: ga::DEFAULT_ELITE_RATIO,
             offspring_ratio: ga::DEFAULT_OFFSPRING_RATIO,
             min_diversity_ratio: ga::DEFAULT_MIN_DIVERSITY_RATIO,
+            diversity_measure: ga::DEFAULT_DIVERSITY_MEASURE,
             selection_strategy: ga::DEFAULT_SELECTION_STRATEGY,
             tournament_size: ga::DEFAULT_TOURNAMENT_SIZE as u32,
             local_search_rate: ga::DEFAULT_LOCAL_SEARCH_RATE,
             local_search_attempts: ga::DEFAULT_LOCAL_SEARCH_ATTEMPTS as u32,
+            symmetry: ga::DEFAULT_SYMMETRY_CONSTRAINT,
+            restart_interval: None,
+            restart_keep_ratio: ga::DEFAULT_RESTART_KEEP_RATIO,
+            restart_kind: ga::DEFAULT_RESTART_KIND,
+            adaptive: true,
+            parameter_control: ga::DEFAULT_PARAMETER_CONTROL,
         }
     }
 }
@@ -68,12 +102,25 @@ impl GuiConfig {
         self.board_size = self.board_size.max(1);
         self.population_size = self.population_size.max(1);
         self.max_epochs = self.max_epochs.max(1);
+        self.max_evaluations = self
+            .max_evaluations
+            .map(|value| value.max(u64::from(self.population_size)));
         self.tournament_size = self.tournament_size.max(1);
         self.mutation_rate = self.mutation_rate.clamp(0.0, 1.0);
         self.elite_ratio = self.elite_ratio.clamp(0.0, 1.0);
         self.offspring_ratio = self.offspring_ratio.clamp(0.0, 1.0);
         self.min_diversity_ratio = self.min_diversity_ratio.clamp(0.0, 1.0);
         self.local_search_rate = self.local_search_rate.clamp(0.0, 1.0);
+        self.restart_interval = self.restart_interval.map(|value| value.max(1));
+        self.restart_keep_ratio = self.restart_keep_ratio.clamp(0.0, 1.0);
+    }
+
+    fn restart_policy(&self) -> RestartPolicy {
+        RestartPolicy::default()
+            .with_interval(self.restart_interval)
+            .with_keep_ratio(self.restart_keep_ratio)
+            .with_kind(self.restart_kind)
+            .with_adaptation(self.adaptive)
     }
 
     fn to_ga_config(&self) -> Result<GaConfig, ga::GaConfigError> {
@@ -83,38 +130,55 @@ impl GuiConfig {
             self.max_epochs,
             self.seed,
         )
+        .with_max_evaluations(self.max_evaluations)
         .with_mutation_rate(self.mutation_rate)
         .with_elite_ratio(self.elite_ratio)
         .with_offspring_ratio(self.offspring_ratio)
         .with_min_diversity_ratio(self.min_diversity_ratio)
+        .with_diversity_measure(self.diversity_measure)
         .with_selection_strategy(self.selection_strategy)
         .with_tournament_size(self.tournament_size as usize)
         .with_local_search_rate(self.local_search_rate)
         .with_local_search_attempts(self.local_search_attempts as usize)
+        .with_symmetry(self.symmetry)
+        .with_restart_policy(self.restart_policy())
+        // The controller choice is kept while adaptation is off, but no controller runs.
+        .with_parameter_control(if self.adaptive {
+            self.parameter_control
+        } else {
+            ga::DEFAULT_PARAMETER_CONTROL
+        })
         .validated()
     }
 
-    fn use_fast_demo_values(&mut self) {
-        self.board_size = 8;
-        self.population_size = 256;
-        self.max_epochs = 250;
-        self.mutation_rate = 0.12;
-        self.elite_ratio = 0.15;
-        self.offspring_ratio = 0.25;
-        self.selection_strategy = SelectionStrategy::Tournament;
-        self.tournament_size = 3;
-        self.local_search_rate = 0.05;
-        self.local_search_attempts = 8;
+    fn comparison_label(&self, number: usize) -> String {
+        format!(
+            "#{number} {}x{}, pop {}, mut {}, elite {}, seed {}",
+            self.board_size,
+            self.board_size,
+            self.population_size,
+            format_ratio(self.mutation_rate),
+            format_ratio(self.elite_ratio),
+            self.seed
+        )
     }
 }
 
 struct RunningRun {
     receiver: Receiver<WorkerMessage>,
     cancel: Arc<AtomicBool>,
+    commands: Sender<WorkerCommand>,
+    paused: bool,
+}
+
+enum WorkerCommand {
+    Pause,
+    Resume,
+    Step,
 }
 
 enum WorkerMessage {
-    Snapshot(EpochSnapshot),
+    Snapshot(Box<EpochSnapshot>),
     Finished(RunResult),
     Failed(String),
 }
@@ -128,38 +192,482 @@ struct RunResult {
     cancelled: bool,
 }
 
-struct ChartSeries {
-    label: &'static str,
+const DEFAULT_RUN_LOG_PATH: &str = "n_queens_run.ndjson";
+const DEFAULT_CHART_EXPORT_PATH: &str = "n_queens_charts.png";
+const MIN_CHART_EPOCH_SPAN: f32 = 4.0;
+const PAUSED_WORKER_POLL_INTERVAL: Duration = Duration::from_millis(50);
+const DEFAULT_GUI_MAX_EVALUATIONS: u64 = 10_000_000;
+const DEFAULT_CONFIG_FILE_PATH: &str = "n_queens.toml";
+const CONFIG_STORAGE_KEY: &str = "config";
+const CUSTOM_PRESETS_STORAGE_KEY: &str = "custom_presets";
+const HISTOGRAM_MAX_BINS: u32 = 40;
+const COMPARISON_COLORS: [Color32; 6] = [
+    Color32::from_rgb(95, 220, 140),
+    Color32::from_rgb(110, 190, 255),
+    Color32::from_rgb(245, 210, 95),
+    Color32::from_rgb(245, 120, 170),
+    Color32::from_rgb(160, 135, 255),
+    Color32::from_rgb(255, 160, 90),
+];
+
+// A run launched from "Add to comparison", drawn alongside the others in the
+// comparison view.
+struct ComparedRun {
+    label: String,
+    color: Color32,
+    running: Option<RunningRun>,
+    snapshots: Vec<EpochSnapshot>,
+    result: Option<RunResult>,
+    error: Option<String>,
+}
+
+impl ComparedRun {
+    fn drain_worker_messages(&mut self) {
+        let messages = self
+            .running
+            .as_ref()
+            .map(|running| running.receiver.try_iter().collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        for message in messages {
+            match message {
+                WorkerMessage::Snapshot(snapshot) => self.snapshots.push(*snapshot),
+                WorkerMessage::Finished(result) => {
+                    self.result = Some(result);
+                    self.running = None;
+                }
+                WorkerMessage::Failed(error) => {
+                    self.error = Some(error);
+                    self.running = None;
+                }
+            }
+        }
+    }
+
+    fn cancel(&self) {
+        if let Some(running) = &self.running {
+            running.cancel.store(true, Ordering::Relaxed);
+        }
+    }
+
+    fn current_board(&self) -> Option<(&[u16], &[u32], u32)> {
+        if let Some(result) = &self.result {
+            return Some((
+                result.best_positions.as_slice(),
+                result.best_conflicts.as_slice(),
+                result.best_conflicts_sum,
+            ));
+        }
+
+        self.snapshots.last().map(|snapshot| {
+            (
+                snapshot.best_positions(),
+                snapshot.best_conflicts(),
+                snapshot.best_conflicts_sum(),
+            )
+        })
+    }
+
+    fn status_text(&self) -> String {
+        if let Some(error) = &self.error {
+            return format!("Error: {error}");
+        }
+
+        if let Some(result) = &self.result {
+            if result.cancelled {
+                return format!(
+                    "Cancelled with {} best conflicts",
+                    result.best_conflicts_sum
+                );
+            }
+            if let Some(epoch) = result.metrics.solved_epoch() {
+                return format!("Solved at epoch {epoch}");
+            }
+            return format!("Finished with {} best conflicts", result.best_conflicts_sum);
+        }
+
+        self.snapshots.last().map_or_else(
+            || "Starting solver".to_owned(),
+            |snapshot| {
+                format!(
+                    "Running epoch {} with {} best conflicts",
+                    snapshot.metrics().epoch(),
+                    snapshot.best_conflicts_sum()
+                )
+            },
+        )
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum MainView {
+    Run,
+    Population,
+    Editor,
+    Comparison,
+    Sweep,
+}
+
+// A board handed from the editor to the solver.
+struct EditorSeed {
+    positions: Vec<u16>,
+    fixed_queens: Vec<(usize, u16)>,
+}
+
+#[derive(Clone, PartialEq, Eq)]
+struct EditorBoard {
+    positions: Vec<u16>,
+    pinned: Vec<bool>,
+}
+
+struct BoardEditor {
+    board: EditorBoard,
+    undo: Vec<EditorBoard>,
+    redo: Vec<EditorBoard>,
+    dragged_column: Option<usize>,
+    drag_row: Option<u16>,
+    positions_text: String,
+    fix_pinned: bool,
+    message: Option<String>,
+}
+
+impl BoardEditor {
+    fn new(size: u16) -> Self {
+        let positions = (0..size.max(1)).collect::<Vec<_>>();
+        Self {
+            board: EditorBoard {
+                pinned: vec![false; positions.len()],
+                positions,
+            },
+            undo: Vec::new(),
+            redo: Vec::new(),
+            dragged_column: None,
+            drag_row: None,
+            positions_text: String::new(),
+            fix_pinned: true,
+            message: None,
+        }
+    }
+
+    fn size(&self) -> usize {
+        self.board.positions.len()
+    }
+
+    // Applies `change` as one undoable step when it reports that the board changed.
+    fn edit(&mut self, change: impl FnOnce(&mut EditorBoard) -> bool) {
+        let before = self.board.clone();
+        if change(&mut self.board) && self.board != before {
+            self.undo.push(before);
+            self.redo.clear();
+        }
+    }
+
+    fn undo(&mut self) {
+        if let Some(board) = self.undo.pop() {
+            self.redo.push(std::mem::replace(&mut self.board, board));
+        }
+    }
+
+    fn redo(&mut self) {
+        if let Some(board) = self.redo.pop() {
+            self.undo.push(std::mem::replace(&mut self.board, board));
+        }
+    }
+
+    fn load(&mut self, positions: Vec<u16>) {
+        self.edit(|board| {
+            board.pinned = vec![false; positions.len()];
+            board.positions = positions;
+            true
+        });
+    }
+
+    // Moves the queen in `column` to `row` by swapping it with the queen already on that
+    // row, which keeps one queen per row and column.
+    fn move_queen(&mut self, column: usize, row: u16) {
+        let Some(other_column) = self.board.positions.iter().position(|&y| y == row) else {
+            return;
+        };
+        if other_column == column {
+            return;
+        }
+        if self.board.pinned[column] || self.board.pinned[other_column] {
+            self.message = Some("Unpin a queen before moving it".to_owned());
+            return;
+        }
+
+        self.message = None;
+        self.edit(|board| {
+            board.positions.swap(column, other_column);
+            true
+        });
+    }
+
+    fn toggle_pin(&mut self, column: usize) {
+        self.edit(|board| {
+            board.pinned[column] = !board.pinned[column];
+            true
+        });
+    }
+
+    fn shuffle_unpinned(&mut self) {
+        self.edit(|board| {
+            let columns = (0..board.positions.len())
+                .filter(|&column| !board.pinned[column])
+                .collect::<Vec<_>>();
+            let mut rows = columns
+                .iter()
+                .map(|&column| board.positions[column])
+                .collect::<Vec<_>>();
+            rows.shuffle(&mut rand::rng());
+            for (column, row) in columns.into_iter().zip(rows) {
+                board.positions[column] = row;
+            }
+            true
+        });
+    }
+
+    fn fixed_queens(&self) -> Vec<(usize, u16)> {
+        self.board
+            .pinned
+            .iter()
+            .enumerate()
+            .filter(|&(_, &pinned)| pinned)
+            .map(|(column, _)| (column, self.board.positions[column]))
+            .collect()
+    }
+
+    fn position_list(&self) -> String {
+        self.board
+            .positions
+            .iter()
+            .map(u16::to_string)
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+// Rows of a parameter sweep CSV. Each row summarizes many runs, so it is shown as a
+// table rather than as epoch charts.
+struct SweepTable {
+    columns: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl SweepTable {
+    // `None` when the CSV has no `solve_rate` column and should load as a run's metrics.
+    fn load(path: &Path) -> io::Result<Option<Self>> {
+        let text = fs::read_to_string(path)?;
+        let mut lines = text.lines().filter(|line| !line.trim().is_empty());
+        let Some(header) = lines.next() else {
+            return Ok(None);
+        };
+        let columns = header
+            .split(',')
+            .map(|column| column.trim().to_owned())
+            .collect::<Vec<_>>();
+        if !columns.iter().any(|column| column == "solve_rate") {
+            return Ok(None);
+        }
+
+        let rows = lines
+            .map(|line| {
+                line.split(',')
+                    .map(|value| value.trim().to_owned())
+                    .collect()
+            })
+            .collect();
+        Ok(Some(Self { columns, rows }))
+    }
+
+    fn column(&self, name: &str) -> Option<usize> {
+        self.columns.iter().position(|column| column == name)
+    }
+
+    fn value<'a>(&self, row: &'a [String], name: &str) -> &'a str {
+        self.column(name)
+            .and_then(|index| row.get(index))
+            .map_or("", String::as_str)
+    }
+
+    // Parameter columns that differ between rows; constant ones would only repeat
+    // the same value down the table.
+    fn varying_parameters(&self) -> Vec<usize> {
+        let parameter_count = self.column("seeds").unwrap_or(self.columns.len());
+        let varying = (0..parameter_count)
+            .filter(|&index| {
+                self.rows.iter().any(|row| {
+                    row.get(index) != self.rows.first().and_then(|first| first.get(index))
+                })
+            })
+            .collect::<Vec<_>>();
+        if varying.is_empty() { vec![0] } else { varying }
+    }
+
+    fn max_value(&self, name: &str) -> f32 {
+        self.rows
+            .iter()
+            .filter_map(|row| self.value(row, name).parse::<f32>().ok())
+            .fold(0.0, f32::max)
+    }
+}
+
+struct ChartPanel {
+    chart: Chart,
+    height: f32,
+    // Conflict sums span orders of magnitude on large boards; ratios stay linear.
+    log_scalable: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ChartMarkerKind {
+    StagnationReset,
+    Solved,
+}
+
+struct ChartMarker {
+    epoch: u32,
+    kind: ChartMarkerKind,
     color: Color32,
-    values: Vec<(u32, f32)>,
+}
+
+// Shared by every chart on screen so zooming or panning one keeps the others aligned.
+#[derive(Default)]
+struct ChartView {
+    // Visible `(first, last)` epochs; `None` shows every recorded epoch.
+    epoch_window: Option<(f32, f32)>,
+    log_scale: bool,
+}
+
+impl ChartView {
+    fn handle_input(
+        &mut self,
+        ui: &egui::Ui,
+        response: &egui::Response,
+        plot_rect: Rect,
+        full_range: (f32, f32),
+    ) {
+        if response.double_clicked() {
+            self.epoch_window = None;
+            return;
+        }
+
+        let (full_start, full_end) = full_range;
+        let (mut start, mut end) = self.epoch_window.unwrap_or(full_range);
+        let span = end - start;
+
+        if response.dragged() {
+            let shift = -response.drag_delta().x * span / plot_rect.width().max(1.0);
+            start += shift;
+            end += shift;
+        }
+        if response.hovered() {
+            let zoom = ui.input(|input| input.zoom_delta());
+            if zoom != 1.0
+                && let Some(pointer) = response.hover_pos()
+            {
+                let share = ((pointer.x - plot_rect.left()) / plot_rect.width()).clamp(0.0, 1.0);
+                let anchor = start + span * share;
+                let full_span = full_end - full_start;
+                let zoomed_span =
+                    (span / zoom).clamp(MIN_CHART_EPOCH_SPAN.min(full_span), full_span);
+                start = anchor - zoomed_span * share;
+                end = start + zoomed_span;
+            }
+        }
+
+        // Keep the window inside the recorded epochs without changing its width.
+        let width = end - start;
+        if start < full_start {
+            start = full_start;
+            end = start + width;
+        }
+        if end > full_end {
+            end = full_end;
+            start = (end - width).max(full_start);
+        }
+        self.epoch_window = (start > full_start || end < full_end).then_some((start, end));
+    }
+}
+
+struct ReplayState {
+    index: usize,
+    playing: bool,
 }
 
 struct NQueensApp {
     config: GuiConfig,
+    custom_presets: Vec<CustomPreset>,
+    preset_name: String,
+    config_path: String,
+    settings_message: Option<String>,
     running: Option<RunningRun>,
     cancel_requested: bool,
     snapshots: Vec<EpochSnapshot>,
     latest_snapshot: Option<EpochSnapshot>,
+    population_summary: Option<PopulationSummary>,
     result: Option<RunResult>,
     error: Option<String>,
+    run_header: Option<RunLogHeader>,
+    run_log_path: String,
+    run_log_message: Option<String>,
+    chart_export_path: String,
+    chart_export_message: Option<String>,
+    chart_view: ChartView,
+    sweep: Option<SweepTable>,
+    replay: Option<ReplayState>,
+    comparisons: Vec<ComparedRun>,
+    selected_comparison: usize,
+    main_view: MainView,
+    editor: BoardEditor,
 }
 
 impl NQueensApp {
     fn new(cc: &eframe::CreationContext<'_>) -> Self {
         cc.egui_ctx.set_visuals(egui::Visuals::dark());
 
+        // A stored value that no longer parses falls back to the defaults.
+        let stored = |key| cc.storage.and_then(|storage| storage.get_string(key));
+        let config = stored(CONFIG_STORAGE_KEY)
+            .and_then(|contents| settings::config_from_toml(&contents).ok())
+            .unwrap_or_default();
+        let custom_presets = stored(CUSTOM_PRESETS_STORAGE_KEY)
+            .and_then(|contents| settings::presets_from_toml(&contents).ok())
+            .unwrap_or_default();
+
         Self {
-            config: GuiConfig::default(),
+            editor: BoardEditor::new(config.board_size),
+            config,
+            custom_presets,
+            preset_name: String::new(),
+            config_path: DEFAULT_CONFIG_FILE_PATH.to_owned(),
+            settings_message: None,
             running: None,
             cancel_requested: false,
             snapshots: Vec::new(),
             latest_snapshot: None,
+            population_summary: None,
             result: None,
             error: None,
+            run_header: None,
+            run_log_path: DEFAULT_RUN_LOG_PATH.to_owned(),
+            run_log_message: None,
+            chart_export_path: DEFAULT_CHART_EXPORT_PATH.to_owned(),
+            chart_export_message: None,
+            chart_view: ChartView::default(),
+            sweep: None,
+            replay: None,
+            comparisons: Vec::new(),
+            selected_comparison: 0,
+            main_view: MainView::Run,
         }
     }
 
     fn start_run(&mut self, ctx: &egui::Context) {
+        self.start_run_with(ctx, None);
+    }
+
+    fn start_run_with(&mut self, ctx: &egui::Context, seed: Option<EditorSeed>) {
         if self.running.is_some() {
             return;
         }
@@ -167,15 +675,47 @@ impl NQueensApp {
         self.config.normalize();
         self.snapshots.clear();
         self.latest_snapshot = None;
+        self.population_summary = None;
         self.result = None;
         self.error = None;
+        self.replay = None;
+        self.run_log_message = None;
         self.cancel_requested = false;
+        self.run_header = self
+            .config
+            .to_ga_config()
+            .ok()
+            .map(|config| RunLogHeader::from_config(&config));
+
+        self.running = Some(spawn_solver(self.config.clone(), seed, true));
+        ctx.request_repaint();
+    }
+
+    fn add_comparison(&mut self, ctx: &egui::Context) {
+        if self.comparisons.len() >= COMPARISON_COLORS.len() {
+            return;
+        }
 
-        let (receiver, cancel) = spawn_solver(self.config.clone());
-        self.running = Some(RunningRun { receiver, cancel });
+        self.config.normalize();
+        let number = self.comparisons.len() + 1;
+        let running = spawn_solver(self.config.clone(), None, false);
+        self.comparisons.push(ComparedRun {
+            label: self.config.comparison_label(number),
+            color: COMPARISON_COLORS[number - 1],
+            running: Some(running),
+            snapshots: Vec::new(),
+            result: None,
+            error: None,
+        });
+        self.selected_comparison = number - 1;
+        self.main_view = MainView::Comparison;
         ctx.request_repaint();
     }
 
+    fn comparisons_running(&self) -> bool {
+        self.comparisons.iter().any(|run| run.running.is_some())
+    }
+
     fn cancel_run(&mut self) {
         if let Some(running) = &self.running {
             running.cancel.store(true, Ordering::Relaxed);
@@ -183,6 +723,178 @@ impl NQueensApp {
         }
     }
 
+    fn send_worker_command(&mut self, command: WorkerCommand) {
+        if let Some(running) = &mut self.running {
+            running.paused = !matches!(command, WorkerCommand::Resume);
+            let _ = running.commands.send(command);
+        }
+    }
+
+    fn load_run_log(&mut self) {
+        let path = Path::new(&self.run_log_path);
+        match SweepTable::load(path) {
+            Ok(Some(sweep)) => {
+                self.run_log_message = Some(format!(
+                    "Loaded {} sweep rows from {}",
+                    sweep.rows.len(),
+                    self.run_log_path
+                ));
+                self.sweep = Some(sweep);
+                self.main_view = MainView::Sweep;
+                return;
+            }
+            Ok(None) => {}
+            Err(error) => {
+                self.run_log_message = Some(format!("Failed to load run log: {error}"));
+                return;
+            }
+        }
+
+        match RunLog::load(path) {
+            Ok(run_log) => {
+                self.snapshots = run_log.snapshots().to_vec();
+                self.latest_snapshot = self.snapshots.last().cloned();
+                self.population_summary = None;
+                self.result = None;
+                self.error = None;
+                self.run_header = run_log.header().copied();
+                if self.main_view == MainView::Sweep {
+                    self.main_view = MainView::Run;
+                }
+                self.replay = Some(ReplayState {
+                    index: self.snapshots.len() - 1,
+                    playing: false,
+                });
+                self.run_log_message = Some(format!(
+                    "Loaded {} epochs from {}",
+                    self.snapshots.len(),
+                    self.run_log_path
+                ));
+            }
+            Err(error) => {
+                self.run_log_message = Some(format!("Failed to load run log: {error}"));
+            }
+        }
+    }
+
+    fn save_run_log(&mut self) {
+        let (Some(header), Some(result)) = (&self.run_header, &self.result) else {
+            return;
+        };
+
+        let saved =
+            RunLogWriter::create(Path::new(&self.run_log_path), header).and_then(|mut writer| {
+                for snapshot in &self.snapshots {
+                    writer.record(snapshot)?;
+                }
+                writer.finish(&result.metrics)
+            });
+        self.run_log_message = Some(match saved {
+            Ok(_) => format!(
+                "Saved {} epochs to {}",
+                self.snapshots.len(),
+                self.run_log_path
+            ),
+            Err(error) => format!("Failed to save run log: {error}"),
+        });
+    }
+
+    // Exports what the charts area currently shows: the compared runs on the comparison
+    // tab, otherwise the current or replayed run.
+    fn export_charts(&mut self) {
+        let panels = if self.main_view == MainView::Comparison {
+            comparison_charts(&self.comparisons)
+        } else {
+            run_charts(self.charted_snapshots())
+        };
+        let charts = panels
+            .into_iter()
+            .map(|panel| panel.chart)
+            .collect::<Vec<_>>();
+        self.chart_export_message = Some(
+            match chart::export_charts(Path::new(&self.chart_export_path), &charts) {
+                Ok(format) => format!(
+                    "Exported {} charts as {format} to {}",
+                    charts.len(),
+                    self.chart_export_path
+                ),
+                Err(error) => format!("Failed to export charts: {error}"),
+            },
+        );
+    }
+
+    fn charted_snapshots(&self) -> &[EpochSnapshot] {
+        match &self.replay {
+            Some(replay) => &self.snapshots[..=replay.index],
+            None => &self.snapshots,
+        }
+    }
+
+    // Saving under an existing name replaces that preset.
+    fn save_custom_preset(&mut self) {
+        let name = self.preset_name.trim().to_owned();
+        if name.is_empty() {
+            return;
+        }
+
+        self.config.normalize();
+        let preset = CustomPreset {
+            name: name.clone(),
+            config: self.config.clone(),
+        };
+        match self
+            .custom_presets
+            .binary_search_by(|existing| existing.name.cmp(&name))
+        {
+            Ok(index) => self.custom_presets[index] = preset,
+            Err(index) => self.custom_presets.insert(index, preset),
+        }
+        self.settings_message = Some(format!("Saved preset \"{name}\""));
+    }
+
+    fn import_config(&mut self) {
+        let path = Path::new(&self.config_path);
+        self.settings_message = Some(match settings::import_config(path, &mut self.config) {
+            Ok(ignored) if ignored.is_empty() => format!("Imported {}", self.config_path),
+            Ok(ignored) => format!(
+                "Imported {} (ignored {})",
+                self.config_path,
+                ignored.join(", ")
+            ),
+            Err(error) => error,
+        });
+        self.config.normalize();
+    }
+
+    fn export_config(&mut self) {
+        self.config.normalize();
+        self.settings_message = Some(
+            match settings::export_config(Path::new(&self.config_path), &self.config) {
+                Ok(()) => format!("Exported {}", self.config_path),
+                Err(error) => error,
+            },
+        );
+    }
+
+    // The snapshot shown while stepping through a loaded run log.
+    fn replay_snapshot(&self) -> Option<&EpochSnapshot> {
+        self.replay
+            .as_ref()
+            .and_then(|replay| self.snapshots.get(replay.index))
+    }
+
+    fn advance_replay(&mut self, ctx: &egui::Context) {
+        let last_index = self.snapshots.len().saturating_sub(1);
+        if let Some(replay) = self.replay.as_mut().filter(|replay| replay.playing) {
+            if replay.index < last_index {
+                replay.index += 1;
+                ctx.request_repaint_after(Duration::from_millis(33));
+            } else {
+                replay.playing = false;
+            }
+        }
+    }
+
     fn drain_worker_messages(&mut self, ctx: &egui::Context) {
         let messages = self
             .running
@@ -193,9 +905,13 @@ impl NQueensApp {
 
         for message in messages {
             match message {
-                WorkerMessage::Snapshot(snapshot) => {
-                    self.latest_snapshot = Some(snapshot.clone());
-                    self.snapshots.push(snapshot);
+                WorkerMessage::Snapshot(mut snapshot) => {
+                    // Only the latest summary is drawn, so keep it out of the chart history.
+                    if let Some(summary) = snapshot.take_population_summary() {
+                        self.population_summary = Some(summary);
+                    }
+                    self.latest_snapshot = Some((*snapshot).clone());
+                    self.snapshots.push(*snapshot);
                 }
                 WorkerMessage::Finished(result) => {
                     self.result = Some(result);
@@ -211,7 +927,13 @@ impl NQueensApp {
         if finished {
             self.running = None;
             self.cancel_requested = false;
-        } else if self.running.is_some() {
+        }
+
+        for run in &mut self.comparisons {
+            run.drain_worker_messages();
+        }
+
+        if self.running.is_some() || self.comparisons_running() {
             ctx.request_repaint_after(Duration::from_millis(33));
         }
     }
@@ -249,6 +971,27 @@ impl NQueensApp {
                 );
                 ui.end_row();
 
+                ui.label("Max evaluations");
+                ui.add_enabled_ui(!is_running, |ui| {
+                    ui.horizontal(|ui| {
+                        let mut has_budget = self.config.max_evaluations.is_some();
+                        if ui.checkbox(&mut has_budget, "").changed() {
+                            self.config.max_evaluations =
+                                has_budget.then_some(DEFAULT_GUI_MAX_EVALUATIONS);
+                        }
+                        if let Some(max_evaluations) = &mut self.config.max_evaluations {
+                            ui.add(
+                                egui::DragValue::new(max_evaluations)
+                                    .speed(10_000.0)
+                                    .range(1..=u64::MAX),
+                            );
+                        } else {
+                            ui.weak("unlimited");
+                        }
+                    });
+                });
+                ui.end_row();
+
                 ui.label("Seed");
                 ui.horizontal(|ui| {
                     ui.add_enabled(
@@ -285,6 +1028,24 @@ impl NQueensApp {
             egui::Slider::new(&mut self.config.min_diversity_ratio, 0.0..=1.0)
                 .text("Min diversity"),
         );
+        ui.add_enabled_ui(!is_running, |ui| {
+            egui::ComboBox::from_label("Diversity measure")
+                .selected_text(diversity_measure_label(self.config.diversity_measure))
+                .show_ui(ui, |ui| {
+                    for measure in [
+                        DiversityMeasure::UniqueRatio,
+                        DiversityMeasure::GeneEntropy,
+                        DiversityMeasure::PairwiseDistance,
+                        DiversityMeasure::BestDistance,
+                    ] {
+                        ui.selectable_value(
+                            &mut self.config.diversity_measure,
+                            measure,
+                            diversity_measure_label(measure),
+                        );
+                    }
+                });
+        });
 
         ui.add_space(4.0);
         ui.label("Selection");
@@ -322,6 +1083,74 @@ impl NQueensApp {
             );
         });
 
+        ui.separator();
+        ui.label(RichText::new("Restarts and adaptation").strong());
+        ui.add_enabled_ui(!is_running, |ui| {
+            ui.horizontal(|ui| {
+                let mut fixed_interval = self.config.restart_interval.is_some();
+                if ui.checkbox(&mut fixed_interval, "Interval").changed() {
+                    self.config.restart_interval = fixed_interval
+                        .then(|| RestartPolicy::default().interval_for(self.config.max_epochs));
+                }
+                if let Some(interval) = &mut self.config.restart_interval {
+                    ui.add(
+                        egui::DragValue::new(interval)
+                            .speed(1.0)
+                            .range(1..=u32::MAX),
+                    );
+                } else {
+                    ui.weak("auto");
+                }
+            });
+            ui.add(
+                egui::Slider::new(&mut self.config.restart_keep_ratio, 0.0..=1.0)
+                    .text("Kept elites"),
+            );
+            ui.horizontal_wrapped(|ui| {
+                for (kind, label) in [
+                    (RestartKind::Random, "Random"),
+                    (RestartKind::PerturbBest, "Perturb best"),
+                    (RestartKind::Constructive, "Constructive"),
+                ] {
+                    ui.radio_value(&mut self.config.restart_kind, kind, label);
+                }
+            });
+            ui.checkbox(&mut self.config.adaptive, "Adapt parameters");
+            ui.add_enabled_ui(self.config.adaptive, |ui| {
+                egui::ComboBox::from_label("Controller")
+                    .selected_text(parameter_control_label(self.config.parameter_control))
+                    .show_ui(ui, |ui| {
+                        for control in [
+                            ParameterControl::Stagnation,
+                            ParameterControl::OneFifthRule,
+                            ParameterControl::SelfAdaptive,
+                            ParameterControl::OperatorBandit,
+                        ] {
+                            ui.selectable_value(
+                                &mut self.config.parameter_control,
+                                control,
+                                parameter_control_label(control),
+                            );
+                        }
+                    });
+            });
+        });
+
+        ui.separator();
+        ui.label(RichText::new("Symmetry").strong());
+        ui.add_enabled_ui(!is_running, |ui| {
+            ui.horizontal_wrapped(|ui| {
+                for (symmetry, label) in [
+                    (SymmetryConstraint::None, "None"),
+                    (SymmetryConstraint::Rotation180, "180°"),
+                    (SymmetryConstraint::Rotation90, "90°"),
+                    (SymmetryConstraint::Reflection, "Reflection (1 queen only)"),
+                ] {
+                    ui.radio_value(&mut self.config.symmetry, symmetry, label);
+                }
+            });
+        });
+
         ui.separator();
         if ui
             .add_enabled(!is_running, egui::Button::new("Run solver"))
@@ -338,17 +1167,187 @@ impl NQueensApp {
         {
             self.cancel_run();
         }
-        if ui
-            .add_enabled(!is_running, egui::Button::new("Fast demo values"))
-            .clicked()
-        {
-            self.config.use_fast_demo_values();
+        let is_paused = self.running.as_ref().is_some_and(|running| running.paused);
+        ui.horizontal(|ui| {
+            let can_control = is_running && !self.cancel_requested;
+            if ui
+                .add_enabled(can_control && !is_paused, egui::Button::new("Pause"))
+                .clicked()
+            {
+                self.send_worker_command(WorkerCommand::Pause);
+            }
+            if ui
+                .add_enabled(can_control && is_paused, egui::Button::new("Resume"))
+                .clicked()
+            {
+                self.send_worker_command(WorkerCommand::Resume);
+                ctx.request_repaint();
+            }
+            if ui
+                .add_enabled(can_control && is_paused, egui::Button::new("Step epoch"))
+                .clicked()
+            {
+                self.send_worker_command(WorkerCommand::Step);
+                ctx.request_repaint();
+            }
+        });
+
+        ui.separator();
+        ui.label(RichText::new("Presets").strong());
+        ui.add_enabled_ui(!is_running, |ui| {
+            ui.horizontal_wrapped(|ui| {
+                for preset in BuiltinPreset::ALL {
+                    if ui.button(preset.name()).clicked() {
+                        preset.apply(&mut self.config);
+                    }
+                }
+                if ui.button("Reset defaults").clicked() {
+                    self.config = GuiConfig::default();
+                }
+            });
+
+            let mut removed = None;
+            for (index, preset) in self.custom_presets.iter().enumerate() {
+                ui.horizontal(|ui| {
+                    if ui.button(&preset.name).clicked() {
+                        self.config = preset.config.clone();
+                    }
+                    if ui.small_button("Delete").clicked() {
+                        removed = Some(index);
+                    }
+                });
+            }
+            if let Some(index) = removed {
+                let preset = self.custom_presets.remove(index);
+                self.settings_message = Some(format!("Deleted preset \"{}\"", preset.name));
+            }
+
+            ui.horizontal(|ui| {
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.preset_name)
+                        .hint_text("Preset name")
+                        .desired_width(170.0),
+                );
+                if ui
+                    .add_enabled(
+                        !self.preset_name.trim().is_empty(),
+                        egui::Button::new("Save preset"),
+                    )
+                    .clicked()
+                {
+                    self.save_custom_preset();
+                }
+            });
+        });
+
+        ui.label(RichText::new("Config file").strong());
+        ui.add_enabled(
+            !is_running,
+            egui::TextEdit::singleline(&mut self.config_path).desired_width(f32::INFINITY),
+        );
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(!is_running, egui::Button::new("Import config"))
+                .on_hover_text("Reads a TOML or JSON file in the CLI --config format")
+                .clicked()
+            {
+                self.import_config();
+            }
+            if ui
+                .add_enabled(!is_running, egui::Button::new("Export config"))
+                .on_hover_text("Writes JSON for a .json path, otherwise TOML")
+                .clicked()
+            {
+                self.export_config();
+            }
+        });
+        if let Some(message) = &self.settings_message {
+            ui.label(message);
         }
+
+        ui.separator();
+        ui.label(RichText::new("Compare runs").strong());
+        ui.horizontal_wrapped(|ui| {
+            if ui
+                .add_enabled(
+                    self.comparisons.len() < COMPARISON_COLORS.len(),
+                    egui::Button::new("Add to comparison"),
+                )
+                .on_hover_text("Run the current parameters alongside the other compared runs")
+                .clicked()
+            {
+                self.add_comparison(ctx);
+            }
+            if ui
+                .add_enabled(
+                    self.comparisons_running(),
+                    egui::Button::new("Cancel compared runs"),
+                )
+                .clicked()
+            {
+                for run in &self.comparisons {
+                    run.cancel();
+                }
+            }
+            if ui
+                .add_enabled(
+                    !self.comparisons.is_empty() && !self.comparisons_running(),
+                    egui::Button::new("Clear"),
+                )
+                .clicked()
+            {
+                self.comparisons.clear();
+                self.selected_comparison = 0;
+                if self.main_view == MainView::Comparison {
+                    self.main_view = MainView::Run;
+                }
+            }
+        });
+
+        ui.separator();
+        ui.label(RichText::new("Run log").strong());
+        ui.add_enabled(
+            !is_running,
+            egui::TextEdit::singleline(&mut self.run_log_path).desired_width(f32::INFINITY),
+        );
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(!is_running, egui::Button::new("Load run log"))
+                .clicked()
+            {
+                self.load_run_log();
+            }
+            let can_save = !is_running && self.result.is_some() && self.run_header.is_some();
+            if ui
+                .add_enabled(can_save, egui::Button::new("Save run log"))
+                .clicked()
+            {
+                self.save_run_log();
+            }
+        });
+        if let Some(message) = &self.run_log_message {
+            ui.label(message);
+        }
+
+        ui.separator();
+        ui.label(RichText::new("Charts").strong());
+        ui.add(
+            egui::TextEdit::singleline(&mut self.chart_export_path).desired_width(f32::INFINITY),
+        );
+        let has_charts = if self.main_view == MainView::Comparison {
+            self.comparisons.iter().any(|run| !run.snapshots.is_empty())
+        } else {
+            !self.snapshots.is_empty()
+        };
         if ui
-            .add_enabled(!is_running, egui::Button::new("Reset defaults"))
+            .add_enabled(has_charts, egui::Button::new("Export charts"))
+            .on_hover_text("Writes PNG or SVG depending on the file extension")
             .clicked()
         {
-            self.config = GuiConfig::default();
+            self.export_charts();
+        }
+        if let Some(message) = &self.chart_export_message {
+            ui.label(message);
         }
 
         ui.separator();
@@ -390,7 +1389,17 @@ impl NQueensApp {
                 metric_row(ui, "Elapsed", format_ms(metrics.elapsed_ms()));
             });
 
-        if let Some(result) = &self.result {
+        let mut run_timings = PhaseTimings::default();
+        for snapshot in self.charted_snapshots() {
+            run_timings += snapshot.metrics().phase_timings();
+        }
+        egui::CollapsingHeader::new("Phase timing")
+            .id_salt("phase_timing")
+            .show(ui, |ui| {
+                draw_phase_timings(ui, metrics.phase_timings(), run_timings);
+            });
+
+        if let Some(result) = self.result.as_ref().filter(|_| self.replay.is_none()) {
             ui.add_space(6.0);
             if result.cancelled {
                 ui.colored_label(Color32::from_rgb(245, 190, 95), "Run cancelled");
@@ -410,27 +1419,501 @@ impl NQueensApp {
         }
     }
 
-    fn draw_main_panel(&self, ui: &mut egui::Ui) {
+    fn draw_main_panel(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.selectable_value(&mut self.main_view, MainView::Run, "Current run");
+            ui.selectable_value(&mut self.main_view, MainView::Population, "Population");
+            ui.selectable_value(&mut self.main_view, MainView::Editor, "Board editor");
+            if !self.comparisons.is_empty() {
+                ui.selectable_value(&mut self.main_view, MainView::Comparison, "Comparison");
+            }
+            if self.sweep.is_some() {
+                ui.selectable_value(&mut self.main_view, MainView::Sweep, "Sweep results");
+            }
+        });
+        ui.separator();
+        match self.main_view {
+            MainView::Run => {}
+            MainView::Population => {
+                self.draw_population_panel(ui);
+                return;
+            }
+            MainView::Editor => {
+                self.draw_editor_panel(ui);
+                return;
+            }
+            MainView::Comparison => {
+                self.draw_comparison_panel(ui);
+                return;
+            }
+            MainView::Sweep => {
+                self.draw_sweep_panel(ui);
+                return;
+            }
+        }
+
         ui.horizontal_wrapped(|ui| {
             ui.heading("Board");
             ui.label(self.status_text());
         });
         ui.add_space(8.0);
+        self.draw_replay_controls(ui);
 
         let board = self.current_board();
         ui.vertical_centered(|ui| {
             if let Some((positions, conflicts, conflicts_sum)) = board {
                 draw_board(ui, positions, conflicts, conflicts_sum);
             } else {
-                draw_empty_board(ui);
+                draw_empty_board(ui, "Run the solver to draw a board");
+            }
+        });
+
+        ui.separator();
+        let snapshots = match &self.replay {
+            Some(replay) => &self.snapshots[..=replay.index],
+            None => &self.snapshots,
+        };
+        draw_charts(
+            ui,
+            &mut self.chart_view,
+            &run_charts(snapshots),
+            &run_markers(snapshots, None),
+            |ui, epoch| {
+                if let Ok(index) =
+                    snapshots.binary_search_by_key(&epoch, |snapshot| snapshot.metrics().epoch())
+                {
+                    draw_epoch_tooltip(ui, snapshots[index].metrics());
+                }
+            },
+        );
+    }
+
+    fn draw_population_panel(&self, ui: &mut egui::Ui) {
+        let Some(summary) = self
+            .population_summary
+            .as_ref()
+            .filter(|_| self.replay.is_none())
+        else {
+            ui.label("Population views appear once a solver run reports its first epoch.");
+            return;
+        };
+
+        ui.horizontal_wrapped(|ui| {
+            ui.heading("Population");
+            if let Some(snapshot) = &self.latest_snapshot {
+                ui.label(format!(
+                    "Epoch {}, {} chromosomes",
+                    snapshot.metrics().epoch(),
+                    summary.population_size()
+                ));
+            }
+        });
+        ui.add_space(6.0);
+
+        let side = (ui.available_width() * 0.5 - 12.0).clamp(200.0, 420.0);
+        ui.horizontal_top(|ui| {
+            ui.vertical(|ui| {
+                ui.label(RichText::new("Queen placement heatmap").strong());
+                draw_population_heatmap(ui, summary, side);
+            });
+            ui.vertical(|ui| {
+                ui.label(RichText::new("Pairwise-distance projection").strong());
+                draw_population_projection(ui, summary, side);
+            });
+        });
+        ui.add_space(8.0);
+        ui.label(RichText::new("Conflict sums across the population").strong());
+        draw_conflict_histogram(ui, summary, 170.0);
+    }
+
+    fn draw_editor_panel(&mut self, ui: &mut egui::Ui) {
+        let is_running = self.running.is_some();
+        let size = self.editor.size();
+
+        ui.input_mut(|input| {
+            if input.consume_key(
+                egui::Modifiers::COMMAND | egui::Modifiers::SHIFT,
+                egui::Key::Z,
+            ) || input.consume_key(egui::Modifiers::COMMAND, egui::Key::Y)
+            {
+                self.editor.redo();
+            } else if input.consume_key(egui::Modifiers::COMMAND, egui::Key::Z) {
+                self.editor.undo();
+            }
+        });
+
+        ui.horizontal_wrapped(|ui| {
+            if ui
+                .button(format!("New {0}x{0} board", self.config.board_size))
+                .clicked()
+            {
+                let size = self.config.board_size.max(1);
+                self.editor.load((0..size).collect());
+            }
+            if ui
+                .add_enabled(
+                    self.current_board().is_some(),
+                    egui::Button::new("Copy best board"),
+                )
+                .clicked()
+                && let Some((positions, _, _)) = self.current_board()
+            {
+                let positions = positions.to_vec();
+                self.editor.load(positions);
+            }
+            if ui.button("Shuffle unpinned").clicked() {
+                self.editor.shuffle_unpinned();
+            }
+            if ui
+                .add_enabled(!self.editor.undo.is_empty(), egui::Button::new("Undo"))
+                .clicked()
+            {
+                self.editor.undo();
+            }
+            if ui
+                .add_enabled(!self.editor.redo.is_empty(), egui::Button::new("Redo"))
+                .clicked()
+            {
+                self.editor.redo();
+            }
+        });
+        ui.label(
+            "Click a cell or drag a queen along its column to move it; the queen on the \
+             target row swaps into its place. Right-click a queen to pin it.",
+        );
+        ui.add_space(8.0);
+
+        let chromosome = Chromosome::new(self.editor.board.positions.clone());
+        let response = ui
+            .vertical_centered(|ui| {
+                draw_board_with_sense(
+                    ui,
+                    chromosome.get_positions(),
+                    chromosome.get_conflicts(),
+                    chromosome.get_conflicts_sum(),
+                    Sense::click_and_drag(),
+                )
+            })
+            .inner;
+        if let Some(response) = response {
+            self.handle_editor_input(&response, size);
+        }
+
+        ui.add_space(8.0);
+        ui.horizontal_wrapped(|ui| {
+            ui.label("Positions");
+            let position_list = self.editor.position_list();
+            let text = ui.add(
+                egui::TextEdit::singleline(&mut self.editor.positions_text)
+                    .hint_text(position_list)
+                    .desired_width(320.0),
+            );
+            let submitted =
+                text.lost_focus() && ui.input(|input| input.key_pressed(egui::Key::Enter));
+            if ui.button("Apply").clicked() || submitted {
+                self.apply_editor_text();
+            }
+            if ui.button("Copy positions").clicked() {
+                ui.ctx().copy_text(self.editor.position_list());
+                self.editor.message = Some("Position list copied".to_owned());
+            }
+        });
+
+        ui.horizontal_wrapped(|ui| {
+            let pinned = self.editor.fixed_queens().len();
+            ui.add_enabled(
+                pinned > 0,
+                egui::Checkbox::new(
+                    &mut self.editor.fix_pinned,
+                    format!("Keep {pinned} pinned queens fixed"),
+                ),
+            );
+            if ui
+                .add_enabled(!is_running, egui::Button::new("Solve from this board"))
+                .on_hover_text("Start a run with this board in the initial population")
+                .clicked()
+            {
+                let fixed_queens = if self.editor.fix_pinned {
+                    self.editor.fixed_queens()
+                } else {
+                    Vec::new()
+                };
+                self.config.board_size = size as u16;
+                let seed = EditorSeed {
+                    positions: self.editor.board.positions.clone(),
+                    fixed_queens,
+                };
+                self.start_run_with(ui.ctx(), Some(seed));
+                self.main_view = MainView::Run;
             }
         });
+        if let Some(message) = &self.editor.message {
+            ui.label(message);
+        }
+    }
+
+    fn handle_editor_input(&mut self, response: &egui::Response, size: usize) {
+        let cell_at = |position: Pos2| {
+            let cell = response.rect.width() / size as f32;
+            let column = ((position.x - response.rect.left()) / cell).floor();
+            let row = ((position.y - response.rect.top()) / cell).floor();
+            (column >= 0.0 && row >= 0.0 && (column as usize) < size && (row as usize) < size)
+                .then_some((column as usize, row as u16))
+        };
+        let pointer_cell = response.interact_pointer_pos().and_then(cell_at);
+
+        if response.drag_started()
+            && let Some((column, row)) = pointer_cell
+            && self.editor.board.positions[column] == row
+        {
+            self.editor.dragged_column = Some(column);
+        }
+        if response.dragged()
+            && let Some((_, row)) = pointer_cell
+        {
+            self.editor.drag_row = Some(row);
+        }
+        if response.drag_stopped() {
+            if let (Some(column), Some(row)) = (
+                self.editor.dragged_column.take(),
+                self.editor.drag_row.take(),
+            ) {
+                self.editor.move_queen(column, row);
+            }
+        } else if response.clicked()
+            && let Some((column, row)) = pointer_cell
+        {
+            self.editor.move_queen(column, row);
+        } else if response.secondary_clicked()
+            && let Some(position) = response.hover_pos()
+            && let Some((column, _)) = cell_at(position)
+        {
+            self.editor.toggle_pin(column);
+        }
+
+        let cell = response.rect.width() / size as f32;
+        let painter = response.ctx.layer_painter(response.layer_id);
+        for (column, _) in self.editor.fixed_queens() {
+            let row = self.editor.board.positions[column];
+            let cell_rect = Rect::from_min_size(
+                Pos2::new(
+                    response.rect.left() + column as f32 * cell,
+                    response.rect.top() + f32::from(row) * cell,
+                ),
+                Vec2::splat(cell),
+            );
+            painter.rect_stroke(
+                cell_rect.shrink(1.0),
+                egui::CornerRadius::ZERO,
+                Stroke::new(2.0, Color32::from_rgb(230, 235, 245)),
+                egui::StrokeKind::Inside,
+            );
+        }
+        if let (Some(column), Some(row)) = (self.editor.dragged_column, self.editor.drag_row) {
+            let center = Pos2::new(
+                response.rect.left() + (column as f32 + 0.5) * cell,
+                response.rect.top() + (f32::from(row) + 0.5) * cell,
+            );
+            painter.circle_stroke(
+                center,
+                (cell * 0.34).clamp(2.0, 18.0),
+                Stroke::new(2.0, Color32::from_rgb(230, 235, 245)),
+            );
+        }
+    }
+
+    fn apply_editor_text(&mut self) {
+        let parsed = board::parse_board(&self.editor.positions_text)
+            .map_err(|error| error.to_string())
+            .and_then(|positions| {
+                Chromosome::try_new(positions.clone())
+                    .map(|_| positions)
+                    .map_err(|error| error.to_string())
+            });
+        match parsed {
+            Ok(positions) => {
+                self.editor.load(positions);
+                self.editor.positions_text.clear();
+                self.editor.message = None;
+            }
+            Err(error) => self.editor.message = Some(format!("Invalid board: {error}")),
+        }
+    }
+
+    fn draw_comparison_panel(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal_wrapped(|ui| {
+            for (index, run) in self.comparisons.iter().enumerate() {
+                let label = RichText::new(&run.label).color(run.color);
+                ui.selectable_value(&mut self.selected_comparison, index, label);
+            }
+        });
+        ui.add_space(8.0);
+
+        if let Some(run) = self.comparisons.get(self.selected_comparison) {
+            ui.horizontal_wrapped(|ui| {
+                ui.heading("Board");
+                ui.label(run.status_text());
+            });
+            ui.add_space(8.0);
+            ui.vertical_centered(|ui| {
+                if let Some((positions, conflicts, conflicts_sum)) = run.current_board() {
+                    draw_board(ui, positions, conflicts, conflicts_sum);
+                } else {
+                    draw_empty_board(ui, "Run the solver to draw a board");
+                }
+            });
+        }
 
         ui.separator();
-        draw_charts(ui, &self.snapshots);
+        let markers = self
+            .comparisons
+            .iter()
+            .flat_map(|run| run_markers(&run.snapshots, Some(run.color)))
+            .collect::<Vec<_>>();
+        let runs = &self.comparisons;
+        draw_charts(
+            ui,
+            &mut self.chart_view,
+            &comparison_charts(runs),
+            &markers,
+            |ui, epoch| {
+                ui.label(RichText::new(format!("Epoch {epoch}")).strong());
+                egui::Grid::new("comparison_tooltip")
+                    .num_columns(4)
+                    .spacing([12.0, 4.0])
+                    .show(ui, |ui| {
+                        for heading in ["Run", "Best", "Avg", "Diversity"] {
+                            ui.label(RichText::new(heading).strong());
+                        }
+                        ui.end_row();
+                        for run in runs {
+                            let Ok(index) =
+                                run.snapshots.binary_search_by_key(&epoch, |snapshot| {
+                                    snapshot.metrics().epoch()
+                                })
+                            else {
+                                continue;
+                            };
+                            let metrics = run.snapshots[index].metrics();
+                            ui.colored_label(run.color, &run.label);
+                            ui.monospace(metrics.best_conflicts_sum().to_string());
+                            ui.monospace(format!("{:.2}", metrics.average_conflicts_sum()));
+                            ui.monospace(format_ratio(metrics.diversity_ratio()));
+                            ui.end_row();
+                        }
+                    });
+            },
+        );
+    }
+
+    fn draw_sweep_panel(&self, ui: &mut egui::Ui) {
+        let Some(sweep) = &self.sweep else {
+            ui.label("Load a sweep CSV to compare its parameter combinations.");
+            return;
+        };
+
+        let parameters = sweep.varying_parameters();
+        let max_epoch = sweep.max_value("median_solved_epoch").max(1.0);
+        egui::ScrollArea::both().show(ui, |ui| {
+            egui::Grid::new("sweep_results")
+                .striped(true)
+                .spacing([16.0, 6.0])
+                .show(ui, |ui| {
+                    for &index in &parameters {
+                        ui.label(RichText::new(&sweep.columns[index]).strong());
+                    }
+                    for heading in ["solved", "solve rate", "median solved epoch", "median ms"] {
+                        ui.label(RichText::new(heading).strong());
+                    }
+                    ui.end_row();
+
+                    for row in &sweep.rows {
+                        for &index in &parameters {
+                            ui.monospace(row.get(index).map_or("", String::as_str));
+                        }
+                        ui.monospace(format!(
+                            "{}/{}",
+                            sweep.value(row, "solved"),
+                            sweep.value(row, "seeds")
+                        ));
+                        let solve_rate = sweep.value(row, "solve_rate");
+                        draw_sweep_bar(
+                            ui,
+                            solve_rate.parse().unwrap_or_default(),
+                            Color32::from_rgb(95, 220, 140),
+                            solve_rate,
+                        );
+                        let median_epoch = sweep.value(row, "median_solved_epoch");
+                        match median_epoch.parse::<f32>() {
+                            Ok(epoch) => draw_sweep_bar(
+                                ui,
+                                epoch / max_epoch,
+                                Color32::from_rgb(110, 190, 255),
+                                median_epoch,
+                            ),
+                            Err(_) => {
+                                ui.monospace("-");
+                            }
+                        }
+                        ui.monospace(sweep.value(row, "median_elapsed_ms"));
+                        ui.end_row();
+                    }
+                });
+        });
+    }
+
+    fn draw_replay_controls(&mut self, ui: &mut egui::Ui) {
+        let last_index = self.snapshots.len().saturating_sub(1);
+        let Some(replay) = self.replay.as_mut() else {
+            return;
+        };
+
+        ui.horizontal_wrapped(|ui| {
+            if ui.button("|<").clicked() {
+                replay.index = 0;
+                replay.playing = false;
+            }
+            if ui.button("<").clicked() {
+                replay.index = replay.index.saturating_sub(1);
+                replay.playing = false;
+            }
+            let play_label = if replay.playing { "Pause" } else { "Play" };
+            if ui.button(play_label).clicked() {
+                if !replay.playing && replay.index == last_index {
+                    replay.index = 0;
+                }
+                replay.playing = !replay.playing;
+            }
+            if ui.button(">").clicked() {
+                replay.index = (replay.index + 1).min(last_index);
+                replay.playing = false;
+            }
+            if ui.button(">|").clicked() {
+                replay.index = last_index;
+                replay.playing = false;
+            }
+            let epoch = self.snapshots[replay.index].metrics().epoch();
+            let slider = ui.add(
+                egui::Slider::new(&mut replay.index, 0..=last_index)
+                    .show_value(false)
+                    .text(format!("epoch {epoch}")),
+            );
+            if slider.changed() {
+                replay.playing = false;
+            }
+        });
+        ui.add_space(8.0);
     }
 
     fn current_board(&self) -> Option<(&[u16], &[u32], u32)> {
+        if let Some(snapshot) = self.replay_snapshot() {
+            return Some((
+                snapshot.best_positions(),
+                snapshot.best_conflicts(),
+                snapshot.best_conflicts_sum(),
+            ));
+        }
+
         if let Some(result) = &self.result {
             return Some((
                 result.best_positions.as_slice(),
@@ -453,7 +1936,19 @@ impl NQueensApp {
             return "Cancelling after current epoch".to_owned();
         }
 
-        if self.running.is_some() {
+        if let Some(running) = &self.running {
+            if running.paused {
+                return self.latest_snapshot.as_ref().map_or_else(
+                    || "Paused before the first epoch".to_owned(),
+                    |snapshot| {
+                        format!(
+                            "Paused at epoch {} with {} best conflicts",
+                            snapshot.metrics().epoch(),
+                            snapshot.best_conflicts_sum()
+                        )
+                    },
+                );
+            }
             return self.latest_snapshot.as_ref().map_or_else(
                 || "Starting solver".to_owned(),
                 |snapshot| {
@@ -470,6 +1965,17 @@ impl NQueensApp {
             return format!("Error: {error}");
         }
 
+        if let Some(snapshot) = self.replay_snapshot() {
+            return format!(
+                "Replaying epoch {} of {} with {} best conflicts",
+                snapshot.metrics().epoch(),
+                self.snapshots
+                    .last()
+                    .map_or(0, |snapshot| snapshot.metrics().epoch()),
+                snapshot.best_conflicts_sum()
+            );
+        }
+
         if let Some(result) = &self.result {
             if result.cancelled {
                 return format!(
@@ -487,6 +1993,10 @@ impl NQueensApp {
     }
 
     fn current_metrics(&self) -> Option<(&ga::EpochMetrics, u32)> {
+        if let Some(snapshot) = self.replay_snapshot() {
+            return Some((snapshot.metrics(), snapshot.best_conflicts_sum()));
+        }
+
         if let Some(result) = &self.result {
             return result
                 .metrics
@@ -506,12 +2016,24 @@ impl Drop for NQueensApp {
         if let Some(running) = &self.running {
             running.cancel.store(true, Ordering::Relaxed);
         }
+        for run in &self.comparisons {
+            run.cancel();
+        }
     }
 }
 
 impl eframe::App for NQueensApp {
+    fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        storage.set_string(CONFIG_STORAGE_KEY, settings::config_to_toml(&self.config));
+        storage.set_string(
+            CUSTOM_PRESETS_STORAGE_KEY,
+            settings::presets_to_toml(&self.custom_presets),
+        );
+    }
+
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         self.drain_worker_messages(ctx);
+        self.advance_replay(ctx);
 
         egui::TopBottomPanel::top("top_bar").show(ctx, |ui| {
             ui.horizontal_wrapped(|ui| {
@@ -538,14 +2060,21 @@ impl eframe::App for NQueensApp {
     }
 }
 
-fn spawn_solver(config: GuiConfig) -> (Receiver<WorkerMessage>, Arc<AtomicBool>) {
+fn spawn_solver(
+    config: GuiConfig,
+    seed: Option<EditorSeed>,
+    summarize_population: bool,
+) -> RunningRun {
     let (sender, receiver) = mpsc::channel();
+    let (commands, command_receiver) = mpsc::channel();
     let cancel = Arc::new(AtomicBool::new(false));
     let cancel_worker = Arc::clone(&cancel);
 
     thread::spawn(move || {
         let ga_config = match config.to_ga_config() {
-            Ok(config) => config,
+            Ok(config) => config
+                .with_population_summary(summarize_population)
+                .with_diversity_report_interval(Some(1)),
             Err(error) => {
                 let _ = sender.send(WorkerMessage::Failed(format!("Invalid GA config: {error}")));
                 return;
@@ -559,17 +2088,39 @@ fn spawn_solver(config: GuiConfig) -> (Receiver<WorkerMessage>, Arc<AtomicBool>)
                 return;
             }
         };
-        let progress_sender = sender.clone();
-        let run_metrics = algorithm.run_algorithm_with_progress(|snapshot| {
+        if let Some(seed) = seed {
+            let seeded = algorithm
+                .fix_queens(&seed.fixed_queens)
+                .and_then(|()| algorithm.seed_population(&seed.positions));
+            if let Err(error) = seeded {
+                let _ = sender.send(WorkerMessage::Failed(format!("Invalid board: {error}")));
+                return;
+            }
+        }
+        let send_snapshot = |algorithm: &ga::GeneticAlgorithm| {
             if cancel_worker.load(Ordering::Relaxed) {
                 return false;
             }
+            algorithm.latest_snapshot().is_none_or(|snapshot| {
+                sender
+                    .send(WorkerMessage::Snapshot(Box::new(snapshot)))
+                    .is_ok()
+                    && !cancel_worker.load(Ordering::Relaxed)
+            })
+        };
 
-            progress_sender
-                .send(WorkerMessage::Snapshot(snapshot.clone()))
-                .is_ok()
-                && !cancel_worker.load(Ordering::Relaxed)
-        });
+        algorithm.init();
+        let mut paused = false;
+        let mut keep_running = send_snapshot(&algorithm);
+        while keep_running && !algorithm.is_finished() {
+            if !wait_for_next_epoch(&command_receiver, &mut paused, &cancel_worker) {
+                break;
+            }
+            algorithm.step();
+            keep_running = send_snapshot(&algorithm);
+        }
+        algorithm.cancel();
+        let run_metrics = algorithm.take_run_metrics();
 
         let best_chromosome = algorithm.get_best_chromosome();
         let result = RunResult {
@@ -584,7 +2135,46 @@ fn spawn_solver(config: GuiConfig) -> (Receiver<WorkerMessage>, Arc<AtomicBool>)
         let _ = sender.send(WorkerMessage::Finished(result));
     });
 
-    (receiver, cancel)
+    RunningRun {
+        receiver,
+        cancel,
+        commands,
+        paused: false,
+    }
+}
+
+// Applies queued commands and blocks while paused; false means the run should stop.
+fn wait_for_next_epoch(
+    commands: &Receiver<WorkerCommand>,
+    paused: &mut bool,
+    cancel: &AtomicBool,
+) -> bool {
+    loop {
+        if cancel.load(Ordering::Relaxed) {
+            return false;
+        }
+        let command = if *paused {
+            match commands.recv_timeout(PAUSED_WORKER_POLL_INTERVAL) {
+                Ok(command) => command,
+                Err(RecvTimeoutError::Timeout) => continue,
+                Err(RecvTimeoutError::Disconnected) => return false,
+            }
+        } else {
+            match commands.try_recv() {
+                Ok(command) => command,
+                Err(TryRecvError::Empty) => return true,
+                Err(TryRecvError::Disconnected) => return false,
+            }
+        };
+        match command {
+            WorkerCommand::Pause => *paused = true,
+            WorkerCommand::Resume => *paused = false,
+            WorkerCommand::Step => {
+                *paused = true;
+                return true;
+            }
+        }
+    }
 }
 
 fn metric_row(ui: &mut egui::Ui, label: &str, value: String) {
@@ -593,10 +2183,177 @@ fn metric_row(ui: &mut egui::Ui, label: &str, value: String) {
     ui.end_row();
 }
 
+fn draw_epoch_tooltip(ui: &mut egui::Ui, metrics: &ga::EpochMetrics) {
+    egui::Grid::new("epoch_tooltip")
+        .num_columns(2)
+        .spacing([12.0, 4.0])
+        .show(ui, |ui| {
+            metric_row(ui, "Epoch", metrics.epoch().to_string());
+            metric_row(
+                ui,
+                "Best conflicts",
+                metrics.best_conflicts_sum().to_string(),
+            );
+            metric_row(
+                ui,
+                "Avg conflicts",
+                format!("{:.2}", metrics.average_conflicts_sum()),
+            );
+            metric_row(
+                ui,
+                "Unique",
+                format!(
+                    "{} of {}",
+                    metrics.unique_chromosomes(),
+                    metrics.population_size()
+                ),
+            );
+            if let Some(classes) = metrics.unique_symmetry_classes() {
+                metric_row(ui, "Symmetry classes", classes.to_string());
+            }
+            metric_row(ui, "Diversity", format_ratio(metrics.diversity_ratio()));
+            if let Some(entropy) = metrics.gene_entropy() {
+                metric_row(ui, "Gene entropy", format_ratio(entropy));
+            }
+            if let Some(distance) = metrics.mean_pairwise_distance() {
+                metric_row(ui, "Pairwise distance", format_ratio(distance));
+            }
+            if let Some(distance) = metrics.mean_best_distance() {
+                metric_row(ui, "Distance to best", format_ratio(distance));
+            }
+            metric_row(ui, "Mutation", format_ratio(metrics.mutation_rate()));
+            metric_row(ui, "Elite", format_ratio(metrics.elite_ratio()));
+            let control = metrics.control();
+            metric_row(
+                ui,
+                "Mutation success",
+                format_ratio(control.mutation_success_ratio),
+            );
+            if control.crossover_probabilities != ControlState::default().crossover_probabilities {
+                metric_row(
+                    ui,
+                    "Crossover odds",
+                    format_operator_odds(["PMX", "OX", "CX"], control.crossover_probabilities),
+                );
+                metric_row(
+                    ui,
+                    "Mutation odds",
+                    format_operator_odds(["swap", "inv", "ins"], control.mutation_probabilities),
+                );
+            }
+            metric_row(ui, "Offspring", metrics.offspring_count().to_string());
+            metric_row(
+                ui,
+                "Local search",
+                metrics.local_search_improvements().to_string(),
+            );
+            metric_row(ui, "Stagnation", metrics.stagnation_epochs().to_string());
+            metric_row(
+                ui,
+                "Diversity refresh",
+                metrics.diversity_replacements().to_string(),
+            );
+            metric_row(
+                ui,
+                "Phase time",
+                format_us(metrics.phase_timings().total_us()),
+            );
+            metric_row(
+                ui,
+                "Evaluations",
+                format!(
+                    "{} (+{})",
+                    metrics.total_evaluations(),
+                    metrics.evaluations().total()
+                ),
+            );
+            metric_row(ui, "Elapsed", format_ms(metrics.elapsed_ms()));
+        });
+    if metrics.stagnation_reset() {
+        ui.colored_label(
+            Color32::from_rgb(245, 170, 80),
+            "Population soft-restarted for stagnation",
+        );
+    }
+    if metrics.best_conflicts_sum() == 0 {
+        ui.colored_label(Color32::from_rgb(105, 220, 145), "Solved");
+    }
+}
+
+fn diversity_measure_label(measure: DiversityMeasure) -> &'static str {
+    match measure {
+        DiversityMeasure::UniqueRatio => "Unique ratio",
+        DiversityMeasure::GeneEntropy => "Gene entropy",
+        DiversityMeasure::PairwiseDistance => "Pairwise distance",
+        DiversityMeasure::BestDistance => "Distance to best",
+    }
+}
+
+fn parameter_control_label(control: ParameterControl) -> &'static str {
+    match control {
+        ParameterControl::Stagnation => "Stagnation",
+        ParameterControl::OneFifthRule => "One-fifth rule",
+        ParameterControl::SelfAdaptive => "Self-adaptive",
+        ParameterControl::OperatorBandit => "Operator bandit",
+    }
+}
+
+fn format_operator_odds(labels: [&str; 3], probabilities: [f32; 3]) -> String {
+    labels
+        .iter()
+        .zip(probabilities)
+        .map(|(label, probability)| format!("{label} {:.0}%", probability * 100.0))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 fn format_ratio(value: f32) -> String {
     format!("{:.1}%", value * 100.0)
 }
 
+// Per-phase time of the shown epoch next to the run's total up to that epoch.
+fn draw_phase_timings(ui: &mut egui::Ui, epoch: PhaseTimings, run: PhaseTimings) {
+    let run_total = run.total_us().max(1) as f32;
+    egui::Grid::new("phase_timing_grid")
+        .num_columns(4)
+        .spacing([12.0, 4.0])
+        .show(ui, |ui| {
+            ui.label(RichText::new("Phase").strong());
+            ui.label(RichText::new("Epoch").strong());
+            ui.label(RichText::new("Run").strong());
+            ui.label(RichText::new("Share").strong());
+            ui.end_row();
+            for (label, epoch_us, run_us) in [
+                ("Mating", epoch.mating_us, run.mating_us),
+                ("Mutation", epoch.mutation_us, run.mutation_us),
+                ("Local search", epoch.local_search_us, run.local_search_us),
+                ("Selection", epoch.selection_us, run.selection_us),
+                ("Fitness", epoch.fitness_us, run.fitness_us),
+                ("Diversity", epoch.diversity_us, run.diversity_us),
+            ] {
+                ui.label(label);
+                ui.label(RichText::new(format_us(epoch_us)).monospace());
+                ui.label(RichText::new(format_us(run_us)).monospace());
+                ui.add(
+                    egui::ProgressBar::new(run_us as f32 / run_total)
+                        .desired_width(70.0)
+                        .show_percentage(),
+                );
+                ui.end_row();
+            }
+        });
+}
+
+fn format_us(us: u64) -> String {
+    if us >= 1_000_000 {
+        format!("{:.2}s", us as f64 / 1_000_000.0)
+    } else if us >= 1_000 {
+        format!("{:.1} ms", us as f64 / 1_000.0)
+    } else {
+        format!("{us} µs")
+    }
+}
+
 fn format_ms(ms: u128) -> String {
     if ms >= 1_000 {
         format!("{:.2}s", ms as f64 / 1_000.0)
@@ -605,7 +2362,7 @@ fn format_ms(ms: u128) -> String {
     }
 }
 
-fn draw_empty_board(ui: &mut egui::Ui) {
+fn draw_empty_board(ui: &mut egui::Ui, message: &str) {
     let side = ui.available_width().clamp(260.0, 560.0);
     let (rect, _) = ui.allocate_exact_size(Vec2::new(side, side), Sense::hover());
     let painter = ui.painter_at(rect);
@@ -617,22 +2374,33 @@ fn draw_empty_board(ui: &mut egui::Ui) {
     painter.text(
         rect.center(),
         Align2::CENTER_CENTER,
-        "Run the solver to draw a board",
+        message,
         FontId::proportional(18.0),
         Color32::from_rgb(180, 190, 205),
     );
 }
 
 fn draw_board(ui: &mut egui::Ui, positions: &[u16], conflicts: &[u32], conflicts_sum: u32) {
+    draw_board_with_sense(ui, positions, conflicts, conflicts_sum, Sense::hover());
+}
+
+fn draw_board_with_sense(
+    ui: &mut egui::Ui,
+    positions: &[u16],
+    conflicts: &[u32],
+    conflicts_sum: u32,
+    sense: Sense,
+) -> Option<egui::Response> {
     let size = positions.len();
+    // Metrics CSV files record epochs without the best board.
     if size == 0 {
-        draw_empty_board(ui);
-        return;
+        draw_empty_board(ui, "No board recorded for this epoch");
+        return None;
     }
 
     ui.label(format!("{size} queens, {conflicts_sum} total conflicts"));
     let side = ui.available_width().clamp(280.0, 620.0);
-    let (rect, _) = ui.allocate_exact_size(Vec2::new(side, side), Sense::hover());
+    let (rect, response) = ui.allocate_exact_size(Vec2::new(side, side), sense);
     let painter = ui.painter_at(rect);
     let cell = rect.width() / size as f32;
 
@@ -668,6 +2436,8 @@ fn draw_board(ui: &mut egui::Ui, positions: &[u16], conflicts: &[u32], conflicts
             );
         }
     }
+
+    Some(response)
 }
 
 fn draw_board_cells(painter: &egui::Painter, rect: Rect, size: usize, cell: f32) {
@@ -718,91 +2488,396 @@ fn queen_color(conflicts: u32) -> Color32 {
     }
 }
 
-fn draw_charts(ui: &mut egui::Ui, snapshots: &[EpochSnapshot]) {
-    if snapshots.is_empty() {
-        ui.label("Charts appear after the first epoch snapshot.");
+// Brighter cells hold queens in more of the population. Columns run left to right and
+// rows top to bottom, as on the board.
+fn draw_population_heatmap(ui: &mut egui::Ui, summary: &PopulationSummary, side: f32) {
+    let (rect, response) = ui.allocate_exact_size(Vec2::splat(side), Sense::hover());
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(
+        rect,
+        egui::CornerRadius::ZERO,
+        Color32::from_rgb(16, 22, 31),
+    );
+
+    let resolution = summary.heatmap_resolution();
+    let max_count = summary.max_queen_count().max(1) as f32;
+    let cell = side / resolution.max(1) as f32;
+    for column in 0..resolution {
+        for row in 0..resolution {
+            let share = summary.queen_count(column, row) as f32 / max_count;
+            if share <= 0.0 {
+                continue;
+            }
+            let cell_rect = Rect::from_min_size(
+                Pos2::new(
+                    rect.left() + column as f32 * cell,
+                    rect.top() + row as f32 * cell,
+                ),
+                Vec2::splat(cell),
+            );
+            painter.rect_filled(cell_rect, egui::CornerRadius::ZERO, heat_color(share));
+        }
+    }
+
+    if let Some(position) = response.hover_pos() {
+        let column = (((position.x - rect.left()) / cell) as usize).min(resolution - 1);
+        let row = (((position.y - rect.top()) / cell) as usize).min(resolution - 1);
+        let board_cells = summary.board_size().div_ceil(resolution);
+        let text = if board_cells > 1 {
+            format!(
+                "columns {}-{}, rows {}-{}: {} queens",
+                column * board_cells,
+                ((column + 1) * board_cells).min(summary.board_size()) - 1,
+                row * board_cells,
+                ((row + 1) * board_cells).min(summary.board_size()) - 1,
+                summary.queen_count(column, row)
+            )
+        } else {
+            format!(
+                "column {column}, row {row}: {} of {} chromosomes",
+                summary.queen_count(column, row),
+                summary.population_size()
+            )
+        };
+        response.on_hover_text(text);
+    }
+}
+
+fn heat_color(share: f32) -> Color32 {
+    let share = share.clamp(0.0, 1.0);
+    Color32::from_rgb(
+        (40.0 + 215.0 * share) as u8,
+        (60.0 + 150.0 * share * share) as u8,
+        (120.0 - 60.0 * share) as u8,
+    )
+}
+
+// Each dot is a sampled chromosome; nearby dots differ in few columns. Green dots have the
+// fewest conflicts in the sample and red ones the most.
+fn draw_population_projection(ui: &mut egui::Ui, summary: &PopulationSummary, side: f32) {
+    let (rect, _) = ui.allocate_exact_size(Vec2::splat(side), Sense::hover());
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(
+        rect,
+        egui::CornerRadius::ZERO,
+        Color32::from_rgb(16, 22, 31),
+    );
+
+    let points = summary.projection();
+    if points.is_empty() {
+        return;
+    }
+    let bounds = |value: fn(&ga::population::ProjectedChromosome) -> f32| {
+        points
+            .iter()
+            .map(value)
+            .fold((f32::MAX, f32::MIN), |(low, high), value| {
+                (low.min(value), high.max(value))
+            })
+    };
+    let (min_x, max_x) = bounds(|point| point.x);
+    let (min_y, max_y) = bounds(|point| point.y);
+    let span = (max_x - min_x).max(max_y - min_y).max(f32::EPSILON);
+    let (min_conflicts, max_conflicts) = points.iter().fold((u32::MAX, 0), |(low, high), point| {
+        (low.min(point.conflicts_sum), high.max(point.conflicts_sum))
+    });
+    let plot_rect = rect.shrink(10.0);
+
+    for point in points {
+        let position = Pos2::new(
+            plot_rect.left() + plot_rect.width() * (point.x - min_x) / span,
+            plot_rect.top() + plot_rect.height() * (point.y - min_y) / span,
+        );
+        let badness = (point.conflicts_sum - min_conflicts) as f32
+            / (max_conflicts - min_conflicts).max(1) as f32;
+        let color = Color32::from_rgb(
+            (95.0 + 150.0 * badness) as u8,
+            (220.0 - 125.0 * badness) as u8,
+            (140.0 - 35.0 * badness) as u8,
+        );
+        painter.circle_filled(position, 3.0, color);
+    }
+}
+
+fn draw_conflict_histogram(ui: &mut egui::Ui, summary: &PopulationSummary, height: f32) {
+    let histogram = summary.conflict_histogram();
+    let (Some(&(min_conflicts, _)), Some(&(max_conflicts, _))) =
+        (histogram.first(), histogram.last())
+    else {
         return;
+    };
+
+    let bin_width = (max_conflicts - min_conflicts + 1).div_ceil(HISTOGRAM_MAX_BINS);
+    let bin_count = ((max_conflicts - min_conflicts) / bin_width + 1) as usize;
+    let mut bins = vec![0u32; bin_count];
+    for &(conflicts_sum, count) in histogram {
+        bins[((conflicts_sum - min_conflicts) / bin_width) as usize] += count;
     }
+    let max_bin = bins.iter().copied().max().unwrap_or(1).max(1) as f32;
 
-    let conflicts = vec![
-        ChartSeries {
-            label: "Best conflicts",
-            color: Color32::from_rgb(95, 220, 140),
-            values: snapshots
-                .iter()
-                .map(|snapshot| {
-                    (
-                        snapshot.metrics().epoch(),
-                        snapshot.metrics().best_conflicts_sum() as f32,
-                    )
-                })
-                .collect(),
-        },
-        ChartSeries {
-            label: "Average conflicts",
-            color: Color32::from_rgb(110, 190, 255),
-            values: snapshots
-                .iter()
-                .map(|snapshot| {
-                    (
-                        snapshot.metrics().epoch(),
-                        snapshot.metrics().average_conflicts_sum(),
-                    )
-                })
-                .collect(),
-        },
-    ];
-    draw_chart(ui, "Conflict history", &conflicts, 170.0);
-
-    let rates = vec![
-        ChartSeries {
-            label: "Diversity ratio",
-            color: Color32::from_rgb(245, 210, 95),
-            values: snapshots
-                .iter()
-                .map(|snapshot| {
-                    (
-                        snapshot.metrics().epoch(),
-                        snapshot.metrics().diversity_ratio(),
-                    )
-                })
-                .collect(),
-        },
-        ChartSeries {
-            label: "Mutation rate",
-            color: Color32::from_rgb(245, 120, 170),
-            values: snapshots
+    let (rect, response) =
+        ui.allocate_exact_size(Vec2::new(ui.available_width(), height), Sense::hover());
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(
+        rect,
+        egui::CornerRadius::ZERO,
+        Color32::from_rgb(16, 22, 31),
+    );
+    let plot_rect = rect.shrink2(Vec2::new(42.0, 22.0));
+    let bar_width = plot_rect.width() / bin_count as f32;
+    let text_color = Color32::from_rgb(150, 165, 180);
+
+    for (index, &count) in bins.iter().enumerate() {
+        let bar_height = plot_rect.height() * count as f32 / max_bin;
+        let bar = Rect::from_min_max(
+            Pos2::new(
+                plot_rect.left() + index as f32 * bar_width + 1.0,
+                plot_rect.bottom() - bar_height,
+            ),
+            Pos2::new(
+                plot_rect.left() + (index + 1) as f32 * bar_width - 1.0,
+                plot_rect.bottom(),
+            ),
+        );
+        painter.rect_filled(
+            bar,
+            egui::CornerRadius::ZERO,
+            Color32::from_rgb(110, 190, 255),
+        );
+    }
+    painter.text(
+        Pos2::new(plot_rect.left(), plot_rect.bottom() + 6.0),
+        Align2::LEFT_TOP,
+        min_conflicts.to_string(),
+        FontId::monospace(11.0),
+        text_color,
+    );
+    painter.text(
+        Pos2::new(plot_rect.right(), plot_rect.bottom() + 6.0),
+        Align2::RIGHT_TOP,
+        max_conflicts.to_string(),
+        FontId::monospace(11.0),
+        text_color,
+    );
+    painter.text(
+        Pos2::new(plot_rect.left() - 6.0, plot_rect.top()),
+        Align2::RIGHT_CENTER,
+        (max_bin as u32).to_string(),
+        FontId::monospace(11.0),
+        text_color,
+    );
+
+    if let Some(position) = response.hover_pos()
+        && plot_rect.contains(position)
+    {
+        let index = (((position.x - plot_rect.left()) / bar_width) as usize).min(bin_count - 1);
+        let low = min_conflicts + index as u32 * bin_width;
+        let range = if bin_width > 1 {
+            format!("{low}-{}", low + bin_width - 1)
+        } else {
+            low.to_string()
+        };
+        response.on_hover_text(format!(
+            "{} chromosomes with {range} conflicts",
+            bins[index]
+        ));
+    }
+}
+
+fn run_charts(snapshots: &[EpochSnapshot]) -> Vec<ChartPanel> {
+    // Epochs without a value, such as diversity measures a replayed log skipped, leave
+    // a gap in the series.
+    let series =
+        |label: &str, color: [u8; 3], metric: fn(&ga::EpochMetrics) -> Option<f32>| ChartSeries {
+            label: label.to_owned(),
+            color,
+            points: snapshots
                 .iter()
-                .map(|snapshot| {
-                    (
-                        snapshot.metrics().epoch(),
-                        snapshot.metrics().mutation_rate(),
-                    )
+                .filter_map(|snapshot| {
+                    metric(snapshot.metrics()).map(|value| (snapshot.metrics().epoch(), value))
                 })
                 .collect(),
+        };
+
+    vec![
+        ChartPanel {
+            chart: Chart {
+                title: "Conflict history".to_owned(),
+                series: vec![
+                    series("Best conflicts", [95, 220, 140], |metrics| {
+                        Some(metrics.best_conflicts_sum() as f32)
+                    }),
+                    series("Average conflicts", [110, 190, 255], |metrics| {
+                        Some(metrics.average_conflicts_sum())
+                    }),
+                ],
+            },
+            height: 170.0,
+            log_scalable: true,
         },
-        ChartSeries {
-            label: "Elite ratio",
-            color: Color32::from_rgb(160, 135, 255),
-            values: snapshots
-                .iter()
-                .map(|snapshot| (snapshot.metrics().epoch(), snapshot.metrics().elite_ratio()))
-                .collect(),
+        ChartPanel {
+            chart: Chart {
+                title: "Population ratios".to_owned(),
+                series: vec![
+                    series("Diversity ratio", [245, 210, 95], |metrics| {
+                        Some(metrics.diversity_ratio())
+                    }),
+                    series("Gene entropy", [95, 215, 215], |metrics| {
+                        metrics.gene_entropy()
+                    }),
+                    series("Pairwise distance", [150, 220, 90], |metrics| {
+                        metrics.mean_pairwise_distance()
+                    }),
+                    series("Distance to best", [235, 150, 90], |metrics| {
+                        metrics.mean_best_distance()
+                    }),
+                    series("Mutation rate", [245, 120, 170], |metrics| {
+                        Some(metrics.mutation_rate())
+                    }),
+                    series("Elite ratio", [160, 135, 255], |metrics| {
+                        Some(metrics.elite_ratio())
+                    }),
+                ],
+            },
+            height: 150.0,
+            log_scalable: false,
         },
-    ];
-    draw_chart(ui, "Population ratios", &rates, 150.0);
+    ]
+}
+
+// Overlays one metric from every compared run per chart, with each run's label as
+// the legend.
+fn comparison_charts(runs: &[ComparedRun]) -> Vec<ChartPanel> {
+    let overlay =
+        |title: &str, metric: fn(&ga::EpochMetrics) -> f32, height, log_scalable| ChartPanel {
+            chart: Chart {
+                title: title.to_owned(),
+                series: runs
+                    .iter()
+                    .map(|run| ChartSeries {
+                        label: run.label.clone(),
+                        color: [run.color.r(), run.color.g(), run.color.b()],
+                        points: run
+                            .snapshots
+                            .iter()
+                            .map(|snapshot| {
+                                (snapshot.metrics().epoch(), metric(snapshot.metrics()))
+                            })
+                            .collect(),
+                    })
+                    .collect(),
+            },
+            height,
+            log_scalable,
+        };
+
+    vec![
+        overlay(
+            "Best conflicts",
+            |metrics| metrics.best_conflicts_sum() as f32,
+            170.0,
+            true,
+        ),
+        overlay(
+            "Average conflicts",
+            |metrics| metrics.average_conflicts_sum(),
+            150.0,
+            true,
+        ),
+        overlay(
+            "Diversity ratio",
+            |metrics| metrics.diversity_ratio(),
+            150.0,
+            false,
+        ),
+    ]
 }
 
-fn draw_chart(ui: &mut egui::Ui, title: &str, series: &[ChartSeries], height: f32) {
+// Stagnation resets and the solved epoch, in `color` for compared runs.
+fn run_markers(snapshots: &[EpochSnapshot], color: Option<Color32>) -> Vec<ChartMarker> {
+    let mut markers = snapshots
+        .iter()
+        .filter(|snapshot| snapshot.metrics().stagnation_reset())
+        .map(|snapshot| ChartMarker {
+            epoch: snapshot.metrics().epoch(),
+            kind: ChartMarkerKind::StagnationReset,
+            color: color.unwrap_or(Color32::from_rgb(245, 170, 80)),
+        })
+        .collect::<Vec<_>>();
+    if let Some(snapshot) = snapshots
+        .iter()
+        .find(|snapshot| snapshot.best_conflicts_sum() == 0)
+    {
+        markers.push(ChartMarker {
+            epoch: snapshot.metrics().epoch(),
+            kind: ChartMarkerKind::Solved,
+            color: color.unwrap_or(Color32::from_rgb(105, 220, 145)),
+        });
+    }
+    markers
+}
+
+fn draw_charts(
+    ui: &mut egui::Ui,
+    view: &mut ChartView,
+    panels: &[ChartPanel],
+    markers: &[ChartMarker],
+    tooltip: impl Fn(&mut egui::Ui, u32),
+) {
+    let max_epoch = panels
+        .iter()
+        .flat_map(|panel| &panel.chart.series)
+        .filter_map(|series| series.points.last().map(|(epoch, _)| *epoch))
+        .max();
+    let Some(max_epoch) = max_epoch else {
+        ui.label("Charts appear after the first epoch snapshot.");
+        return;
+    };
+
+    ui.horizontal_wrapped(|ui| {
+        ui.checkbox(&mut view.log_scale, "Log scale conflicts");
+        if ui
+            .add_enabled(view.epoch_window.is_some(), egui::Button::new("Reset zoom"))
+            .clicked()
+        {
+            view.epoch_window = None;
+        }
+        ui.label(
+            RichText::new("Ctrl+scroll to zoom, drag to pan, double-click to reset")
+                .small()
+                .weak(),
+        );
+    });
+
+    let full_range = (0.0, max_epoch.max(1) as f32);
+    for panel in panels {
+        draw_chart(ui, view, panel, markers, full_range, &tooltip);
+    }
+}
+
+fn draw_chart(
+    ui: &mut egui::Ui,
+    view: &mut ChartView,
+    panel: &ChartPanel,
+    markers: &[ChartMarker],
+    full_range: (f32, f32),
+    tooltip: &impl Fn(&mut egui::Ui, u32),
+) {
     ui.add_space(4.0);
     ui.horizontal_wrapped(|ui| {
-        ui.label(RichText::new(title).strong());
-        for line in series {
-            ui.colored_label(line.color, line.label);
+        ui.label(RichText::new(&panel.chart.title).strong());
+        for line in &panel.chart.series {
+            ui.colored_label(series_color(line), &line.label);
         }
     });
 
-    let (rect, _) = ui.allocate_exact_size(Vec2::new(ui.available_width(), height), Sense::hover());
+    let (rect, response) = ui.allocate_exact_size(
+        Vec2::new(ui.available_width(), panel.height),
+        Sense::click_and_drag(),
+    );
+    let plot_rect = rect.shrink2(Vec2::new(42.0, 22.0));
+    view.handle_input(ui, &response, plot_rect, full_range);
+
     let painter = ui.painter_at(rect);
     painter.rect_filled(
         rect,
@@ -810,27 +2885,152 @@ fn draw_chart(ui: &mut egui::Ui, title: &str, series: &[ChartSeries], height: f3
         Color32::from_rgb(16, 22, 31),
     );
 
-    let plot_rect = rect.shrink2(Vec2::new(42.0, 22.0));
-    let max_epoch = series
-        .iter()
-        .flat_map(|line| line.values.iter().map(|(epoch, _)| *epoch))
-        .max()
-        .unwrap_or(1)
-        .max(1);
-    let max_value = series
+    let (start, end) = view.epoch_window.unwrap_or(full_range);
+    let max_value = panel
+        .chart
+        .series
         .iter()
-        .flat_map(|line| line.values.iter().map(|(_, value)| *value))
+        .flat_map(|line| &line.points)
+        .filter(|(epoch, _)| (start..=end).contains(&(*epoch as f32)))
+        .map(|(_, value)| *value)
         .fold(0.0_f32, f32::max)
         .max(1.0);
+    let scale = ChartScale {
+        rect: plot_rect,
+        start,
+        end,
+        max_value,
+        log: view.log_scale && panel.log_scalable,
+    };
 
-    draw_chart_grid(&painter, plot_rect, max_epoch, max_value);
+    draw_chart_grid(&painter, &scale);
 
-    for line in series {
-        draw_chart_series(&painter, plot_rect, max_epoch, max_value, line);
+    let plot_painter = ui.painter_at(plot_rect.expand(3.0));
+    for marker in markers {
+        draw_chart_marker(&plot_painter, &scale, marker);
     }
+    for line in &panel.chart.series {
+        draw_chart_series(&plot_painter, &scale, line);
+    }
+
+    if response.dragged() {
+        return;
+    }
+    let Some(pointer) = response.hover_pos() else {
+        return;
+    };
+    let hovered_epoch = scale.epoch_at(pointer.x);
+    let Some(epoch) = panel
+        .chart
+        .series
+        .iter()
+        .filter_map(|line| nearest_point(&line.points, hovered_epoch))
+        .map(|(epoch, _)| epoch)
+        .min_by(|one, two| {
+            (*one as f32 - hovered_epoch)
+                .abs()
+                .total_cmp(&(*two as f32 - hovered_epoch).abs())
+        })
+    else {
+        return;
+    };
+
+    let x = scale.to_pos(epoch, 0.0).x;
+    plot_painter.line_segment(
+        [
+            Pos2::new(x, plot_rect.top()),
+            Pos2::new(x, plot_rect.bottom()),
+        ],
+        Stroke::new(1.0, Color32::from_rgba_unmultiplied(220, 230, 240, 90)),
+    );
+    for line in &panel.chart.series {
+        if let Ok(index) = line
+            .points
+            .binary_search_by_key(&epoch, |(epoch, _)| *epoch)
+        {
+            let (_, value) = line.points[index];
+            plot_painter.circle_filled(scale.to_pos(epoch, value), 3.5, series_color(line));
+        }
+    }
+    response.on_hover_ui_at_pointer(|ui| tooltip(ui, epoch));
+}
+
+fn nearest_point(points: &[(u32, f32)], epoch: f32) -> Option<(u32, f32)> {
+    let index = points.partition_point(|(point_epoch, _)| (*point_epoch as f32) < epoch);
+    [index.checked_sub(1), Some(index)]
+        .into_iter()
+        .flatten()
+        .filter_map(|index| points.get(index).copied())
+        .min_by(|one, two| {
+            (one.0 as f32 - epoch)
+                .abs()
+                .total_cmp(&(two.0 as f32 - epoch).abs())
+        })
+}
+
+// Maps epochs and values into a chart's plot area for the visible epoch window.
+struct ChartScale {
+    rect: Rect,
+    start: f32,
+    end: f32,
+    max_value: f32,
+    log: bool,
+}
+
+impl ChartScale {
+    fn to_pos(&self, epoch: u32, value: f32) -> Pos2 {
+        let span = (self.end - self.start).max(f32::EPSILON);
+        let x = self.rect.left() + self.rect.width() * ((epoch as f32 - self.start) / span);
+        let share = if self.log {
+            value.max(0.0).ln_1p() / self.max_value.ln_1p()
+        } else {
+            value / self.max_value
+        };
+        let y = self.rect.bottom() - self.rect.height() * share.clamp(0.0, 1.0);
+        Pos2::new(x, y)
+    }
+
+    fn epoch_at(&self, x: f32) -> f32 {
+        self.start + (x - self.rect.left()) / self.rect.width() * (self.end - self.start)
+    }
+
+    // The value drawn at `share` of the plot height.
+    fn value_at(&self, share: f32) -> f32 {
+        if self.log {
+            (self.max_value.ln_1p() * share).exp_m1()
+        } else {
+            self.max_value * share
+        }
+    }
+}
+
+fn draw_sweep_bar(ui: &mut egui::Ui, share: f32, color: Color32, text: &str) {
+    let (rect, _) = ui.allocate_exact_size(Vec2::new(140.0, 16.0), Sense::hover());
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(
+        rect,
+        egui::CornerRadius::ZERO,
+        Color32::from_rgb(16, 22, 31),
+    );
+    let mut filled = rect;
+    filled.set_width(rect.width() * share.clamp(0.0, 1.0));
+    painter.rect_filled(filled, egui::CornerRadius::ZERO, color.gamma_multiply(0.7));
+    painter.text(
+        rect.left_center() + Vec2::new(4.0, 0.0),
+        Align2::LEFT_CENTER,
+        text,
+        FontId::monospace(11.0),
+        Color32::WHITE,
+    );
+}
+
+fn series_color(series: &ChartSeries) -> Color32 {
+    let [red, green, blue] = series.color;
+    Color32::from_rgb(red, green, blue)
 }
 
-fn draw_chart_grid(painter: &egui::Painter, rect: Rect, max_epoch: u32, max_value: f32) {
+fn draw_chart_grid(painter: &egui::Painter, scale: &ChartScale) {
+    let rect = scale.rect;
     let grid_stroke = Stroke::new(1.0, Color32::from_rgba_unmultiplied(180, 205, 220, 32));
     let text_color = Color32::from_rgb(150, 165, 180);
 
@@ -844,7 +3044,7 @@ fn draw_chart_grid(painter: &egui::Painter, rect: Rect, max_epoch: u32, max_valu
         painter.text(
             Pos2::new(rect.left() - 6.0, y),
             Align2::RIGHT_CENTER,
-            format!("{:.1}", max_value * t),
+            format!("{:.1}", scale.value_at(t)),
             FontId::monospace(11.0),
             text_color,
         );
@@ -860,34 +3060,70 @@ fn draw_chart_grid(painter: &egui::Painter, rect: Rect, max_epoch: u32, max_valu
         painter.text(
             Pos2::new(x, rect.bottom() + 6.0),
             Align2::CENTER_TOP,
-            format!("{}", (max_epoch as f32 * t).round() as u32),
+            format!(
+                "{}",
+                (scale.start + (scale.end - scale.start) * t).round() as u32
+            ),
             FontId::monospace(11.0),
             text_color,
         );
     }
 }
 
-fn draw_chart_series(
-    painter: &egui::Painter,
-    rect: Rect,
-    max_epoch: u32,
-    max_value: f32,
-    series: &ChartSeries,
-) {
-    let to_pos = |epoch: u32, value: f32| {
-        let x = rect.left() + rect.width() * (epoch as f32 / max_epoch as f32);
-        let y = rect.bottom() - rect.height() * (value / max_value).clamp(0.0, 1.0);
-        Pos2::new(x, y)
-    };
-    let stroke = Stroke::new(2.0, series.color);
+fn draw_chart_marker(painter: &egui::Painter, scale: &ChartScale, marker: &ChartMarker) {
+    let epoch = marker.epoch as f32;
+    if epoch < scale.start || epoch > scale.end {
+        return;
+    }
 
-    if let Some(&(epoch, value)) = series.values.first() {
-        painter.circle_filled(to_pos(epoch, value), 2.5, series.color);
+    let x = scale.to_pos(marker.epoch, 0.0).x;
+    let top = Pos2::new(x, scale.rect.top());
+    let bottom = Pos2::new(x, scale.rect.bottom());
+    match marker.kind {
+        ChartMarkerKind::StagnationReset => {
+            painter.add(egui::Shape::dashed_line(
+                &[top, bottom],
+                Stroke::new(1.0, marker.color.gamma_multiply(0.7)),
+                4.0,
+                4.0,
+            ));
+        }
+        ChartMarkerKind::Solved => {
+            painter.line_segment([top, bottom], Stroke::new(1.5, marker.color));
+            painter.text(
+                top + Vec2::new(4.0, 2.0),
+                Align2::LEFT_TOP,
+                "solved",
+                FontId::proportional(11.0),
+                marker.color,
+            );
+        }
     }
+}
 
-    for pair in series.values.windows(2) {
-        let start = to_pos(pair[0].0, pair[0].1);
-        let end = to_pos(pair[1].0, pair[1].1);
-        painter.line_segment([start, end], stroke);
+fn draw_chart_series(painter: &egui::Painter, scale: &ChartScale, series: &ChartSeries) {
+    let color = series_color(series);
+
+    // Only the points in the visible window, plus one on each side so lines reach the edges.
+    let first = series
+        .points
+        .partition_point(|(epoch, _)| (*epoch as f32) < scale.start)
+        .saturating_sub(1);
+    let last = series
+        .points
+        .partition_point(|(epoch, _)| (*epoch as f32) <= scale.end)
+        .min(series.points.len().saturating_sub(1));
+    let Some(visible) = series.points.get(first..=last) else {
+        return;
+    };
+
+    if let [(epoch, value)] = visible {
+        painter.circle_filled(scale.to_pos(*epoch, *value), 2.5, color);
+        return;
     }
+    let points = visible
+        .iter()
+        .map(|&(epoch, value)| scale.to_pos(epoch, value))
+        .collect();
+    painter.add(egui::Shape::line(points, Stroke::new(2.0, color)));
 }
@@ -0,0 +1,481 @@
+use std::{fmt, fs, path::Path, str::FromStr};
+
+use serde_json::{Map, Value, json};
+
+use super::GuiConfig;
+use crate::{
+    ga::{
+        DiversityMeasure, RestartKind, SelectionStrategy, control::ParameterControl,
+        run_log::json_f32,
+    },
+    symmetry::SymmetryConstraint,
+};
+
+const DIVERSITY_MEASURES: [DiversityMeasure; 4] = [
+    DiversityMeasure::UniqueRatio,
+    DiversityMeasure::GeneEntropy,
+    DiversityMeasure::PairwiseDistance,
+    DiversityMeasure::BestDistance,
+];
+const PARAMETER_CONTROLS: [ParameterControl; 4] = [
+    ParameterControl::Stagnation,
+    ParameterControl::OneFifthRule,
+    ParameterControl::SelfAdaptive,
+    ParameterControl::OperatorBandit,
+];
+const RESTART_KINDS: [RestartKind; 3] = [
+    RestartKind::Random,
+    RestartKind::PerturbBest,
+    RestartKind::Constructive,
+];
+const SELECTION_STRATEGIES: [SelectionStrategy; 2] =
+    [SelectionStrategy::Roulette, SelectionStrategy::Tournament];
+const SYMMETRY_CONSTRAINTS: [SymmetryConstraint; 4] = [
+    SymmetryConstraint::None,
+    SymmetryConstraint::Rotation180,
+    SymmetryConstraint::Rotation90,
+    SymmetryConstraint::Reflection,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum BuiltinPreset {
+    FastDemo,
+    LargeBoard,
+    ExplorationHeavy,
+    LocalSearchHeavy,
+}
+
+impl BuiltinPreset {
+    pub(super) const ALL: [Self; 4] = [
+        Self::FastDemo,
+        Self::LargeBoard,
+        Self::ExplorationHeavy,
+        Self::LocalSearchHeavy,
+    ];
+
+    pub(super) fn name(self) -> &'static str {
+        match self {
+            Self::FastDemo => "Fast demo",
+            Self::LargeBoard => "Large board",
+            Self::ExplorationHeavy => "Exploration heavy",
+            Self::LocalSearchHeavy => "Local search heavy",
+        }
+    }
+
+    // Presets tune the search; the seed and symmetry constraint are left as they are.
+    pub(super) fn apply(self, config: &mut GuiConfig) {
+        match self {
+            Self::FastDemo => {
+                config.board_size = 8;
+                config.population_size = 256;
+                config.max_epochs = 250;
+                config.mutation_rate = 0.12;
+                config.elite_ratio = 0.15;
+                config.offspring_ratio = 0.25;
+                config.selection_strategy = SelectionStrategy::Tournament;
+                config.tournament_size = 3;
+                config.local_search_rate = 0.05;
+                config.local_search_attempts = 8;
+            }
+            Self::LargeBoard => {
+                config.board_size = 128;
+                config.population_size = 2_000;
+                config.max_epochs = 20_000;
+                config.mutation_rate = 0.05;
+                config.elite_ratio = 0.1;
+                config.offspring_ratio = 0.3;
+                config.min_diversity_ratio = 0.25;
+                config.selection_strategy = SelectionStrategy::Tournament;
+                config.tournament_size = 4;
+                config.local_search_rate = 0.25;
+                config.local_search_attempts = 64;
+            }
+            Self::ExplorationHeavy => {
+                config.mutation_rate = 0.3;
+                config.elite_ratio = 0.02;
+                config.offspring_ratio = 0.6;
+                config.min_diversity_ratio = 0.6;
+                config.selection_strategy = SelectionStrategy::Roulette;
+                config.local_search_rate = 0.0;
+            }
+            Self::LocalSearchHeavy => {
+                config.mutation_rate = 0.05;
+                config.elite_ratio = 0.1;
+                config.offspring_ratio = 0.2;
+                config.selection_strategy = SelectionStrategy::Tournament;
+                config.tournament_size = 3;
+                config.local_search_rate = 0.6;
+                config.local_search_attempts = 64;
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+pub(super) struct CustomPreset {
+    pub(super) name: String,
+    pub(super) config: GuiConfig,
+}
+
+// The solver keys of the CLI `--config` format, as written by `--dump-config`.
+pub(super) fn config_values(config: &GuiConfig) -> Map<String, Value> {
+    let mut values = Map::new();
+    values.insert("seed".to_owned(), json!(config.seed));
+    values.insert("board_size".to_owned(), json!(config.board_size));
+    values.insert(
+        "target_population".to_owned(),
+        json!(config.population_size),
+    );
+    values.insert("max_epochs".to_owned(), json!(config.max_epochs));
+    if let Some(max_evaluations) = config.max_evaluations {
+        values.insert("max_evaluations".to_owned(), json!(max_evaluations));
+    }
+    values.insert(
+        "mutation_rate".to_owned(),
+        json!(json_f32(config.mutation_rate)),
+    );
+    values.insert(
+        "elite_ratio".to_owned(),
+        json!(json_f32(config.elite_ratio)),
+    );
+    values.insert(
+        "offspring_ratio".to_owned(),
+        json!(json_f32(config.offspring_ratio)),
+    );
+    values.insert(
+        "min_diversity_ratio".to_owned(),
+        json!(json_f32(config.min_diversity_ratio)),
+    );
+    values.insert(
+        "diversity_measure".to_owned(),
+        json!(config.diversity_measure.to_string()),
+    );
+    values.insert(
+        "selection_strategy".to_owned(),
+        json!(config.selection_strategy.to_string()),
+    );
+    values.insert("tournament_size".to_owned(), json!(config.tournament_size));
+    values.insert(
+        "local_search_rate".to_owned(),
+        json!(json_f32(config.local_search_rate)),
+    );
+    values.insert(
+        "local_search_attempts".to_owned(),
+        json!(config.local_search_attempts),
+    );
+    values.insert("symmetry".to_owned(), json!(config.symmetry.to_string()));
+    if let Some(restart_interval) = config.restart_interval {
+        values.insert("restart_interval".to_owned(), json!(restart_interval));
+    }
+    values.insert(
+        "restart_keep_ratio".to_owned(),
+        json!(json_f32(config.restart_keep_ratio)),
+    );
+    values.insert(
+        "restart_kind".to_owned(),
+        json!(config.restart_kind.to_string()),
+    );
+    values.insert("adaptive".to_owned(), json!(config.adaptive));
+    values.insert(
+        "parameter_control".to_owned(),
+        json!(config.parameter_control.to_string()),
+    );
+    values
+}
+
+// Applies every solver key in `values` and returns the keys the GUI has no setting
+// for (output options such as `runs` or `json`), so they can be reported.
+pub(super) fn apply_config_values(
+    config: &mut GuiConfig,
+    values: &Map<String, Value>,
+) -> Result<Vec<String>, String> {
+    let mut updated = config.clone();
+    // Configs leave these keys out when there is no budget and for the automatic interval.
+    updated.max_evaluations = None;
+    updated.restart_interval = None;
+    let mut ignored = Vec::new();
+
+    for (key, value) in values {
+        if value.is_null() {
+            continue;
+        }
+        let raw_value = match value {
+            Value::String(value) => value.clone(),
+            Value::Number(_) | Value::Bool(_) => value.to_string(),
+            _ => {
+                return Err(format!(
+                    "config key `{key}` must be a string, number, or boolean"
+                ));
+            }
+        };
+        let raw_value = raw_value.as_str();
+
+        let applied = match key.as_str() {
+            "seed" => parse_number(raw_value).map(|value| updated.seed = value),
+            "board_size" => parse_positive(raw_value).map(|value| updated.board_size = value),
+            "target_population" => {
+                parse_positive(raw_value).map(|value| updated.population_size = value)
+            }
+            "max_epochs" => parse_positive(raw_value).map(|value| updated.max_epochs = value),
+            "max_evaluations" => {
+                parse_positive(raw_value).map(|value| updated.max_evaluations = Some(value))
+            }
+            "mutation_rate" => {
+                parse_unit_interval(raw_value).map(|value| updated.mutation_rate = value)
+            }
+            "elite_ratio" => {
+                parse_unit_interval(raw_value).map(|value| updated.elite_ratio = value)
+            }
+            "offspring_ratio" => {
+                parse_unit_interval(raw_value).map(|value| updated.offspring_ratio = value)
+            }
+            "min_diversity_ratio" => {
+                parse_unit_interval(raw_value).map(|value| updated.min_diversity_ratio = value)
+            }
+            "diversity_measure" => parse_named(raw_value, &DIVERSITY_MEASURES)
+                .map(|value| updated.diversity_measure = value),
+            "selection_strategy" => parse_named(raw_value, &SELECTION_STRATEGIES)
+                .map(|value| updated.selection_strategy = value),
+            "tournament_size" => {
+                parse_positive(raw_value).map(|value| updated.tournament_size = value)
+            }
+            "local_search_rate" => {
+                parse_unit_interval(raw_value).map(|value| updated.local_search_rate = value)
+            }
+            "local_search_attempts" => {
+                parse_number(raw_value).map(|value| updated.local_search_attempts = value)
+            }
+            "symmetry" => {
+                parse_named(raw_value, &SYMMETRY_CONSTRAINTS).map(|value| updated.symmetry = value)
+            }
+            "restart_interval" => {
+                parse_positive(raw_value).map(|value| updated.restart_interval = Some(value))
+            }
+            "restart_keep_ratio" => {
+                parse_unit_interval(raw_value).map(|value| updated.restart_keep_ratio = value)
+            }
+            "restart_kind" => {
+                parse_named(raw_value, &RESTART_KINDS).map(|value| updated.restart_kind = value)
+            }
+            "adaptive" => parse_number(raw_value).map(|value| updated.adaptive = value),
+            "parameter_control" => parse_named(raw_value, &PARAMETER_CONTROLS)
+                .map(|value| updated.parameter_control = value),
+            _ => {
+                ignored.push(key.clone());
+                Ok(())
+            }
+        };
+        applied.map_err(|error| format!("invalid config `{key}`: {error}"))?;
+    }
+
+    *config = updated;
+    Ok(ignored)
+}
+
+// Keys missing from the file keep their current values.
+pub(super) fn import_config(path: &Path, config: &mut GuiConfig) -> Result<Vec<String>, String> {
+    let contents = fs::read_to_string(path)
+        .map_err(|error| format!("failed to read config file {}: {error}", path.display()))?;
+    let is_json = match path.extension().and_then(|extension| extension.to_str()) {
+        Some(extension) => extension.eq_ignore_ascii_case("json"),
+        None => contents.trim_start().starts_with('{'),
+    };
+
+    let values = if is_json {
+        serde_json::from_str(&contents)
+            .map_err(|error| format!("failed to parse JSON config {}: {error}", path.display()))?
+    } else {
+        toml::from_str(&contents)
+            .map_err(|error| format!("failed to parse TOML config {}: {error}", path.display()))?
+    };
+    apply_config_values(config, &values)
+}
+
+pub(super) fn export_config(path: &Path, config: &GuiConfig) -> Result<(), String> {
+    let values = config_values(config);
+    let is_json = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("json"));
+    let contents = if is_json {
+        serde_json::to_string_pretty(&values)
+            .map_err(|error| format!("failed to write JSON config: {error}"))?
+    } else {
+        toml::to_string(&values).map_err(|error| format!("failed to write TOML config: {error}"))?
+    };
+
+    fs::write(path, contents)
+        .map_err(|error| format!("failed to write config file {}: {error}", path.display()))
+}
+
+// The last-used config and custom presets are persisted as TOML in the config file
+// format, with one table per preset name.
+pub(super) fn config_to_toml(config: &GuiConfig) -> String {
+    toml::to_string(&config_values(config)).unwrap_or_default()
+}
+
+pub(super) fn config_from_toml(contents: &str) -> Result<GuiConfig, String> {
+    let values = toml::from_str(contents).map_err(|error| error.to_string())?;
+    let mut config = GuiConfig::default();
+    apply_config_values(&mut config, &values)?;
+    config.normalize();
+    Ok(config)
+}
+
+pub(super) fn presets_to_toml(presets: &[CustomPreset]) -> String {
+    let tables = presets
+        .iter()
+        .map(|preset| {
+            (
+                preset.name.clone(),
+                Value::Object(config_values(&preset.config)),
+            )
+        })
+        .collect::<Map<_, _>>();
+    toml::to_string(&tables).unwrap_or_default()
+}
+
+pub(super) fn presets_from_toml(contents: &str) -> Result<Vec<CustomPreset>, String> {
+    let tables: Map<String, Value> = toml::from_str(contents).map_err(|error| error.to_string())?;
+    tables
+        .into_iter()
+        .map(|(name, values)| {
+            let Value::Object(values) = values else {
+                return Err(format!("preset `{name}` must be a table"));
+            };
+            let mut config = GuiConfig::default();
+            apply_config_values(&mut config, &values)?;
+            config.normalize();
+            Ok(CustomPreset { name, config })
+        })
+        .collect()
+}
+
+fn parse_number<T: FromStr<Err: fmt::Display>>(raw_value: &str) -> Result<T, String> {
+    raw_value
+        .parse::<T>()
+        .map_err(|err| format!("invalid value `{raw_value}`: {err}"))
+}
+
+fn parse_positive<T: FromStr<Err: fmt::Display> + Default + PartialEq>(
+    raw_value: &str,
+) -> Result<T, String> {
+    let value = parse_number(raw_value)?;
+    if value == T::default() {
+        return Err("must be greater than 0".to_owned());
+    }
+    Ok(value)
+}
+
+fn parse_unit_interval(raw_value: &str) -> Result<f32, String> {
+    let value = parse_number::<f32>(raw_value)?;
+    if !(0.0..=1.0).contains(&value) {
+        return Err(format!("`{raw_value}` must be between 0.0 and 1.0"));
+    }
+    Ok(value)
+}
+
+fn parse_named<T: Copy + fmt::Display>(raw_value: &str, options: &[T]) -> Result<T, String> {
+    options
+        .iter()
+        .copied()
+        .find(|option| option.to_string().eq_ignore_ascii_case(raw_value))
+        .ok_or_else(|| {
+            let names = options
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("must be one of: {names}")
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{
+        BuiltinPreset, CustomPreset, apply_config_values, config_from_toml, config_to_toml,
+        presets_from_toml, presets_to_toml,
+    };
+    use crate::{
+        ga::{RestartKind, SelectionStrategy, control::ParameterControl},
+        gui::GuiConfig,
+        symmetry::SymmetryConstraint,
+    };
+
+    #[test]
+    fn test_config_round_trips_through_toml() {
+        let mut config = GuiConfig::default();
+        BuiltinPreset::ExplorationHeavy.apply(&mut config);
+        config.seed = 7;
+        config.symmetry = SymmetryConstraint::Rotation90;
+        config.max_evaluations = Some(250_000);
+        config.restart_interval = Some(75);
+        config.restart_kind = RestartKind::Constructive;
+        config.adaptive = false;
+        config.parameter_control = ParameterControl::SelfAdaptive;
+
+        let restored = config_from_toml(&config_to_toml(&config)).unwrap();
+
+        assert_eq!(config_to_toml(&restored), config_to_toml(&config));
+        assert_eq!(restored.mutation_rate, 0.3);
+        assert_eq!(restored.selection_strategy, SelectionStrategy::Roulette);
+        assert_eq!(restored.symmetry, SymmetryConstraint::Rotation90);
+        assert_eq!(restored.max_evaluations, Some(250_000));
+        assert_eq!(restored.restart_interval, Some(75));
+        assert_eq!(restored.restart_kind, RestartKind::Constructive);
+        assert!(!restored.adaptive);
+        assert_eq!(restored.parameter_control, ParameterControl::SelfAdaptive);
+    }
+
+    #[test]
+    fn test_cli_config_values_apply_and_report_other_keys() {
+        let values = json!({
+            "board_size": 12,
+            "mutation_rate": "0.25",
+            "selection_strategy": "ROULETTE",
+            "runs": 3,
+            "json": true,
+        });
+        let mut config = GuiConfig::default();
+
+        let ignored = apply_config_values(&mut config, values.as_object().unwrap()).unwrap();
+
+        assert_eq!(config.board_size, 12);
+        assert_eq!(config.mutation_rate, 0.25);
+        assert_eq!(config.selection_strategy, SelectionStrategy::Roulette);
+        assert_eq!(ignored, ["json", "runs"]);
+
+        let invalid = json!({ "board_size": 4, "elite_ratio": 1.5 });
+        let error = apply_config_values(&mut config, invalid.as_object().unwrap()).unwrap_err();
+        assert!(error.contains("invalid config `elite_ratio`"));
+        assert_eq!(config.board_size, 12);
+    }
+
+    #[test]
+    fn test_custom_presets_round_trip_by_name() {
+        let mut large = GuiConfig::default();
+        BuiltinPreset::LargeBoard.apply(&mut large);
+        let presets = vec![
+            CustomPreset {
+                name: "My large board".to_owned(),
+                config: large,
+            },
+            CustomPreset {
+                name: "defaults".to_owned(),
+                config: GuiConfig::default(),
+            },
+        ];
+
+        let restored = presets_from_toml(&presets_to_toml(&presets)).unwrap();
+
+        assert_eq!(restored.len(), 2);
+        let restored_large = restored
+            .iter()
+            .find(|preset| preset.name == "My large board")
+            .unwrap();
+        assert_eq!(restored_large.config.board_size, 128);
+        assert_eq!(restored_large.config.local_search_attempts, 64);
+    }
+}
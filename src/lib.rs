@@ -1,4 +1,9 @@
+pub mod board;
+pub mod exact;
 pub mod ga;
 #[cfg(feature = "gui")]
 pub mod gui;
+pub mod portfolio;
+pub mod render;
+pub mod symmetry;
 pub mod tui;
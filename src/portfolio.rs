@@ -0,0 +1,611 @@
+use std::{
+    fmt,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, RecvTimeoutError},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use rand::{Rng, RngExt, SeedableRng, rngs::StdRng};
+
+use crate::{
+    exact,
+    ga::{self, GaConfig, GaConfigError, chromosome::Chromosome},
+    symmetry::SymmetryConstraint,
+};
+
+pub const DEFAULT_BACKTRACKING_MAX_SIZE: u16 = 32;
+pub const STRATEGIES: [Strategy; 4] = [
+    Strategy::GeneticAlgorithm,
+    Strategy::MinConflicts,
+    Strategy::Annealing,
+    Strategy::Backtracking,
+];
+const CANCEL_CHECK_INTERVAL: u64 = 256;
+const MIN_CONFLICTS_STEPS_PER_QUEEN: u64 = 50;
+const ANNEALING_START_TEMPERATURE: f64 = 2.0;
+const ANNEALING_MIN_TEMPERATURE: f64 = 0.05;
+const ANNEALING_COOLING_FACTOR: f64 = 0.999;
+
+// One solver the portfolio can race against the others.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    GeneticAlgorithm,
+    // Repairs a random board by moving a conflicted queen to its least attacked row,
+    // restarting after a fixed number of moves per queen.
+    MinConflicts,
+    // Swaps pairs of queens, accepting worse boards with a probability that cools
+    // geometrically and reheats once it gets too cold.
+    Annealing,
+    // Exact search for the first solution; only tried up to `backtracking_max_size`.
+    Backtracking,
+}
+
+impl fmt::Display for Strategy {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::GeneticAlgorithm => formatter.write_str("ga"),
+            Self::MinConflicts => formatter.write_str("min-conflicts"),
+            Self::Annealing => formatter.write_str("annealing"),
+            Self::Backtracking => formatter.write_str("backtracking"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StrategyStatus {
+    Solved,
+    // Cancelled because another strategy won or the time budget ran out.
+    Stopped,
+    // Gave up on its own: the GA ran out of epochs or evaluations, or the exact search
+    // proved there is no solution.
+    Exhausted,
+    // Not started because it cannot handle the board size or symmetry constraint.
+    Skipped,
+}
+
+impl fmt::Display for StrategyStatus {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Solved => formatter.write_str("solved"),
+            Self::Stopped => formatter.write_str("stopped"),
+            Self::Exhausted => formatter.write_str("exhausted"),
+            Self::Skipped => formatter.write_str("skipped"),
+        }
+    }
+}
+
+// The board size, seed, and symmetry constraint come from the GA config, which also
+// configures the GA strategy itself.
+#[derive(Debug, Clone)]
+pub struct PortfolioConfig {
+    pub ga: GaConfig,
+    pub strategies: Vec<Strategy>,
+    pub time_limit: Option<Duration>,
+    pub backtracking_max_size: u16,
+}
+
+impl PortfolioConfig {
+    pub fn new(ga: GaConfig) -> Self {
+        Self {
+            ga,
+            strategies: STRATEGIES.to_vec(),
+            time_limit: None,
+            backtracking_max_size: DEFAULT_BACKTRACKING_MAX_SIZE,
+        }
+    }
+
+    pub fn with_strategies(mut self, strategies: &[Strategy]) -> Self {
+        self.strategies = strategies.to_vec();
+        self
+    }
+
+    pub fn with_time_limit(mut self, time_limit: Option<Duration>) -> Self {
+        self.time_limit = time_limit;
+        self
+    }
+
+    pub fn with_backtracking_max_size(mut self, backtracking_max_size: u16) -> Self {
+        self.backtracking_max_size = backtracking_max_size;
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct StrategyReport {
+    pub strategy: Strategy,
+    pub status: StrategyStatus,
+    pub elapsed: Duration,
+    // GA epochs, min-conflicts moves, annealing swaps, or backtracking nodes.
+    pub steps: u64,
+    pub solution: Option<Chromosome>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PortfolioOutcome {
+    pub winner: Option<Strategy>,
+    pub timed_out: bool,
+    pub elapsed: Duration,
+    // One report per configured strategy, in the configured order.
+    pub reports: Vec<StrategyReport>,
+}
+
+impl PortfolioOutcome {
+    pub fn solution(&self) -> Option<&Chromosome> {
+        let winner = self.winner?;
+        self.reports
+            .iter()
+            .find(|report| report.strategy == winner)
+            .and_then(|report| report.solution.as_ref())
+    }
+}
+
+// Runs every configured strategy on its own thread until one solves the board, all of them
+// give up, or the time limit passes. The first solution reported wins and cancels the rest
+// through a shared flag, the same way the GUI cancels its solver thread.
+pub fn run_portfolio(config: &PortfolioConfig) -> Result<PortfolioOutcome, GaConfigError> {
+    let started_at = Instant::now();
+    let (sender, receiver) = mpsc::channel();
+    let cancel = Arc::new(AtomicBool::new(false));
+    let mut reports = Vec::with_capacity(config.strategies.len());
+    let mut running = 0;
+
+    for (index, &strategy) in config.strategies.iter().enumerate() {
+        if config.strategies[..index].contains(&strategy) {
+            continue;
+        }
+        if !supports(config, strategy) {
+            reports.push(StrategyReport {
+                strategy,
+                status: StrategyStatus::Skipped,
+                elapsed: Duration::ZERO,
+                steps: 0,
+                solution: None,
+            });
+            continue;
+        }
+
+        let worker: Box<dyn FnOnce(&AtomicBool) -> StrategyReport + Send> = match strategy {
+            Strategy::GeneticAlgorithm => {
+                let mut algorithm = ga::build_genetic_algorithm(config.ga)?;
+                algorithm.clear_observers();
+                Box::new(move |cancel| run_genetic_algorithm(&mut algorithm, cancel))
+            }
+            Strategy::MinConflicts => {
+                let size = config.ga.size;
+                let rng = StdRng::seed_from_u64(config.ga.seed.wrapping_add(1));
+                Box::new(move |cancel| run_min_conflicts(size, rng, cancel))
+            }
+            Strategy::Annealing => {
+                let size = config.ga.size;
+                let rng = StdRng::seed_from_u64(config.ga.seed.wrapping_add(2));
+                Box::new(move |cancel| run_annealing(size, rng, cancel))
+            }
+            Strategy::Backtracking => {
+                let size = config.ga.size;
+                let symmetry = config.ga.symmetry;
+                Box::new(move |cancel| run_backtracking(size, symmetry, cancel))
+            }
+        };
+
+        let sender = sender.clone();
+        let cancel_worker = Arc::clone(&cancel);
+        running += 1;
+        thread::spawn(move || {
+            let mut report = worker(&cancel_worker);
+            report.elapsed = started_at.elapsed();
+            let _ = sender.send(report);
+        });
+    }
+    drop(sender);
+
+    let deadline = config.time_limit.map(|time_limit| started_at + time_limit);
+    let mut winner = None;
+    let mut timed_out = false;
+    while running > 0 {
+        let message = match deadline.filter(|_| !timed_out) {
+            Some(deadline) => {
+                receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+            }
+            None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        match message {
+            Ok(report) => {
+                running -= 1;
+                if winner.is_none() && report.status == StrategyStatus::Solved {
+                    winner = Some(report.strategy);
+                    cancel.store(true, Ordering::Relaxed);
+                }
+                // An exhausted exact search proves there is no solution to keep looking for.
+                if report.strategy == Strategy::Backtracking
+                    && report.status == StrategyStatus::Exhausted
+                {
+                    cancel.store(true, Ordering::Relaxed);
+                }
+                reports.push(report);
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                timed_out = true;
+                cancel.store(true, Ordering::Relaxed);
+            }
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
+
+    // Strategies reporting after the winner may have solved too, but only the winner counts.
+    for report in &mut reports {
+        if Some(report.strategy) != winner && report.status == StrategyStatus::Solved {
+            report.status = StrategyStatus::Stopped;
+        }
+    }
+    reports.sort_by_key(|report| {
+        config
+            .strategies
+            .iter()
+            .position(|&strategy| strategy == report.strategy)
+    });
+
+    Ok(PortfolioOutcome {
+        winner,
+        timed_out: timed_out && winner.is_none(),
+        elapsed: started_at.elapsed(),
+        reports,
+    })
+}
+
+fn supports(config: &PortfolioConfig, strategy: Strategy) -> bool {
+    match strategy {
+        Strategy::GeneticAlgorithm => true,
+        // Local search only stops on a solution, and sizes 2 and 3 have none.
+        Strategy::MinConflicts | Strategy::Annealing => {
+            config.ga.symmetry == SymmetryConstraint::None && !matches!(config.ga.size, 2 | 3)
+        }
+        Strategy::Backtracking => config.ga.size <= config.backtracking_max_size,
+    }
+}
+
+fn finished(
+    strategy: Strategy,
+    solution: Option<Chromosome>,
+    steps: u64,
+    stopped: bool,
+) -> StrategyReport {
+    let status = match (&solution, stopped) {
+        (Some(_), _) => StrategyStatus::Solved,
+        (None, true) => StrategyStatus::Stopped,
+        (None, false) => StrategyStatus::Exhausted,
+    };
+    StrategyReport {
+        strategy,
+        status,
+        elapsed: Duration::ZERO,
+        steps,
+        solution,
+    }
+}
+
+fn run_genetic_algorithm(
+    algorithm: &mut ga::GeneticAlgorithm,
+    cancel: &AtomicBool,
+) -> StrategyReport {
+    let run_metrics = algorithm.run_algorithm_with_progress(|_| !cancel.load(Ordering::Relaxed));
+    let solution = run_metrics
+        .solved_epoch()
+        .map(|_| algorithm.get_best_chromosome().clone());
+    let epochs = run_metrics.epochs().len().saturating_sub(1) as u64;
+    finished(
+        Strategy::GeneticAlgorithm,
+        solution,
+        epochs,
+        cancel.load(Ordering::Relaxed),
+    )
+}
+
+fn run_backtracking(
+    size: u16,
+    symmetry: SymmetryConstraint,
+    cancel: &AtomicBool,
+) -> StrategyReport {
+    let mut nodes = 0;
+    let solution = exact::first_solution_until(size, symmetry, || {
+        nodes += 1;
+        nodes % CANCEL_CHECK_INTERVAL == 0 && cancel.load(Ordering::Relaxed)
+    });
+    finished(
+        Strategy::Backtracking,
+        solution.map(Chromosome::new),
+        nodes,
+        cancel.load(Ordering::Relaxed),
+    )
+}
+
+fn run_min_conflicts(size: u16, mut rng: StdRng, cancel: &AtomicBool) -> StrategyReport {
+    let mut board = MinConflictsBoard::new(size);
+    let restart_steps = MIN_CONFLICTS_STEPS_PER_QUEEN * u64::from(size);
+    let mut steps = 0;
+    let mut steps_since_restart = 0;
+
+    board.restart(&mut rng);
+    while board.attacking_pairs > 0 {
+        if steps % CANCEL_CHECK_INTERVAL == 0 && cancel.load(Ordering::Relaxed) {
+            return finished(Strategy::MinConflicts, None, steps, true);
+        }
+        if steps_since_restart == restart_steps {
+            board.restart(&mut rng);
+            steps_since_restart = 0;
+            continue;
+        }
+
+        let column = board.random_conflicted_column(&mut rng);
+        board.move_queen(column, &mut rng);
+        steps += 1;
+        steps_since_restart += 1;
+    }
+
+    let positions = board.rows.iter().map(|&row| row as u16).collect();
+    finished(
+        Strategy::MinConflicts,
+        Some(Chromosome::new(positions)),
+        steps,
+        false,
+    )
+}
+
+// One queen per column, with queens counted per row and diagonal so a move costs O(1)
+// and choosing the destination row costs O(size).
+struct MinConflictsBoard {
+    size: usize,
+    rows: Vec<usize>,
+    row_counts: Vec<u32>,
+    descending_counts: Vec<u32>,
+    ascending_counts: Vec<u32>,
+    attacking_pairs: u64,
+}
+
+impl MinConflictsBoard {
+    fn new(size: u16) -> Self {
+        let size = usize::from(size);
+        Self {
+            size,
+            rows: vec![0; size],
+            row_counts: vec![0; size],
+            descending_counts: vec![0; size * 2 - 1],
+            ascending_counts: vec![0; size * 2 - 1],
+            attacking_pairs: 0,
+        }
+    }
+
+    // Places the queens column by column, each on the least attacked row so far.
+    fn restart(&mut self, rng: &mut impl Rng) {
+        self.row_counts.fill(0);
+        self.descending_counts.fill(0);
+        self.ascending_counts.fill(0);
+        self.attacking_pairs = 0;
+        for column in 0..self.size {
+            let row = self.least_attacked_row(column, rng);
+            self.rows[column] = row;
+            self.add(column, row);
+        }
+    }
+
+    fn attacks(&self, column: usize, row: usize) -> u32 {
+        self.row_counts[row]
+            + self.descending_counts[column + self.size - 1 - row]
+            + self.ascending_counts[column + row]
+    }
+
+    fn is_attacked(&self, column: usize) -> bool {
+        let row = self.rows[column];
+        self.attacks(column, row) > 3
+    }
+
+    fn random_conflicted_column(&self, rng: &mut impl Rng) -> usize {
+        loop {
+            let column = rng.random_range(0..self.size);
+            if self.is_attacked(column) {
+                return column;
+            }
+        }
+    }
+
+    // Ties are broken uniformly so the search does not cycle between the same boards.
+    fn least_attacked_row(&self, column: usize, rng: &mut impl Rng) -> usize {
+        let mut best_row = 0;
+        let mut best_attacks = u32::MAX;
+        let mut ties = 0;
+        for row in 0..self.size {
+            let attacks = self.attacks(column, row);
+            if attacks < best_attacks {
+                best_row = row;
+                best_attacks = attacks;
+                ties = 1;
+            } else if attacks == best_attacks {
+                ties += 1;
+                if rng.random_range(0..ties) == 0 {
+                    best_row = row;
+                }
+            }
+        }
+        best_row
+    }
+
+    // Lifts the queen first so its own row and diagonals do not count against staying put.
+    fn move_queen(&mut self, column: usize, rng: &mut impl Rng) {
+        self.remove(column, self.rows[column]);
+        let row = self.least_attacked_row(column, rng);
+        self.rows[column] = row;
+        self.add(column, row);
+    }
+
+    fn add(&mut self, column: usize, row: usize) {
+        self.attacking_pairs += u64::from(self.attacks(column, row));
+        self.row_counts[row] += 1;
+        self.descending_counts[column + self.size - 1 - row] += 1;
+        self.ascending_counts[column + row] += 1;
+    }
+
+    fn remove(&mut self, column: usize, row: usize) {
+        self.row_counts[row] -= 1;
+        self.descending_counts[column + self.size - 1 - row] -= 1;
+        self.ascending_counts[column + row] -= 1;
+        self.attacking_pairs -= u64::from(self.attacks(column, row));
+    }
+}
+
+fn run_annealing(size: u16, mut rng: StdRng, cancel: &AtomicBool) -> StrategyReport {
+    let mut chromosome = Chromosome::new(ga::chromosome::generate_distinct_random_values_with_rng(
+        size, &mut rng,
+    ));
+    let size = usize::from(size);
+    let mut temperature = ANNEALING_START_TEMPERATURE;
+    let mut steps = 0;
+
+    while chromosome.get_conflicts_sum() > 0 {
+        if steps % CANCEL_CHECK_INTERVAL == 0 && cancel.load(Ordering::Relaxed) {
+            return finished(Strategy::Annealing, None, steps, true);
+        }
+        steps += 1;
+        if size < 2 {
+            continue;
+        }
+
+        let index_one = rng.random_range(0..size);
+        let mut index_two = rng.random_range(0..(size - 1));
+        if index_two >= index_one {
+            index_two += 1;
+        }
+        let before = chromosome.get_conflicts_sum();
+        chromosome.mutate_swap_at(index_one, index_two);
+        let delta = f64::from(chromosome.get_conflicts_sum()) - f64::from(before);
+        if delta > 0.0 && rng.random::<f64>() >= (-delta / temperature).exp() {
+            chromosome.mutate_swap_at(index_one, index_two);
+        }
+
+        temperature *= ANNEALING_COOLING_FACTOR;
+        if temperature < ANNEALING_MIN_TEMPERATURE {
+            temperature = ANNEALING_START_TEMPERATURE;
+        }
+    }
+
+    finished(Strategy::Annealing, Some(chromosome), steps, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{PortfolioConfig, STRATEGIES, Strategy, StrategyStatus, run_portfolio};
+    use crate::{ga::GaConfig, symmetry::SymmetryConstraint};
+
+    fn portfolio_config(size: u16, strategies: &[Strategy]) -> PortfolioConfig {
+        PortfolioConfig::new(GaConfig::new(size, 200, 2_000, 7)).with_strategies(strategies)
+    }
+
+    #[test]
+    fn test_each_strategy_solves_a_small_board_alone() {
+        for strategy in STRATEGIES {
+            let outcome = run_portfolio(&portfolio_config(12, &[strategy])).unwrap();
+
+            assert_eq!(outcome.winner, Some(strategy));
+            assert_eq!(outcome.reports.len(), 1);
+            assert_eq!(outcome.reports[0].status, StrategyStatus::Solved);
+            assert_eq!(outcome.solution().unwrap().get_conflicts_sum(), 0);
+        }
+    }
+
+    #[test]
+    fn test_portfolio_reports_one_winner_and_stops_the_rest() {
+        let outcome = run_portfolio(&portfolio_config(40, &STRATEGIES)).unwrap();
+
+        let winner = outcome.winner.unwrap();
+        assert_eq!(outcome.solution().unwrap().get_conflicts_sum(), 0);
+        assert!(!outcome.timed_out);
+        let statuses = outcome
+            .reports
+            .iter()
+            .map(|report| (report.strategy, report.status))
+            .collect::<Vec<_>>();
+        assert_eq!(statuses.len(), STRATEGIES.len());
+        for (strategy, status) in statuses {
+            if strategy == winner {
+                assert_eq!(status, StrategyStatus::Solved);
+            } else if strategy == Strategy::Backtracking {
+                assert_eq!(status, StrategyStatus::Skipped);
+            } else {
+                assert_ne!(status, StrategyStatus::Solved);
+            }
+        }
+    }
+
+    #[test]
+    fn test_portfolio_stops_once_backtracking_proves_no_solution() {
+        let outcome = run_portfolio(&portfolio_config(3, &STRATEGIES)).unwrap();
+
+        assert_eq!(outcome.winner, None);
+        assert!(!outcome.timed_out);
+        let statuses = outcome
+            .reports
+            .iter()
+            .map(|report| report.status)
+            .collect::<Vec<_>>();
+        assert_ne!(statuses[0], StrategyStatus::Solved);
+        assert_eq!(
+            statuses[1..],
+            [
+                StrategyStatus::Skipped,
+                StrategyStatus::Skipped,
+                StrategyStatus::Exhausted,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_portfolio_finishes_on_unsolvable_sizes_without_a_time_limit() {
+        for size in [2, 3] {
+            let outcome = run_portfolio(&portfolio_config(
+                size,
+                &[Strategy::MinConflicts, Strategy::Annealing],
+            ))
+            .unwrap();
+
+            assert_eq!(outcome.winner, None);
+            assert!(!outcome.timed_out);
+            assert!(
+                outcome
+                    .reports
+                    .iter()
+                    .all(|report| report.status == StrategyStatus::Skipped)
+            );
+        }
+    }
+
+    #[test]
+    fn test_portfolio_skips_local_search_under_symmetry() {
+        let config = PortfolioConfig::new(
+            GaConfig::new(10, 200, 2_000, 7).with_symmetry(SymmetryConstraint::Rotation180),
+        );
+        let outcome = run_portfolio(&config).unwrap();
+
+        assert!(matches!(
+            outcome.winner,
+            Some(Strategy::GeneticAlgorithm | Strategy::Backtracking)
+        ));
+        assert_eq!(outcome.reports[1].status, StrategyStatus::Skipped);
+        assert_eq!(outcome.reports[2].status, StrategyStatus::Skipped);
+    }
+
+    #[test]
+    fn test_portfolio_stops_at_the_time_limit() {
+        let config = PortfolioConfig::new(GaConfig::new(3, 200, u32::MAX, 7))
+            .with_strategies(&[Strategy::GeneticAlgorithm])
+            .with_time_limit(Some(Duration::from_millis(50)));
+        let outcome = run_portfolio(&config).unwrap();
+
+        assert_eq!(outcome.winner, None);
+        assert!(outcome.timed_out);
+        assert_eq!(outcome.reports[0].status, StrategyStatus::Stopped);
+    }
+}
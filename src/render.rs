@@ -0,0 +1,550 @@
+use std::{error::Error, fmt, fs, io, path::Path};
+
+use crate::ga::chromosome::Chromosome;
+
+pub mod chart;
+
+pub const DEFAULT_CELL_SIZE: u32 = 48;
+const MIN_AUTO_CELL_SIZE: u32 = 4;
+const MAX_AUTO_IMAGE_SIZE: u32 = 2_048;
+
+const LIGHT_SQUARE: Rgb = Rgb(0xf0, 0xd9, 0xb5);
+const DARK_SQUARE: Rgb = Rgb(0xb5, 0x88, 0x63);
+const QUEEN: Rgb = Rgb(0x1f, 0x1f, 0x1f);
+const CONFLICTING_QUEEN: Rgb = Rgb(0xd3, 0x2f, 0x2f);
+const ATTACK_LINE: Rgb = Rgb(0xe5, 0x39, 0x35);
+
+// Crown outline in cell-relative coordinates, shared by every vector and raster format.
+const QUEEN_OUTLINE: [(f32, f32); 7] = [
+    (0.20, 0.80),
+    (0.80, 0.80),
+    (0.86, 0.32),
+    (0.67, 0.54),
+    (0.50, 0.20),
+    (0.33, 0.54),
+    (0.14, 0.32),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoardExportFormat {
+    Svg,
+    Png,
+    Latex,
+    Html,
+}
+
+impl BoardExportFormat {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "svg" => Some(Self::Svg),
+            "png" => Some(Self::Png),
+            "tex" => Some(Self::Latex),
+            "html" | "htm" => Some(Self::Html),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for BoardExportFormat {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Svg => formatter.write_str("svg"),
+            Self::Png => formatter.write_str("png"),
+            Self::Latex => formatter.write_str("latex"),
+            Self::Html => formatter.write_str("html"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ExportError {
+    UnsupportedExtension,
+    UnsupportedChartExtension,
+    Io(io::Error),
+    PngEncoding(png::EncodingError),
+}
+
+impl fmt::Display for ExportError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedExtension => {
+                formatter.write_str("board export path must end in .svg, .png, .tex, or .html")
+            }
+            Self::UnsupportedChartExtension => {
+                formatter.write_str("chart export path must end in .svg or .png")
+            }
+            Self::Io(error) => write!(formatter, "failed to write board export: {error}"),
+            Self::PngEncoding(error) => write!(formatter, "failed to encode PNG: {error}"),
+        }
+    }
+}
+
+impl Error for ExportError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::UnsupportedExtension | Self::UnsupportedChartExtension => None,
+            Self::Io(error) => Some(error),
+            Self::PngEncoding(error) => Some(error),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RenderOptions {
+    pub cell_size: u32,
+    pub attack_lines: bool,
+}
+
+impl RenderOptions {
+    pub fn for_board_size(size: usize) -> Self {
+        let size = u32::try_from(size.max(1)).unwrap_or(u32::MAX);
+        Self {
+            cell_size: (MAX_AUTO_IMAGE_SIZE / size).clamp(MIN_AUTO_CELL_SIZE, DEFAULT_CELL_SIZE),
+            attack_lines: false,
+        }
+    }
+
+    pub fn with_cell_size(mut self, cell_size: u32) -> Self {
+        self.cell_size = cell_size.max(1);
+        self
+    }
+
+    pub fn with_attack_lines(mut self, attack_lines: bool) -> Self {
+        self.attack_lines = attack_lines;
+        self
+    }
+}
+
+pub fn export_board(
+    path: &Path,
+    chromosome: &Chromosome,
+    options: RenderOptions,
+) -> Result<BoardExportFormat, ExportError> {
+    let format = BoardExportFormat::from_path(path).ok_or(ExportError::UnsupportedExtension)?;
+    if let Some(parent) = path.parent().filter(|path| !path.as_os_str().is_empty()) {
+        fs::create_dir_all(parent).map_err(ExportError::Io)?;
+    }
+
+    let contents = match format {
+        BoardExportFormat::Svg => render_svg(chromosome, options).into_bytes(),
+        BoardExportFormat::Png => render_png(chromosome, options)?,
+        BoardExportFormat::Latex => render_latex(chromosome, options).into_bytes(),
+        BoardExportFormat::Html => render_html(chromosome, options).into_bytes(),
+    };
+    fs::write(path, contents).map_err(ExportError::Io)?;
+    Ok(format)
+}
+
+pub fn render_svg(chromosome: &Chromosome, options: RenderOptions) -> String {
+    let positions = chromosome.get_positions();
+    let conflicts = chromosome.get_conflicts();
+    let cell = options.cell_size as f32;
+    let board_pixels = positions.len() as f32 * cell;
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{board_pixels}\" height=\"{board_pixels}\" viewBox=\"0 0 {board_pixels} {board_pixels}\">\n"
+    );
+
+    for y in 0..positions.len() {
+        for x in 0..positions.len() {
+            svg.push_str(&format!(
+                "  <rect x=\"{}\" y=\"{}\" width=\"{cell}\" height=\"{cell}\" fill=\"{}\"/>\n",
+                x as f32 * cell,
+                y as f32 * cell,
+                square_color(x, y)
+            ));
+        }
+    }
+
+    if options.attack_lines {
+        let stroke_width = (cell / 12.0).max(1.0);
+        for ((x_one, y_one), (x_two, y_two)) in attack_line_centers(chromosome, cell) {
+            svg.push_str(&format!(
+                "  <line class=\"attack\" x1=\"{x_one}\" y1=\"{y_one}\" x2=\"{x_two}\" y2=\"{y_two}\" stroke=\"{ATTACK_LINE}\" stroke-width=\"{stroke_width}\" stroke-linecap=\"round\"/>\n"
+            ));
+        }
+    }
+
+    for (x, &y) in positions.iter().enumerate() {
+        let points = queen_outline(x, usize::from(y), cell)
+            .iter()
+            .map(|(point_x, point_y)| format!("{point_x},{point_y}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let (class, color) = queen_style(conflicts[x]);
+        svg.push_str(&format!(
+            "  <polygon class=\"{class}\" points=\"{points}\" fill=\"{color}\"/>\n"
+        ));
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+pub fn render_html(chromosome: &Chromosome, options: RenderOptions) -> String {
+    let size = chromosome.get_positions().len();
+    let conflicts_sum = chromosome.get_conflicts_sum();
+    let status = match conflicts_sum {
+        0 => "Solution".to_owned(),
+        1 => "1 attacking pair, conflicting queens in red".to_owned(),
+        _ => format!("{conflicts_sum} attacking pairs, conflicting queens in red"),
+    };
+
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{size}-Queens board</title>\n<style>\nbody {{ font-family: sans-serif; margin: 2rem; background: #fafafa; color: #212121; }}\nsvg {{ max-width: 100%; height: auto; box-shadow: 0 2px 8px rgba(0, 0, 0, 0.25); }}\n</style>\n</head>\n<body>\n<h1>{size}-Queens board</h1>\n<p>{status}</p>\n{}</body>\n</html>\n",
+        render_svg(chromosome, options)
+    )
+}
+
+pub fn render_latex(chromosome: &Chromosome, options: RenderOptions) -> String {
+    let positions = chromosome.get_positions();
+    let conflicts = chromosome.get_conflicts();
+    let size = positions.len();
+    let mut latex = format!(
+        "\\documentclass[tikz,border=2pt]{{standalone}}\n\\definecolor{{lightsquare}}{{HTML}}{{{}}}\n\\definecolor{{darksquare}}{{HTML}}{{{}}}\n\\definecolor{{queen}}{{HTML}}{{{}}}\n\\definecolor{{conflict}}{{HTML}}{{{}}}\n\\definecolor{{attack}}{{HTML}}{{{}}}\n\\begin{{document}}\n\\begin{{tikzpicture}}[y=-1cm, x=1cm, scale={:.4}]\n",
+        LIGHT_SQUARE.hex_digits(),
+        DARK_SQUARE.hex_digits(),
+        QUEEN.hex_digits(),
+        CONFLICTING_QUEEN.hex_digits(),
+        ATTACK_LINE.hex_digits(),
+        16.0 / size as f32
+    );
+
+    for y in 0..size {
+        for x in 0..size {
+            let color = if (x + y) % 2 == 0 {
+                "lightsquare"
+            } else {
+                "darksquare"
+            };
+            latex.push_str(&format!("\\fill[{color}] ({x},{y}) rectangle ++(1,1);\n"));
+        }
+    }
+
+    if options.attack_lines {
+        for ((x_one, y_one), (x_two, y_two)) in attack_line_centers(chromosome, 1.0) {
+            latex.push_str(&format!(
+                "\\draw[attack, line width=1.5pt, line cap=round] ({x_one},{y_one}) -- ({x_two},{y_two});\n"
+            ));
+        }
+    }
+
+    for (x, &y) in positions.iter().enumerate() {
+        let color = if conflicts[x] > 0 {
+            "conflict"
+        } else {
+            "queen"
+        };
+        let points = queen_outline(x, usize::from(y), 1.0)
+            .iter()
+            .map(|(point_x, point_y)| format!("({point_x},{point_y})"))
+            .collect::<Vec<_>>()
+            .join(" -- ");
+        latex.push_str(&format!("\\fill[{color}] {points} -- cycle;\n"));
+    }
+
+    latex.push_str("\\end{tikzpicture}\n\\end{document}\n");
+    latex
+}
+
+pub fn render_png(chromosome: &Chromosome, options: RenderOptions) -> Result<Vec<u8>, ExportError> {
+    encode_png(&rasterize(chromosome, options))
+}
+
+fn encode_png(image: &RgbImage) -> Result<Vec<u8>, ExportError> {
+    let mut encoded = Vec::new();
+    let mut encoder = png::Encoder::new(&mut encoded, image.width, image.height);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().map_err(ExportError::PngEncoding)?;
+    writer
+        .write_image_data(&image.pixels)
+        .map_err(ExportError::PngEncoding)?;
+    writer.finish().map_err(ExportError::PngEncoding)?;
+    Ok(encoded)
+}
+
+struct RgbImage {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+impl RgbImage {
+    fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![0; width as usize * height as usize * 3],
+        }
+    }
+
+    fn put(&mut self, x: i64, y: i64, color: Rgb) {
+        if x < 0 || y < 0 || x >= i64::from(self.width) || y >= i64::from(self.height) {
+            return;
+        }
+
+        let offset = (y as usize * self.width as usize + x as usize) * 3;
+        self.pixels[offset..offset + 3].copy_from_slice(&[color.0, color.1, color.2]);
+    }
+
+    fn fill_rect(&mut self, left: u32, top: u32, size: u32, color: Rgb) {
+        for y in top..top + size {
+            for x in left..left + size {
+                self.put(i64::from(x), i64::from(y), color);
+            }
+        }
+    }
+
+    fn fill_disk(&mut self, center_x: f32, center_y: f32, radius: f32, color: Rgb) {
+        let reach = radius.ceil() as i64;
+        let (pixel_x, pixel_y) = (center_x.floor() as i64, center_y.floor() as i64);
+        for y in pixel_y - reach..=pixel_y + reach {
+            for x in pixel_x - reach..=pixel_x + reach {
+                let (dx, dy) = (x as f32 + 0.5 - center_x, y as f32 + 0.5 - center_y);
+                if dx * dx + dy * dy <= radius * radius {
+                    self.put(x, y, color);
+                }
+            }
+        }
+    }
+
+    fn draw_line(&mut self, from: (f32, f32), to: (f32, f32), width: f32, color: Rgb) {
+        let length = (to.0 - from.0).hypot(to.1 - from.1);
+        let steps = length.ceil().max(1.0) as usize;
+        for step in 0..=steps {
+            let t = step as f32 / steps as f32;
+            self.fill_disk(
+                from.0 + (to.0 - from.0) * t,
+                from.1 + (to.1 - from.1) * t,
+                width / 2.0,
+                color,
+            );
+        }
+    }
+
+    fn fill_polygon(&mut self, points: &[(f32, f32)], color: Rgb) {
+        let min_x = points.iter().map(|point| point.0).fold(f32::MAX, f32::min);
+        let max_x = points.iter().map(|point| point.0).fold(f32::MIN, f32::max);
+        let min_y = points.iter().map(|point| point.1).fold(f32::MAX, f32::min);
+        let max_y = points.iter().map(|point| point.1).fold(f32::MIN, f32::max);
+
+        for y in min_y.floor() as i64..=max_y.ceil() as i64 {
+            for x in min_x.floor() as i64..=max_x.ceil() as i64 {
+                if polygon_contains(points, x as f32 + 0.5, y as f32 + 0.5) {
+                    self.put(x, y, color);
+                }
+            }
+        }
+    }
+}
+
+fn rasterize(chromosome: &Chromosome, options: RenderOptions) -> RgbImage {
+    let positions = chromosome.get_positions();
+    let conflicts = chromosome.get_conflicts();
+    let cell = options.cell_size;
+    let board_pixels = u32::try_from(positions.len())
+        .unwrap_or(u32::MAX)
+        .saturating_mul(cell);
+    let mut image = RgbImage::new(board_pixels, board_pixels);
+
+    for y in 0..positions.len() {
+        for x in 0..positions.len() {
+            image.fill_rect(x as u32 * cell, y as u32 * cell, cell, square_color(x, y));
+        }
+    }
+
+    if options.attack_lines {
+        let width = (cell as f32 / 12.0).max(1.0);
+        for (from, to) in attack_line_centers(chromosome, cell as f32) {
+            image.draw_line(from, to, width, ATTACK_LINE);
+        }
+    }
+
+    for (x, &y) in positions.iter().enumerate() {
+        let (_, color) = queen_style(conflicts[x]);
+        image.fill_polygon(&queen_outline(x, usize::from(y), cell as f32), color);
+    }
+
+    image
+}
+
+fn polygon_contains(points: &[(f32, f32)], x: f32, y: f32) -> bool {
+    let mut inside = false;
+    let mut previous = points[points.len() - 1];
+    for &current in points {
+        if (current.1 > y) != (previous.1 > y)
+            && x < (previous.0 - current.0) * (y - current.1) / (previous.1 - current.1) + current.0
+        {
+            inside = !inside;
+        }
+        previous = current;
+    }
+    inside
+}
+
+fn queen_outline(x: usize, y: usize, cell: f32) -> Vec<(f32, f32)> {
+    QUEEN_OUTLINE
+        .iter()
+        .map(|&(offset_x, offset_y)| {
+            (
+                round_coordinate((x as f32 + offset_x) * cell),
+                round_coordinate((y as f32 + offset_y) * cell),
+            )
+        })
+        .collect()
+}
+
+fn attack_line_centers(chromosome: &Chromosome, cell: f32) -> Vec<((f32, f32), (f32, f32))> {
+    let positions = chromosome.get_positions();
+    let center = |x: usize| {
+        (
+            (x as f32 + 0.5) * cell,
+            (f32::from(positions[x]) + 0.5) * cell,
+        )
+    };
+
+    chromosome
+        .get_attacking_pairs()
+        .into_iter()
+        .map(|(x_one, x_two)| (center(x_one), center(x_two)))
+        .collect()
+}
+
+fn round_coordinate(value: f32) -> f32 {
+    (value * 100.0).round() / 100.0
+}
+
+fn square_color(x: usize, y: usize) -> Rgb {
+    if (x + y).is_multiple_of(2) {
+        LIGHT_SQUARE
+    } else {
+        DARK_SQUARE
+    }
+}
+
+fn queen_style(conflicts: u32) -> (&'static str, Rgb) {
+    if conflicts > 0 {
+        ("queen conflict", CONFLICTING_QUEEN)
+    } else {
+        ("queen", QUEEN)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Rgb(u8, u8, u8);
+
+impl Rgb {
+    fn hex_digits(self) -> String {
+        format!("{:02X}{:02X}{:02X}", self.0, self.1, self.2)
+    }
+}
+
+impl fmt::Display for Rgb {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Cursor, path::Path};
+
+    use super::{
+        BoardExportFormat, CONFLICTING_QUEEN, DARK_SQUARE, QUEEN, RenderOptions, render_html,
+        render_latex, render_png, render_svg,
+    };
+    use crate::ga::chromosome::Chromosome;
+
+    #[test]
+    fn test_export_format_follows_extension() {
+        assert_eq!(
+            BoardExportFormat::from_path(Path::new("board.SVG")),
+            Some(BoardExportFormat::Svg)
+        );
+        assert_eq!(
+            BoardExportFormat::from_path(Path::new("out/board.tex")),
+            Some(BoardExportFormat::Latex)
+        );
+        assert_eq!(
+            BoardExportFormat::from_path(Path::new("board.htm")),
+            Some(BoardExportFormat::Html)
+        );
+        assert_eq!(BoardExportFormat::from_path(Path::new("board.txt")), None);
+        assert_eq!(RenderOptions::for_board_size(8).cell_size, 48);
+        assert_eq!(RenderOptions::for_board_size(1_000).cell_size, 4);
+    }
+
+    #[test]
+    fn test_vector_formats_highlight_conflicts_and_attack_lines() {
+        let chromosome = Chromosome::new(vec![0, 2, 4, 6, 1, 3, 5, 7]);
+        let options = RenderOptions::for_board_size(8).with_attack_lines(true);
+
+        let svg = render_svg(&chromosome, options);
+        assert_eq!(svg.matches("<polygon").count(), 8);
+        assert_eq!(svg.matches("class=\"queen conflict\"").count(), 2);
+        assert_eq!(svg.matches("class=\"attack\"").count(), 1);
+        assert!(!render_svg(&chromosome, options.with_attack_lines(false)).contains("<line"));
+
+        let latex = render_latex(&chromosome, options);
+        assert!(latex.starts_with("\\documentclass[tikz"));
+        assert!(latex.contains("\\definecolor{conflict}{HTML}{D32F2F}"));
+        assert_eq!(latex.matches("\\fill[conflict]").count(), 2);
+        assert_eq!(latex.matches("\\draw[attack").count(), 1);
+
+        let html = render_html(&chromosome, options);
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("1 attacking pair,"));
+        assert!(html.contains("<svg"));
+    }
+
+    #[test]
+    fn test_png_rasterizes_board_and_queens() {
+        let chromosome = Chromosome::new(vec![0, 1, 3, 2]);
+        let options = RenderOptions::for_board_size(4).with_cell_size(20);
+        let encoded = render_png(&chromosome, options).expect("png should encode");
+
+        let decoder = png::Decoder::new(Cursor::new(encoded));
+        let mut reader = decoder.read_info().expect("png header should decode");
+        let mut pixels = vec![0; reader.output_buffer_size().expect("buffer size should fit")];
+        let info = reader
+            .next_frame(&mut pixels)
+            .expect("png frame should decode");
+        assert_eq!((info.width, info.height), (80, 80));
+
+        let pixel = |x: usize, y: usize| {
+            let offset = (y * 80 + x) * 3;
+            (pixels[offset], pixels[offset + 1], pixels[offset + 2])
+        };
+        // Queen bodies sit around 70% down each cell; corners keep the square colors.
+        assert_eq!(
+            pixel(10, 14),
+            (
+                CONFLICTING_QUEEN.0,
+                CONFLICTING_QUEEN.1,
+                CONFLICTING_QUEEN.2
+            )
+        );
+        assert_eq!(
+            pixel(30, 34),
+            (
+                CONFLICTING_QUEEN.0,
+                CONFLICTING_QUEEN.1,
+                CONFLICTING_QUEEN.2
+            )
+        );
+        assert_eq!(pixel(21, 1), (DARK_SQUARE.0, DARK_SQUARE.1, DARK_SQUARE.2));
+
+        let solved = Chromosome::new(vec![1, 3, 0, 2]);
+        let encoded = render_png(&solved, options).expect("png should encode");
+        let mut reader = png::Decoder::new(Cursor::new(encoded))
+            .read_info()
+            .expect("png header should decode");
+        let mut pixels = vec![0; reader.output_buffer_size().expect("buffer size should fit")];
+        reader
+            .next_frame(&mut pixels)
+            .expect("png frame should decode");
+        let offset = (34 * 80 + 10) * 3;
+        assert_eq!(&pixels[offset..offset + 3], &[QUEEN.0, QUEEN.1, QUEEN.2]);
+    }
+}
@@ -0,0 +1,459 @@
+use std::{fmt, fs, path::Path};
+
+use super::{ExportError, Rgb, RgbImage};
+
+pub const CHART_WIDTH: u32 = 800;
+pub const CHART_HEIGHT: u32 = 260;
+const MARGIN_LEFT: f32 = 64.0;
+const MARGIN_RIGHT: f32 = 20.0;
+const MARGIN_TOP: f32 = 52.0;
+const MARGIN_BOTTOM: f32 = 30.0;
+const GRID_DIVISIONS: u32 = 4;
+const LINE_WIDTH: f32 = 2.0;
+
+const BACKGROUND: Rgb = Rgb(0xff, 0xff, 0xff);
+const GRID: Rgb = Rgb(0xe0, 0xe0, 0xe0);
+const AXIS_TEXT: Rgb = Rgb(0x61, 0x61, 0x61);
+const TITLE_TEXT: Rgb = Rgb(0x21, 0x21, 0x21);
+
+// PNG labels use a 3x5 bitmap font drawn at `FONT_SCALE`, so exports need no font files.
+const FONT_SCALE: u32 = 2;
+const GLYPH_ADVANCE: u32 = 4 * FONT_SCALE;
+const GLYPHS: [(char, [u8; 5]); 49] = [
+    ('0', [0b111, 0b101, 0b101, 0b101, 0b111]),
+    ('1', [0b010, 0b110, 0b010, 0b010, 0b111]),
+    ('2', [0b111, 0b001, 0b111, 0b100, 0b111]),
+    ('3', [0b111, 0b001, 0b111, 0b001, 0b111]),
+    ('4', [0b101, 0b101, 0b111, 0b001, 0b001]),
+    ('5', [0b111, 0b100, 0b111, 0b001, 0b111]),
+    ('6', [0b111, 0b100, 0b111, 0b101, 0b111]),
+    ('7', [0b111, 0b001, 0b001, 0b001, 0b001]),
+    ('8', [0b111, 0b101, 0b111, 0b101, 0b111]),
+    ('9', [0b111, 0b101, 0b111, 0b001, 0b111]),
+    ('A', [0b010, 0b101, 0b111, 0b101, 0b101]),
+    ('B', [0b110, 0b101, 0b110, 0b101, 0b110]),
+    ('C', [0b011, 0b100, 0b100, 0b100, 0b011]),
+    ('D', [0b110, 0b101, 0b101, 0b101, 0b110]),
+    ('E', [0b111, 0b100, 0b110, 0b100, 0b111]),
+    ('F', [0b111, 0b100, 0b110, 0b100, 0b100]),
+    ('G', [0b011, 0b100, 0b101, 0b101, 0b011]),
+    ('H', [0b101, 0b101, 0b111, 0b101, 0b101]),
+    ('I', [0b111, 0b010, 0b010, 0b010, 0b111]),
+    ('J', [0b001, 0b001, 0b001, 0b101, 0b010]),
+    ('K', [0b101, 0b101, 0b110, 0b101, 0b101]),
+    ('L', [0b100, 0b100, 0b100, 0b100, 0b111]),
+    ('M', [0b101, 0b111, 0b111, 0b101, 0b101]),
+    ('N', [0b110, 0b101, 0b101, 0b101, 0b101]),
+    ('O', [0b010, 0b101, 0b101, 0b101, 0b010]),
+    ('P', [0b110, 0b101, 0b110, 0b100, 0b100]),
+    ('Q', [0b010, 0b101, 0b101, 0b110, 0b011]),
+    ('R', [0b110, 0b101, 0b110, 0b101, 0b101]),
+    ('S', [0b011, 0b100, 0b010, 0b001, 0b110]),
+    ('T', [0b111, 0b010, 0b010, 0b010, 0b010]),
+    ('U', [0b101, 0b101, 0b101, 0b101, 0b111]),
+    ('V', [0b101, 0b101, 0b101, 0b101, 0b010]),
+    ('W', [0b101, 0b101, 0b111, 0b111, 0b101]),
+    ('X', [0b101, 0b101, 0b010, 0b101, 0b101]),
+    ('Y', [0b101, 0b101, 0b010, 0b010, 0b010]),
+    ('Z', [0b111, 0b001, 0b010, 0b100, 0b111]),
+    ('.', [0b000, 0b000, 0b000, 0b000, 0b010]),
+    (',', [0b000, 0b000, 0b000, 0b010, 0b100]),
+    ('-', [0b000, 0b000, 0b111, 0b000, 0b000]),
+    ('+', [0b000, 0b010, 0b111, 0b010, 0b000]),
+    ('=', [0b000, 0b111, 0b000, 0b111, 0b000]),
+    ('%', [0b101, 0b001, 0b010, 0b100, 0b101]),
+    (':', [0b000, 0b010, 0b000, 0b010, 0b000]),
+    ('#', [0b101, 0b111, 0b101, 0b111, 0b101]),
+    ('(', [0b001, 0b010, 0b010, 0b010, 0b001]),
+    (')', [0b100, 0b010, 0b010, 0b010, 0b100]),
+    ('/', [0b001, 0b001, 0b010, 0b100, 0b100]),
+    ('_', [0b000, 0b000, 0b000, 0b000, 0b111]),
+    ('*', [0b000, 0b101, 0b010, 0b101, 0b000]),
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chart {
+    pub title: String,
+    pub series: Vec<ChartSeries>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartSeries {
+    pub label: String,
+    pub color: [u8; 3],
+    pub points: Vec<(u32, f32)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChartExportFormat {
+    Svg,
+    Png,
+}
+
+impl ChartExportFormat {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "svg" => Some(Self::Svg),
+            "png" => Some(Self::Png),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ChartExportFormat {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Svg => formatter.write_str("svg"),
+            Self::Png => formatter.write_str("png"),
+        }
+    }
+}
+
+// Charts are stacked top to bottom in one image, each `CHART_WIDTH` by `CHART_HEIGHT`.
+pub fn export_charts(path: &Path, charts: &[Chart]) -> Result<ChartExportFormat, ExportError> {
+    let format =
+        ChartExportFormat::from_path(path).ok_or(ExportError::UnsupportedChartExtension)?;
+    if let Some(parent) = path.parent().filter(|path| !path.as_os_str().is_empty()) {
+        fs::create_dir_all(parent).map_err(ExportError::Io)?;
+    }
+
+    let contents = match format {
+        ChartExportFormat::Svg => render_charts_svg(charts).into_bytes(),
+        ChartExportFormat::Png => render_charts_png(charts)?,
+    };
+    fs::write(path, contents).map_err(ExportError::Io)?;
+    Ok(format)
+}
+
+pub fn render_charts_svg(charts: &[Chart]) -> String {
+    let height = total_height(charts);
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{CHART_WIDTH}\" height=\"{height}\" viewBox=\"0 0 {CHART_WIDTH} {height}\" font-family=\"sans-serif\">\n  <rect width=\"{CHART_WIDTH}\" height=\"{height}\" fill=\"{BACKGROUND}\"/>\n"
+    );
+
+    for (index, chart) in charts.iter().enumerate() {
+        let layout = ChartLayout::new(chart, index);
+        svg.push_str(&format!(
+            "  <text class=\"title\" x=\"{MARGIN_LEFT}\" y=\"{}\" font-size=\"15\" font-weight=\"bold\" fill=\"{TITLE_TEXT}\">{}</text>\n",
+            layout.top + 20.0,
+            escape_xml(&chart.title)
+        ));
+
+        let mut legend_x = MARGIN_LEFT;
+        for series in &chart.series {
+            let color = series_color(series);
+            svg.push_str(&format!(
+                "  <rect x=\"{legend_x}\" y=\"{}\" width=\"10\" height=\"10\" fill=\"{color}\"/>\n  <text x=\"{}\" y=\"{}\" font-size=\"12\" fill=\"{AXIS_TEXT}\">{}</text>\n",
+                layout.top + 30.0,
+                legend_x + 14.0,
+                layout.top + 39.0,
+                escape_xml(&series.label)
+            ));
+            legend_x += 24.0 + 7.0 * series.label.chars().count() as f32;
+        }
+
+        for step in 0..=GRID_DIVISIONS {
+            let t = step as f32 / GRID_DIVISIONS as f32;
+            let y = round(layout.plot_bottom - layout.plot_height() * t);
+            let x = round(MARGIN_LEFT + layout.plot_width() * t);
+            svg.push_str(&format!(
+                "  <line x1=\"{MARGIN_LEFT}\" y1=\"{y}\" x2=\"{}\" y2=\"{y}\" stroke=\"{GRID}\"/>\n  <text x=\"{}\" y=\"{}\" font-size=\"11\" text-anchor=\"end\" fill=\"{AXIS_TEXT}\">{}</text>\n",
+                layout.plot_right(),
+                MARGIN_LEFT - 6.0,
+                y + 4.0,
+                value_label(layout.max_value * t)
+            ));
+            svg.push_str(&format!(
+                "  <line x1=\"{x}\" y1=\"{}\" x2=\"{x}\" y2=\"{}\" stroke=\"{GRID}\"/>\n  <text x=\"{x}\" y=\"{}\" font-size=\"11\" text-anchor=\"middle\" fill=\"{AXIS_TEXT}\">{}</text>\n",
+                layout.plot_top,
+                layout.plot_bottom,
+                layout.plot_bottom + 16.0,
+                epoch_label(layout.max_epoch, t)
+            ));
+        }
+
+        for series in &chart.series {
+            let points = series
+                .points
+                .iter()
+                .map(|&(epoch, value)| {
+                    let (x, y) = layout.position(epoch, value);
+                    format!("{},{}", round(x), round(y))
+                })
+                .collect::<Vec<_>>()
+                .join(" ");
+            svg.push_str(&format!(
+                "  <polyline class=\"series\" points=\"{points}\" fill=\"none\" stroke=\"{}\" stroke-width=\"{LINE_WIDTH}\"/>\n",
+                series_color(series)
+            ));
+        }
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+pub fn render_charts_png(charts: &[Chart]) -> Result<Vec<u8>, ExportError> {
+    let height = total_height(charts);
+    let mut image = RgbImage::new(CHART_WIDTH, height);
+    fill_area(&mut image, 0, 0, CHART_WIDTH, height, BACKGROUND);
+
+    for (index, chart) in charts.iter().enumerate() {
+        let layout = ChartLayout::new(chart, index);
+        draw_text(
+            &mut image,
+            MARGIN_LEFT as u32,
+            layout.top as u32 + 8,
+            &chart.title,
+            TITLE_TEXT,
+        );
+
+        let mut legend_x = MARGIN_LEFT as u32;
+        for series in &chart.series {
+            let color = series_color(series);
+            fill_area(&mut image, legend_x, layout.top as u32 + 30, 10, 10, color);
+            let text_width = draw_text(
+                &mut image,
+                legend_x + 14,
+                layout.top as u32 + 30,
+                &series.label,
+                AXIS_TEXT,
+            );
+            legend_x += 14 + text_width + 12;
+        }
+
+        for step in 0..=GRID_DIVISIONS {
+            let t = step as f32 / GRID_DIVISIONS as f32;
+            let y = layout.plot_bottom - layout.plot_height() * t;
+            let x = MARGIN_LEFT + layout.plot_width() * t;
+            image.draw_line((MARGIN_LEFT, y), (layout.plot_right(), y), 1.0, GRID);
+            image.draw_line((x, layout.plot_top), (x, layout.plot_bottom), 1.0, GRID);
+
+            let value = value_label(layout.max_value * t);
+            let value_width = text_width(&value);
+            draw_text(
+                &mut image,
+                (MARGIN_LEFT as u32).saturating_sub(value_width + 6),
+                y as u32 - 5,
+                &value,
+                AXIS_TEXT,
+            );
+            let epoch = epoch_label(layout.max_epoch, t);
+            draw_text(
+                &mut image,
+                (x as u32).saturating_sub(text_width(&epoch) / 2),
+                layout.plot_bottom as u32 + 8,
+                &epoch,
+                AXIS_TEXT,
+            );
+        }
+
+        for series in &chart.series {
+            let color = series_color(series);
+            for pair in series.points.windows(2) {
+                image.draw_line(
+                    layout.position(pair[0].0, pair[0].1),
+                    layout.position(pair[1].0, pair[1].1),
+                    LINE_WIDTH,
+                    color,
+                );
+            }
+            if let [(epoch, value)] = series.points[..] {
+                let (x, y) = layout.position(epoch, value);
+                image.fill_disk(x, y, LINE_WIDTH, color);
+            }
+        }
+    }
+
+    super::encode_png(&image)
+}
+
+// Axes start at zero and end at the largest epoch and value across the chart's series,
+// matching the GUI charts.
+struct ChartLayout {
+    top: f32,
+    plot_top: f32,
+    plot_bottom: f32,
+    max_epoch: u32,
+    max_value: f32,
+}
+
+impl ChartLayout {
+    fn new(chart: &Chart, index: usize) -> Self {
+        let top = index as f32 * CHART_HEIGHT as f32;
+        let points = || chart.series.iter().flat_map(|series| series.points.iter());
+        Self {
+            top,
+            plot_top: top + MARGIN_TOP,
+            plot_bottom: top + CHART_HEIGHT as f32 - MARGIN_BOTTOM,
+            max_epoch: points().map(|&(epoch, _)| epoch).max().unwrap_or(1).max(1),
+            max_value: points()
+                .map(|&(_, value)| value)
+                .fold(0.0_f32, f32::max)
+                .max(1.0),
+        }
+    }
+
+    fn plot_right(&self) -> f32 {
+        CHART_WIDTH as f32 - MARGIN_RIGHT
+    }
+
+    fn plot_width(&self) -> f32 {
+        self.plot_right() - MARGIN_LEFT
+    }
+
+    fn plot_height(&self) -> f32 {
+        self.plot_bottom - self.plot_top
+    }
+
+    fn position(&self, epoch: u32, value: f32) -> (f32, f32) {
+        (
+            MARGIN_LEFT + self.plot_width() * (epoch as f32 / self.max_epoch as f32),
+            self.plot_bottom - self.plot_height() * (value / self.max_value).clamp(0.0, 1.0),
+        )
+    }
+}
+
+fn total_height(charts: &[Chart]) -> u32 {
+    CHART_HEIGHT * u32::try_from(charts.len().max(1)).unwrap_or(u32::MAX)
+}
+
+fn series_color(series: &ChartSeries) -> Rgb {
+    let [red, green, blue] = series.color;
+    Rgb(red, green, blue)
+}
+
+fn value_label(value: f32) -> String {
+    format!("{value:.1}")
+}
+
+fn epoch_label(max_epoch: u32, t: f32) -> String {
+    ((max_epoch as f32 * t).round() as u32).to_string()
+}
+
+fn round(value: f32) -> f32 {
+    (value * 100.0).round() / 100.0
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn fill_area(image: &mut RgbImage, left: u32, top: u32, width: u32, height: u32, color: Rgb) {
+    for y in top..top.saturating_add(height) {
+        for x in left..left.saturating_add(width) {
+            image.put(i64::from(x), i64::from(y), color);
+        }
+    }
+}
+
+fn text_width(text: &str) -> u32 {
+    u32::try_from(text.chars().count())
+        .unwrap_or(u32::MAX)
+        .saturating_mul(GLYPH_ADVANCE)
+}
+
+// Draws `text` in upper case with its top-left corner at `(left, top)` and returns its
+// width. Characters without a glyph are left blank.
+fn draw_text(image: &mut RgbImage, left: u32, top: u32, text: &str, color: Rgb) -> u32 {
+    for (index, character) in text.chars().enumerate() {
+        let character = character.to_ascii_uppercase();
+        let Some((_, rows)) = GLYPHS.iter().find(|(glyph, _)| *glyph == character) else {
+            continue;
+        };
+        let glyph_left = left + index as u32 * GLYPH_ADVANCE;
+        for (row, bits) in rows.iter().enumerate() {
+            for column in 0..3 {
+                if bits & (0b100 >> column) != 0 {
+                    fill_area(
+                        image,
+                        glyph_left + column * FONT_SCALE,
+                        top + row as u32 * FONT_SCALE,
+                        FONT_SCALE,
+                        FONT_SCALE,
+                        color,
+                    );
+                }
+            }
+        }
+    }
+    text_width(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Cursor, path::Path};
+
+    use super::{
+        CHART_HEIGHT, CHART_WIDTH, Chart, ChartExportFormat, ChartSeries, render_charts_png,
+        render_charts_svg,
+    };
+
+    fn sample_charts() -> Vec<Chart> {
+        vec![
+            Chart {
+                title: "Conflict history".to_owned(),
+                series: vec![
+                    ChartSeries {
+                        label: "Best conflicts".to_owned(),
+                        color: [95, 220, 140],
+                        points: vec![(0, 9.0), (1, 4.0), (2, 0.0)],
+                    },
+                    ChartSeries {
+                        label: "Average <conflicts>".to_owned(),
+                        color: [110, 190, 255],
+                        points: vec![(0, 14.5), (1, 10.0), (2, 7.25)],
+                    },
+                ],
+            },
+            Chart {
+                title: "Population ratios".to_owned(),
+                series: vec![ChartSeries {
+                    label: "Diversity ratio".to_owned(),
+                    color: [245, 210, 95],
+                    points: vec![(0, 1.0)],
+                }],
+            },
+        ]
+    }
+
+    #[test]
+    fn test_chart_format_follows_extension() {
+        assert_eq!(
+            ChartExportFormat::from_path(Path::new("charts.PNG")),
+            Some(ChartExportFormat::Png)
+        );
+        assert_eq!(
+            ChartExportFormat::from_path(Path::new("out/charts.svg")),
+            Some(ChartExportFormat::Svg)
+        );
+        assert_eq!(ChartExportFormat::from_path(Path::new("charts.tex")), None);
+    }
+
+    #[test]
+    fn test_svg_charts_draw_every_series_with_legend() {
+        let svg = render_charts_svg(&sample_charts());
+
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains(&format!("height=\"{}\"", 2 * CHART_HEIGHT)));
+        assert_eq!(svg.matches("class=\"title\"").count(), 2);
+        assert_eq!(svg.matches("class=\"series\"").count(), 3);
+        assert!(svg.contains("Average &lt;conflicts&gt;"));
+        assert!(svg.contains("stroke=\"#5fdc8c\""));
+    }
+
+    #[test]
+    fn test_png_charts_stack_vertically() {
+        let png = render_charts_png(&sample_charts()).unwrap();
+
+        let decoder = png::Decoder::new(Cursor::new(png));
+        let reader = decoder.read_info().unwrap();
+        let info = reader.info();
+        assert_eq!(info.width, CHART_WIDTH);
+        assert_eq!(info.height, 2 * CHART_HEIGHT);
+    }
+}
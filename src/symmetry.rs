@@ -0,0 +1,521 @@
+use std::fmt;
+
+use rand::{Rng, RngExt, seq::SliceRandom};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SymmetryConstraint {
+    #[default]
+    None,
+    Rotation180,
+    Rotation90,
+    Reflection,
+}
+
+impl fmt::Display for SymmetryConstraint {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => formatter.write_str("none"),
+            Self::Rotation180 => formatter.write_str("rot180"),
+            Self::Rotation90 => formatter.write_str("rot90"),
+            Self::Reflection => formatter.write_str("reflection"),
+        }
+    }
+}
+
+impl SymmetryConstraint {
+    // Whether any solution of this size can have the symmetry. A reflection-symmetric board
+    // puts every queen pair (x, y) and (y, x) on one anti-diagonal, so only size 1 qualifies.
+    pub fn supports_size(self, size: u16) -> bool {
+        match self {
+            Self::Rotation90 => size % 4 <= 1,
+            Self::Reflection => size <= 1,
+            Self::None | Self::Rotation180 => true,
+        }
+    }
+
+    // Cells that must hold a queen together with `(x, y)` on a board invariant under `self`.
+    // Reflection is about the main diagonal, the only reflection a permutation can satisfy.
+    pub fn orbit(self, size: u16, x: u16, y: u16) -> Vec<(u16, u16)> {
+        let last = size - 1;
+        let mut cells = match self {
+            Self::None => vec![(x, y)],
+            Self::Rotation180 => vec![(x, y), (last - x, last - y)],
+            Self::Rotation90 => vec![(x, y), (y, last - x), (last - x, last - y), (last - y, x)],
+            Self::Reflection => vec![(x, y), (y, x)],
+        };
+        cells.sort_unstable();
+        cells.dedup();
+        cells
+    }
+}
+
+pub fn is_invariant(positions: &[u16], symmetry: SymmetryConstraint) -> bool {
+    let Ok(size) = u16::try_from(positions.len()) else {
+        return false;
+    };
+    if size == 0 {
+        return false;
+    }
+
+    positions.iter().enumerate().all(|(x, &y)| {
+        let x = x as u16;
+        y < size
+            && symmetry
+                .orbit(size, x, y)
+                .into_iter()
+                .all(|(orbit_x, orbit_y)| positions.get(usize::from(orbit_x)) == Some(&orbit_y))
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DihedralTransform {
+    Identity,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+    FlipHorizontal,
+    FlipVertical,
+    FlipDiagonal,
+    FlipAntiDiagonal,
+}
+
+impl fmt::Display for DihedralTransform {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Identity => formatter.write_str("identity"),
+            Self::Rotate90 => formatter.write_str("rot90"),
+            Self::Rotate180 => formatter.write_str("rot180"),
+            Self::Rotate270 => formatter.write_str("rot270"),
+            Self::FlipHorizontal => formatter.write_str("flip-horizontal"),
+            Self::FlipVertical => formatter.write_str("flip-vertical"),
+            Self::FlipDiagonal => formatter.write_str("flip-diagonal"),
+            Self::FlipAntiDiagonal => formatter.write_str("flip-anti-diagonal"),
+        }
+    }
+}
+
+impl DihedralTransform {
+    pub const ALL: [Self; 8] = [
+        Self::Identity,
+        Self::Rotate90,
+        Self::Rotate180,
+        Self::Rotate270,
+        Self::FlipHorizontal,
+        Self::FlipVertical,
+        Self::FlipDiagonal,
+        Self::FlipAntiDiagonal,
+    ];
+
+    pub fn apply(self, positions: &[u16]) -> Vec<u16> {
+        let size = positions.len();
+        let last = u16::try_from(size.saturating_sub(1)).expect("board size should fit into u16");
+        let mut transformed = vec![0; size];
+
+        for (x, &y) in positions.iter().enumerate() {
+            assert!(usize::from(y) < size, "positions must stay on the board");
+            let x = x as u16;
+            let (new_x, new_y) = match self {
+                Self::Identity => (x, y),
+                Self::Rotate90 => (last - y, x),
+                Self::Rotate180 => (last - x, last - y),
+                Self::Rotate270 => (y, last - x),
+                Self::FlipHorizontal => (last - x, y),
+                Self::FlipVertical => (x, last - y),
+                Self::FlipDiagonal => (y, x),
+                Self::FlipAntiDiagonal => (last - y, last - x),
+            };
+            transformed[usize::from(new_x)] = new_y;
+        }
+
+        transformed
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SymmetryGroup {
+    Trivial,
+    Rotation180,
+    Rotation90,
+    DiagonalReflection,
+    AntiDiagonalReflection,
+    BothDiagonalReflections,
+    Full,
+}
+
+impl fmt::Display for SymmetryGroup {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Trivial => formatter.write_str("none"),
+            Self::Rotation180 => formatter.write_str("rot180"),
+            Self::Rotation90 => formatter.write_str("rot90"),
+            Self::DiagonalReflection => formatter.write_str("diagonal"),
+            Self::AntiDiagonalReflection => formatter.write_str("anti-diagonal"),
+            Self::BothDiagonalReflections => formatter.write_str("both-diagonals"),
+            Self::Full => formatter.write_str("full"),
+        }
+    }
+}
+
+impl SymmetryGroup {
+    pub fn order(self) -> usize {
+        match self {
+            Self::Trivial => 1,
+            Self::Rotation180 | Self::DiagonalReflection | Self::AntiDiagonalReflection => 2,
+            Self::Rotation90 | Self::BothDiagonalReflections => 4,
+            Self::Full => 8,
+        }
+    }
+
+    // Number of distinct boards produced by applying every dihedral transform.
+    pub fn orbit_size(self) -> usize {
+        8 / self.order()
+    }
+}
+
+pub fn dihedral_transforms(positions: &[u16]) -> Vec<Vec<u16>> {
+    DihedralTransform::ALL
+        .iter()
+        .map(|transform| transform.apply(positions))
+        .collect()
+}
+
+pub fn canonical_positions(positions: &[u16]) -> Vec<u16> {
+    dihedral_transforms(positions)
+        .into_iter()
+        .min()
+        .unwrap_or_default()
+}
+
+pub fn classify(positions: &[u16]) -> SymmetryGroup {
+    let fixed_by = |transform: DihedralTransform| transform.apply(positions) == positions;
+
+    let rotation90 = fixed_by(DihedralTransform::Rotate90);
+    let rotation180 = fixed_by(DihedralTransform::Rotate180);
+    let diagonal = fixed_by(DihedralTransform::FlipDiagonal);
+    let anti_diagonal = fixed_by(DihedralTransform::FlipAntiDiagonal);
+
+    match (rotation90, rotation180, diagonal, anti_diagonal) {
+        (true, _, true, _) => SymmetryGroup::Full,
+        (true, _, false, _) => SymmetryGroup::Rotation90,
+        (false, true, true, _) => SymmetryGroup::BothDiagonalReflections,
+        (false, true, false, _) => SymmetryGroup::Rotation180,
+        (false, false, true, _) => SymmetryGroup::DiagonalReflection,
+        (false, false, false, true) => SymmetryGroup::AntiDiagonalReflection,
+        (false, false, false, false) => SymmetryGroup::Trivial,
+    }
+}
+
+// Symmetric boards are searched through a smaller genome that expands into full positions.
+// Rotation180 keeps a permutation of mirrored row pairs for the leading columns plus a flip per
+// column choosing the low or high row of the pair. Rotation90 keeps a permutation whose
+// consecutive entries are mirrored column pairs, with a flip per pair. Reflection only supports
+// the one-queen board, so it shares the plain genome of an unconstrained search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ReducedGenome {
+    pub(crate) pairs: Vec<u16>,
+    pub(crate) flips: Vec<bool>,
+}
+
+pub(crate) fn random_positions(
+    size: u16,
+    symmetry: SymmetryConstraint,
+    rng: &mut impl Rng,
+) -> Vec<u16> {
+    assert!(size > 0, "board size must be greater than 0");
+    assert!(
+        symmetry.supports_size(size),
+        "board size must support the symmetry constraint"
+    );
+
+    let mut pairs = (0..reduced_pair_count(size, symmetry)).collect::<Vec<_>>();
+    pairs.shuffle(rng);
+    let flips = (0..reduced_flip_count(size, symmetry))
+        .map(|_| rng.random::<bool>())
+        .collect();
+
+    expand(size, symmetry, &ReducedGenome { pairs, flips })
+}
+
+pub(crate) fn mutate_positions(
+    positions: &[u16],
+    symmetry: SymmetryConstraint,
+    rng: &mut impl Rng,
+) -> Vec<u16> {
+    let size = u16::try_from(positions.len()).expect("board size should fit into u16");
+    let mut genome = reduce(positions, symmetry);
+    let pair_count = genome.pairs.len();
+    let can_swap = pair_count >= 2;
+    let can_flip = !genome.flips.is_empty();
+
+    if can_swap && (!can_flip || rng.random::<bool>()) {
+        let index_one = rng.random_range(0..pair_count);
+        let mut index_two = rng.random_range(0..(pair_count - 1));
+        if index_two >= index_one {
+            index_two += 1;
+        }
+        genome.pairs.swap(index_one, index_two);
+    } else if can_flip {
+        let index = rng.random_range(0..genome.flips.len());
+        genome.flips[index] = !genome.flips[index];
+    }
+
+    expand(size, symmetry, &genome)
+}
+
+pub(crate) fn reduce(positions: &[u16], symmetry: SymmetryConstraint) -> ReducedGenome {
+    let size = u16::try_from(positions.len()).expect("board size should fit into u16");
+    let last = size.saturating_sub(1);
+
+    match symmetry {
+        SymmetryConstraint::None | SymmetryConstraint::Reflection => ReducedGenome {
+            pairs: positions.to_vec(),
+            flips: Vec::new(),
+        },
+        SymmetryConstraint::Rotation180 => {
+            let (pairs, flips) = positions[..usize::from(size / 2)]
+                .iter()
+                .map(|&y| (y.min(last - y), y > last - y))
+                .unzip();
+            ReducedGenome { pairs, flips }
+        }
+        SymmetryConstraint::Rotation90 => {
+            let mut pairs = Vec::with_capacity(usize::from(size / 2));
+            let mut flips = Vec::with_capacity(usize::from(size / 4));
+            let mut matched = vec![false; usize::from(size / 2)];
+            for x in 0..size / 2 {
+                if matched[usize::from(x)] {
+                    continue;
+                }
+
+                let y = positions[usize::from(x)];
+                let mirrored_pair = y.min(last - y);
+                matched[usize::from(mirrored_pair)] = true;
+                pairs.push(x);
+                pairs.push(mirrored_pair);
+                flips.push(y > last - y);
+            }
+            ReducedGenome { pairs, flips }
+        }
+    }
+}
+
+pub(crate) fn expand(size: u16, symmetry: SymmetryConstraint, genome: &ReducedGenome) -> Vec<u16> {
+    let last = size.saturating_sub(1);
+    let mut positions = vec![0; usize::from(size)];
+    let mut place_orbit = |x: u16, y: u16| {
+        for (orbit_x, orbit_y) in symmetry.orbit(size, x, y) {
+            positions[usize::from(orbit_x)] = orbit_y;
+        }
+    };
+
+    match symmetry {
+        SymmetryConstraint::None | SymmetryConstraint::Reflection => {
+            for (x, &y) in genome.pairs.iter().enumerate() {
+                place_orbit(x as u16, y);
+            }
+        }
+        SymmetryConstraint::Rotation180 => {
+            place_orbit(size / 2, size / 2);
+            for (x, (&pair, &flip)) in genome.pairs.iter().zip(&genome.flips).enumerate() {
+                place_orbit(x as u16, if flip { last - pair } else { pair });
+            }
+        }
+        SymmetryConstraint::Rotation90 => {
+            place_orbit(size / 2, size / 2);
+            for (pair, &flip) in genome.pairs.chunks_exact(2).zip(&genome.flips) {
+                place_orbit(pair[0], if flip { last - pair[1] } else { pair[1] });
+            }
+        }
+    }
+
+    positions
+}
+
+fn reduced_pair_count(size: u16, symmetry: SymmetryConstraint) -> u16 {
+    match symmetry {
+        SymmetryConstraint::None | SymmetryConstraint::Reflection => size,
+        SymmetryConstraint::Rotation180 | SymmetryConstraint::Rotation90 => size / 2,
+    }
+}
+
+fn reduced_flip_count(size: u16, symmetry: SymmetryConstraint) -> u16 {
+    match symmetry {
+        SymmetryConstraint::None | SymmetryConstraint::Reflection => 0,
+        SymmetryConstraint::Rotation180 => size / 2,
+        SymmetryConstraint::Rotation90 => size / 4,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use rand::{RngExt, SeedableRng, rngs::StdRng, seq::SliceRandom};
+
+    use super::{
+        DihedralTransform, SymmetryConstraint, SymmetryGroup, canonical_positions, classify,
+        dihedral_transforms, expand, is_invariant, mutate_positions, random_positions, reduce,
+    };
+    use crate::exact;
+
+    const CONSTRAINTS: [SymmetryConstraint; 4] = [
+        SymmetryConstraint::None,
+        SymmetryConstraint::Rotation180,
+        SymmetryConstraint::Rotation90,
+        SymmetryConstraint::Reflection,
+    ];
+
+    fn assert_permutation(positions: &[u16]) {
+        let mut sorted = positions.to_vec();
+        sorted.sort_unstable();
+        assert_eq!(sorted, (0..positions.len() as u16).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_rotation90_requires_compatible_board_size() {
+        assert!(SymmetryConstraint::Rotation90.supports_size(1));
+        assert!(SymmetryConstraint::Rotation90.supports_size(4));
+        assert!(SymmetryConstraint::Rotation90.supports_size(5));
+        assert!(!SymmetryConstraint::Rotation90.supports_size(6));
+        assert!(!SymmetryConstraint::Rotation90.supports_size(7));
+        assert!(SymmetryConstraint::Rotation180.supports_size(7));
+        assert!(SymmetryConstraint::Reflection.supports_size(1));
+        assert!(!SymmetryConstraint::Reflection.supports_size(2));
+        assert!(!SymmetryConstraint::Reflection.supports_size(8));
+    }
+
+    #[test]
+    fn test_is_invariant_detects_symmetric_solutions() {
+        let rotation90 = [1, 3, 0, 2];
+        assert!(is_invariant(&rotation90, SymmetryConstraint::Rotation90));
+        assert!(is_invariant(&rotation90, SymmetryConstraint::Rotation180));
+        assert!(!is_invariant(&rotation90, SymmetryConstraint::Reflection));
+
+        let rotation180 = [4, 2, 0, 5, 3, 1];
+        assert!(is_invariant(&rotation180, SymmetryConstraint::Rotation180));
+        assert!(!is_invariant(&rotation180, SymmetryConstraint::Rotation90));
+
+        assert!(is_invariant(&[1, 0, 2], SymmetryConstraint::Reflection));
+    }
+
+    #[test]
+    fn test_reduce_inverts_expand_for_rotations() {
+        let positions = vec![1, 4, 2, 0, 3];
+        let genome = reduce(&positions, SymmetryConstraint::Rotation90);
+
+        assert_eq!(genome.pairs, vec![0, 1]);
+        assert_eq!(genome.flips, vec![false]);
+        assert_eq!(
+            expand(5, SymmetryConstraint::Rotation90, &genome),
+            positions
+        );
+    }
+
+    #[test]
+    fn test_dihedral_transforms_of_asymmetric_solution_are_distinct() {
+        let positions = [0, 4, 7, 5, 2, 6, 1, 3];
+        let mut transforms = dihedral_transforms(&positions);
+
+        assert_eq!(transforms[0], positions);
+        assert_eq!(
+            DihedralTransform::Rotate90.apply(&DihedralTransform::Rotate270.apply(&positions)),
+            positions
+        );
+        assert_eq!(
+            DihedralTransform::Rotate90.apply(&DihedralTransform::Rotate90.apply(&positions)),
+            DihedralTransform::Rotate180.apply(&positions)
+        );
+
+        transforms.sort_unstable();
+        transforms.dedup();
+        assert_eq!(transforms.len(), 8);
+        assert_eq!(classify(&positions), SymmetryGroup::Trivial);
+    }
+
+    #[test]
+    fn test_classify_matches_symmetry_of_known_boards() {
+        assert_eq!(classify(&[1, 3, 0, 2]), SymmetryGroup::Rotation90);
+        assert_eq!(classify(&[4, 2, 0, 5, 3, 1]), SymmetryGroup::Rotation180);
+        assert_eq!(classify(&[1, 0, 2]), SymmetryGroup::DiagonalReflection);
+        assert_eq!(classify(&[0, 1]), SymmetryGroup::BothDiagonalReflections);
+        assert_eq!(classify(&[0]), SymmetryGroup::Full);
+        assert_eq!(SymmetryGroup::Rotation90.orbit_size(), 2);
+    }
+
+    #[test]
+    fn test_canonical_positions_count_fundamental_solutions() {
+        let mut canonical = exact::find_solutions(8, SymmetryConstraint::None, usize::MAX)
+            .iter()
+            .map(|positions| canonical_positions(positions))
+            .collect::<Vec<_>>();
+        canonical.sort_unstable();
+        canonical.dedup();
+
+        assert_eq!(canonical.len(), 12);
+        let orbit_total = canonical
+            .iter()
+            .map(|positions| classify(positions).orbit_size())
+            .sum::<usize>();
+        assert_eq!(orbit_total, 92);
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(128))]
+
+        #[test]
+        fn prop_random_and_mutated_positions_stay_invariant(
+            size in 1u16..48,
+            seed in any::<u64>(),
+        ) {
+            let mut rng = StdRng::seed_from_u64(seed);
+            for symmetry in CONSTRAINTS {
+                if !symmetry.supports_size(size) {
+                    continue;
+                }
+
+                let positions = random_positions(size, symmetry, &mut rng);
+                assert_permutation(&positions);
+                prop_assert!(is_invariant(&positions, symmetry));
+                prop_assert_eq!(
+                    &expand(size, symmetry, &reduce(&positions, symmetry)),
+                    &positions
+                );
+
+                let mutated = mutate_positions(&positions, symmetry, &mut rng);
+                assert_permutation(&mutated);
+                prop_assert!(is_invariant(&mutated, symmetry));
+            }
+        }
+
+        // Builds the invariant board directly, so shapes `random_positions` never produces
+        // still have to survive a round trip.
+        #[test]
+        fn prop_hand_built_invariant_boards_round_trip(
+            size in 1u16..48,
+            seed in any::<u64>(),
+        ) {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let last = size - 1;
+            let mut low_rows = (0..size / 2).collect::<Vec<_>>();
+            low_rows.shuffle(&mut rng);
+            let mut positions = vec![size / 2; usize::from(size)];
+            for (x, &low_row) in low_rows.iter().enumerate() {
+                let x = x as u16;
+                let y = if rng.random::<bool>() { low_row } else { last - low_row };
+                positions[usize::from(x)] = y;
+                positions[usize::from(last - x)] = last - y;
+            }
+
+            let symmetry = SymmetryConstraint::Rotation180;
+            assert_permutation(&positions);
+            prop_assert!(is_invariant(&positions, symmetry));
+            prop_assert_eq!(
+                &expand(size, symmetry, &reduce(&positions, symmetry)),
+                &positions
+            );
+
+            let mutated = mutate_positions(&positions, symmetry, &mut rng);
+            assert_permutation(&mutated);
+            prop_assert!(is_invariant(&mutated, symmetry));
+        }
+    }
+}
@@ -1,62 +1,535 @@
+use std::{
+    env, fmt,
+    fmt::Write as _,
+    io::{self, IsTerminal},
+};
+
+pub const DEFAULT_TERMINAL_WIDTH: usize = 80;
+pub const DEFAULT_WINDOW_SIZE: usize = 24;
+const MAX_BOXED_SIZE: usize = 30;
+
+const ANSI_RESET: &str = "\x1b[0m";
+const ANSI_GREEN: &str = "\x1b[32m";
+const ANSI_YELLOW: &str = "\x1b[33m";
+const ANSI_RED: &str = "\x1b[31m";
+
+const BRAILLE_BASE: u32 = 0x2800;
+// Braille dot bits indexed by [row][column] inside one 2x4 character cell.
+const BRAILLE_DOTS: [[u8; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BoardStyle {
+    #[default]
+    Auto,
+    Boxed,
+    Compact,
+    Braille,
+    HalfBlock,
+    Window,
+}
+
+impl fmt::Display for BoardStyle {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Auto => formatter.write_str("auto"),
+            Self::Boxed => formatter.write_str("boxed"),
+            Self::Compact => formatter.write_str("compact"),
+            Self::Braille => formatter.write_str("braille"),
+            Self::HalfBlock => formatter.write_str("half-block"),
+            Self::Window => formatter.write_str("window"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorMode {
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl fmt::Display for ColorMode {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Auto => formatter.write_str("auto"),
+            Self::Always => formatter.write_str("always"),
+            Self::Never => formatter.write_str("never"),
+        }
+    }
+}
+
+impl ColorMode {
+    pub fn enabled(self) -> bool {
+        match self {
+            Self::Auto => io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none(),
+            Self::Always => true,
+            Self::Never => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TerminalOptions {
+    style: BoardStyle,
+    width: usize,
+    color: bool,
+    window_size: usize,
+}
+
+impl Default for TerminalOptions {
+    fn default() -> Self {
+        Self {
+            style: BoardStyle::Auto,
+            width: DEFAULT_TERMINAL_WIDTH,
+            color: false,
+            window_size: DEFAULT_WINDOW_SIZE,
+        }
+    }
+}
+
+impl TerminalOptions {
+    pub fn detect() -> Self {
+        Self::default()
+            .with_width(terminal_width())
+            .with_color(ColorMode::Auto.enabled())
+    }
+
+    pub fn with_style(mut self, style: BoardStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_width(mut self, width: usize) -> Self {
+        self.width = width.max(1);
+        self
+    }
+
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn with_window_size(mut self, window_size: usize) -> Self {
+        self.window_size = window_size.max(1);
+        self
+    }
+
+    pub fn style(&self) -> BoardStyle {
+        self.style
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn color(&self) -> bool {
+        self.color
+    }
+
+    pub fn window_size(&self) -> usize {
+        self.window_size
+    }
+
+    // The style `Auto` settles on: the framed board while it fits, then one character per
+    // cell, then a braille density plot.
+    pub fn resolved_style(&self, size: usize) -> BoardStyle {
+        if self.style != BoardStyle::Auto {
+            return self.style;
+        }
+
+        if size <= MAX_BOXED_SIZE && boxed_width(size) <= self.width {
+            BoardStyle::Boxed
+        } else if size <= self.width {
+            BoardStyle::Compact
+        } else {
+            BoardStyle::Braille
+        }
+    }
+}
+
+// Width of the controlling terminal, falling back to `COLUMNS` and then a fixed default
+// when stdout is redirected.
+pub fn terminal_width() -> usize {
+    if let Some((terminal_size::Width(width), _)) = terminal_size::terminal_size() {
+        return usize::from(width);
+    }
+
+    env::var("COLUMNS")
+        .ok()
+        .and_then(|columns| columns.trim().parse().ok())
+        .filter(|&columns| columns > 0)
+        .unwrap_or(DEFAULT_TERMINAL_WIDTH)
+}
+
 pub fn draw_board(positions: &[u16], conflicts: &[u32]) {
+    draw_board_with(positions, conflicts, &TerminalOptions::detect());
+}
+
+pub fn draw_board_with(positions: &[u16], conflicts: &[u32], options: &TerminalOptions) {
+    print!("{}", render_board(positions, conflicts, options));
+}
+
+pub fn render_board(positions: &[u16], conflicts: &[u32], options: &TerminalOptions) -> String {
     let size = positions.len();
     if size == 0 {
-        println!("(empty board)");
-        return;
+        return "(empty board)\n".to_owned();
+    }
+
+    let mut output = String::new();
+    match options.resolved_style(size) {
+        BoardStyle::Auto | BoardStyle::Boxed => {
+            render_boxed(&mut output, positions, conflicts, options.color)
+        }
+        BoardStyle::Compact => render_compact(&mut output, positions, conflicts, options.color),
+        BoardStyle::Braille => render_braille(&mut output, positions, conflicts, options),
+        BoardStyle::HalfBlock => render_half_block(&mut output, positions, conflicts, options),
+        BoardStyle::Window => render_window(&mut output, positions, conflicts, options),
+    }
+    output
+}
+
+fn boxed_width(size: usize) -> usize {
+    5 * size + 1
+}
+
+fn conflict_at(conflicts: &[u32], x: usize) -> u32 {
+    conflicts.get(x).copied().unwrap_or_default()
+}
+
+fn row_at(positions: &[u16], x: usize) -> usize {
+    usize::from(positions.get(x).copied().unwrap_or_default())
+}
+
+fn conflict_color(conflicts: u32) -> &'static str {
+    match conflicts {
+        0 => ANSI_GREEN,
+        1 => ANSI_YELLOW,
+        _ => ANSI_RED,
     }
+}
 
-    if size > 30 {
-        println!("(board too large to print)");
-        return;
+fn push_colored(output: &mut String, text: &str, conflicts: Option<u32>, color: bool) {
+    match conflicts {
+        Some(conflicts) if color => {
+            output.push_str(conflict_color(conflicts));
+            output.push_str(text);
+            output.push_str(ANSI_RESET);
+        }
+        _ => output.push_str(text),
     }
+}
 
-    draw_top_row(size);
+fn render_boxed(output: &mut String, positions: &[u16], conflicts: &[u32], color: bool) {
+    let size = positions.len();
+    push_rule(output, size, "╔══", "══╤══", "══╗");
     for y in 0..size {
-        print!("║ ");
+        output.push_str("║ ");
         for x in 0..size {
-            let y_position = *positions.get(x).unwrap_or(&0) as usize;
-            if y_position == y {
-                let current_conflicts = conflicts.get(x).unwrap_or(&0);
-                print!("{current_conflicts:0>2}");
+            if row_at(positions, x) == y {
+                let current_conflicts = conflict_at(conflicts, x);
+                push_colored(
+                    output,
+                    &format!("{current_conflicts:0>2}"),
+                    Some(current_conflicts),
+                    color,
+                );
             } else {
-                print!("  ");
+                output.push_str("  ");
             }
             if x < size - 1 {
-                print!(" │ ")
+                output.push_str(" │ ");
             } else {
-                println!(" ║")
+                output.push_str(" ║\n");
             }
         }
         if y < size - 1 {
-            draw_middle_row(size);
+            push_rule(output, size, "╟──", "──┼──", "──╢");
         }
     }
-    draw_bottom_row(size);
+    push_rule(output, size, "╚══", "══╧══", "══╝");
 }
 
-fn draw_top_row(size: usize) {
-    let mut s = String::from("╔══");
+fn push_rule(output: &mut String, size: usize, left: &str, middle: &str, right: &str) {
+    output.push_str(left);
     for _ in 0..(size - 1) {
-        s.push_str("══╤══");
+        output.push_str(middle);
     }
-    s.push_str("══╗");
-    println!("{s}");
+    output.push_str(right);
+    output.push('\n');
 }
 
-fn draw_middle_row(size: usize) {
-    let mut s = String::from("╟──");
-    for _ in 0..(size - 1) {
-        s.push_str("──┼──");
+fn compact_cell(conflicts: u32) -> char {
+    match conflicts {
+        0 => 'Q',
+        1..=9 => char::from_digit(conflicts, 10).unwrap_or('+'),
+        _ => '+',
     }
-    s.push_str("──╢");
-    println!("{s}");
 }
 
-fn draw_bottom_row(size: usize) {
-    let mut s = String::from("╚══");
-    for _ in 0..(size - 1) {
-        s.push_str("══╧══");
+fn push_compact_row(
+    output: &mut String,
+    positions: &[u16],
+    conflicts: &[u32],
+    y: usize,
+    columns: std::ops::Range<usize>,
+    color: bool,
+) {
+    for x in columns {
+        if row_at(positions, x) == y {
+            let current_conflicts = conflict_at(conflicts, x);
+            let cell = compact_cell(current_conflicts).to_string();
+            push_colored(output, &cell, Some(current_conflicts), color);
+        } else {
+            output.push('.');
+        }
+    }
+    output.push('\n');
+}
+
+fn render_compact(output: &mut String, positions: &[u16], conflicts: &[u32], color: bool) {
+    let size = positions.len();
+    for y in 0..size {
+        push_compact_row(output, positions, conflicts, y, 0..size, color);
+    }
+}
+
+// Board cells covered by one plot dot so the plot is at most `max_dots` dots wide.
+fn density_scale(size: usize, max_dots: usize) -> usize {
+    size.div_ceil(max_dots.max(1)).max(1)
+}
+
+fn push_scale_note(output: &mut String, size: usize, scale: usize) {
+    if scale > 1 {
+        let _ = writeln!(
+            output,
+            "({size}x{size} board, each dot covers {scale}x{scale} cells)"
+        );
+    }
+}
+
+// Worst conflict count per character cell, `None` where the cell holds no queen.
+struct DensityGrid {
+    columns: usize,
+    bits: Vec<u8>,
+    conflicts: Vec<Option<u32>>,
+}
+
+impl DensityGrid {
+    fn new(columns: usize, rows: usize) -> Self {
+        Self {
+            columns,
+            bits: vec![0; columns * rows],
+            conflicts: vec![None; columns * rows],
+        }
+    }
+
+    fn mark(&mut self, column: usize, row: usize, bit: u8, conflicts: u32) {
+        let index = row * self.columns + column;
+        self.bits[index] |= bit;
+        let worst = self.conflicts[index].get_or_insert(conflicts);
+        *worst = (*worst).max(conflicts);
+    }
+
+    fn write(&self, output: &mut String, color: bool, glyph: impl Fn(u8) -> char) {
+        for (row_bits, row_conflicts) in self
+            .bits
+            .chunks(self.columns)
+            .zip(self.conflicts.chunks(self.columns))
+        {
+            for (&bits, &conflicts) in row_bits.iter().zip(row_conflicts) {
+                push_colored(output, &glyph(bits).to_string(), conflicts, color);
+            }
+            output.push('\n');
+        }
+    }
+}
+
+fn render_braille(
+    output: &mut String,
+    positions: &[u16],
+    conflicts: &[u32],
+    options: &TerminalOptions,
+) {
+    let size = positions.len();
+    let scale = density_scale(size, options.width * 2);
+    let dots = size.div_ceil(scale);
+    let mut grid = DensityGrid::new(dots.div_ceil(2), dots.div_ceil(4));
+    for x in 0..size {
+        let (dot_x, dot_y) = (x / scale, row_at(positions, x) / scale);
+        grid.mark(
+            dot_x / 2,
+            dot_y / 4,
+            BRAILLE_DOTS[dot_y % 4][dot_x % 2],
+            conflict_at(conflicts, x),
+        );
+    }
+
+    push_scale_note(output, size, scale);
+    grid.write(output, options.color, |bits| {
+        char::from_u32(BRAILLE_BASE + u32::from(bits)).unwrap_or(' ')
+    });
+}
+
+fn render_half_block(
+    output: &mut String,
+    positions: &[u16],
+    conflicts: &[u32],
+    options: &TerminalOptions,
+) {
+    let size = positions.len();
+    let scale = density_scale(size, options.width);
+    let dots = size.div_ceil(scale);
+    let mut grid = DensityGrid::new(dots, dots.div_ceil(2));
+    for x in 0..size {
+        let (dot_x, dot_y) = (x / scale, row_at(positions, x) / scale);
+        grid.mark(
+            dot_x,
+            dot_y / 2,
+            1 << (dot_y % 2),
+            conflict_at(conflicts, x),
+        );
+    }
+
+    push_scale_note(output, size, scale);
+    grid.write(output, options.color, |bits| match bits {
+        0 => ' ',
+        1 => '▀',
+        2 => '▄',
+        _ => '█',
+    });
+}
+
+// Compact view of a square window centered on the queen with the most conflicts, or on the
+// middle of the board when it is already solved.
+fn render_window(
+    output: &mut String,
+    positions: &[u16],
+    conflicts: &[u32],
+    options: &TerminalOptions,
+) {
+    let size = positions.len();
+    let side = size.min(options.window_size).min(options.width);
+    let focus = (0..size)
+        .filter(|&x| conflict_at(conflicts, x) > 0)
+        .max_by_key(|&x| (conflict_at(conflicts, x), std::cmp::Reverse(x)));
+    let (center_x, center_y) = focus.map_or((size / 2, size / 2), |x| (x, row_at(positions, x)));
+    let left = center_x.saturating_sub(side / 2).min(size - side);
+    let top = center_y.saturating_sub(side / 2).min(size - side);
+
+    let _ = write!(
+        output,
+        "(columns {}-{}, rows {}-{} of {size}",
+        left,
+        left + side - 1,
+        top,
+        top + side - 1
+    );
+    match focus {
+        Some(x) => {
+            let _ = writeln!(
+                output,
+                ", centered on column {x} with {} conflicts)",
+                conflict_at(conflicts, x)
+            );
+        }
+        None => output.push_str(", no conflicts)\n"),
+    }
+    for y in top..top + side {
+        push_compact_row(
+            output,
+            positions,
+            conflicts,
+            y,
+            left..left + side,
+            options.color,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auto_style_falls_back_as_boards_grow() {
+        let options = TerminalOptions::default().with_width(80);
+
+        assert_eq!(options.resolved_style(8), BoardStyle::Boxed);
+        assert_eq!(options.resolved_style(20), BoardStyle::Compact);
+        assert_eq!(options.resolved_style(81), BoardStyle::Braille);
+        assert_eq!(
+            options.with_style(BoardStyle::Window).resolved_style(4),
+            BoardStyle::Window
+        );
+    }
+
+    #[test]
+    fn test_compact_and_colored_output_mark_conflicts() {
+        let positions = [1, 0, 2, 3];
+        let conflicts = [0, 1, 2, 1];
+        let options = TerminalOptions::default().with_style(BoardStyle::Compact);
+
+        assert_eq!(
+            render_board(&positions, &conflicts, &options),
+            ".1..\nQ...\n..2.\n...1\n"
+        );
+
+        let colored = render_board(&positions, &conflicts, &options.with_color(true));
+        assert!(colored.contains(&format!("{ANSI_RED}2{ANSI_RESET}")));
+        assert!(colored.contains(&format!("{ANSI_GREEN}Q{ANSI_RESET}")));
+    }
+
+    #[test]
+    fn test_density_plots_scale_to_terminal_width() {
+        let size = 1_000;
+        let positions = (0..size as u16).collect::<Vec<_>>();
+        let conflicts = vec![0; size];
+        let options = TerminalOptions::default().with_width(50);
+
+        let braille = render_board(
+            &positions,
+            &conflicts,
+            &options.with_style(BoardStyle::Braille),
+        );
+        let mut lines = braille.lines();
+        assert_eq!(
+            lines.next(),
+            Some("(1000x1000 board, each dot covers 10x10 cells)")
+        );
+        assert!(lines.all(|line| line.chars().count() == 50));
+
+        let half_block = render_board(
+            &positions,
+            &conflicts,
+            &options.with_style(BoardStyle::HalfBlock),
+        );
+        assert_eq!(half_block.lines().count(), 1 + 25);
+        assert!(
+            half_block
+                .lines()
+                .skip(1)
+                .all(|line| line.chars().count() == 50)
+        );
+    }
+
+    #[test]
+    fn test_window_centers_on_most_conflicted_queen() {
+        let mut positions = (0..100).collect::<Vec<u16>>();
+        positions.swap(60, 61);
+        let mut conflicts = vec![0; 100];
+        conflicts[60] = 3;
+        conflicts[61] = 1;
+        let options = TerminalOptions::default()
+            .with_style(BoardStyle::Window)
+            .with_window_size(10);
+
+        let output = render_board(&positions, &conflicts, &options);
+        let mut lines = output.lines();
+        assert_eq!(
+            lines.next(),
+            Some("(columns 55-64, rows 56-65 of 100, centered on column 60 with 3 conflicts)")
+        );
+        assert_eq!(lines.count(), 10);
     }
-    s.push_str("══╝");
-    println!("{s}");
 }
@@ -202,6 +202,30 @@ fn cli_accepts_tournament_selection() {
     assert!(String::from_utf8_lossy(&output.stdout).contains("Best  ="));
 }
 
+#[test]
+fn cli_accepts_entropy_diversity_measure() {
+    let output = run_success(&[
+        "--size",
+        "8",
+        "--population",
+        "24",
+        "--epochs",
+        "3",
+        "--seed",
+        "42",
+        "--min-diversity-ratio",
+        "0.50",
+        "--diversity-measure",
+        "entropy",
+        "--no-board",
+    ]);
+
+    assert!(String::from_utf8_lossy(&output.stdout).contains("Best  ="));
+
+    let rejected = run_command(&["--size", "8", "--diversity-measure", "variance"]);
+    assert_eq!(rejected.status.code(), Some(2));
+}
+
 #[test]
 fn json_mode_emits_machine_readable_summary() {
     let metrics_path = temp_metrics_path("json_metrics");
@@ -253,12 +277,22 @@ fn json_mode_emits_machine_readable_summary() {
     assert_eq!(summary["tournament_size"], 3);
     assert_eq!(summary["local_search_rate"], 0.0);
     assert_eq!(summary["local_search_attempts"], 8);
+    assert_eq!(summary["symmetry"], "none");
     assert!(summary["final_unique_chromosomes"].is_number());
     assert!(summary["final_diversity_ratio"].is_number());
     assert!(summary["last_local_search_improvements"].is_number());
     assert!(summary["last_diversity_replacements"].is_number());
     assert_eq!(summary["metrics_csv"], metrics_path_string);
     assert!(summary["elapsed_ms"].is_number());
+    assert!(summary["phase_us"]["fitness"].is_number());
+    assert_eq!(summary["diversity_measure"], "unique");
+    assert!(summary["final_gene_entropy"].is_number());
+    assert!(summary["final_mean_pairwise_distance"].is_number());
+    assert!(summary["final_mean_best_distance"].is_number());
+    assert!(summary["phase_us"]["diversity"].is_number());
+    assert!(summary["max_evaluations"].is_null());
+    assert!(summary["evaluations"]["total"].as_u64().unwrap() >= 8);
+    assert_eq!(summary["evaluation_budget_reached"], false);
     assert!(summary["solved_epoch"].is_null() || summary["solved_epoch"].is_number());
     assert!(summary["best_chromosome"]["positions"].is_array());
     assert!(summary["best_chromosome"]["conflicts"].is_array());
@@ -268,6 +302,117 @@ fn json_mode_emits_machine_readable_summary() {
     fs::remove_file(&metrics_path).expect("temporary metrics CSV should be removable");
 }
 
+#[test]
+fn evaluation_budget_stops_run_early() {
+    let output = run_success(&[
+        "--size",
+        "40",
+        "--population",
+        "30",
+        "--epochs",
+        "1000",
+        "--seed",
+        "5",
+        "--max-evaluations",
+        "300",
+        "--json",
+    ]);
+    let summary = serde_json::from_slice::<Value>(&output.stdout).unwrap();
+
+    assert_eq!(summary["max_evaluations"], 300);
+    assert_eq!(summary["evaluation_budget_reached"], true);
+    assert!(summary["solved_epoch"].is_null());
+    let evaluations = &summary["evaluations"];
+    assert_eq!(evaluations["total"], 300);
+    assert_eq!(
+        evaluations["total"].as_u64(),
+        Some(evaluations["full"].as_u64().unwrap() + evaluations["swap_delta"].as_u64().unwrap())
+    );
+
+    let rejected = run_command(&["--size", "8", "--max-evaluations", "0"]);
+    assert_eq!(rejected.status.code(), Some(2));
+    let rejected = run_command(&[
+        "--size",
+        "8",
+        "--population",
+        "30",
+        "--max-evaluations",
+        "29",
+    ]);
+    assert_eq!(rejected.status.code(), Some(2));
+}
+
+#[test]
+fn restart_policy_options_reach_summary() {
+    let output = run_success(&[
+        "--size",
+        "12",
+        "--population",
+        "20",
+        "--epochs",
+        "40",
+        "--seed",
+        "3",
+        "--restart-interval",
+        "5",
+        "--restart-keep-ratio",
+        "0.25",
+        "--restart-kind",
+        "constructive",
+        "--no-adaptation",
+        "--json",
+    ]);
+    let summary = serde_json::from_slice::<Value>(&output.stdout).unwrap();
+
+    assert_eq!(summary["restart_interval"], 5);
+    assert_eq!(summary["restart_keep_ratio"], 0.25);
+    assert_eq!(summary["restart_kind"], "constructive");
+    assert_eq!(summary["adaptive"], false);
+
+    let rejected = run_command(&["--size", "8", "--restart-kind", "greedy"]);
+    assert_eq!(rejected.status.code(), Some(2));
+    let rejected = run_command(&[
+        "--size",
+        "8",
+        "--restart-kind",
+        "constructive",
+        "--symmetry",
+        "rot180",
+    ]);
+    assert_eq!(rejected.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&rejected.stderr).contains("constructive restarts"));
+}
+
+#[test]
+fn parameter_control_option_reaches_summary() {
+    let output = run_success(&[
+        "--size",
+        "10",
+        "--population",
+        "20",
+        "--epochs",
+        "20",
+        "--seed",
+        "8",
+        "--parameter-control",
+        "bandit",
+        "--json",
+    ]);
+    let summary = serde_json::from_slice::<Value>(&output.stdout).unwrap();
+    assert_eq!(summary["parameter_control"], "bandit");
+
+    let rejected = run_command(&["--size", "8", "--parameter-control", "annealing"]);
+    assert_eq!(rejected.status.code(), Some(2));
+    let rejected = run_command(&[
+        "--size",
+        "8",
+        "--parameter-control",
+        "bandit",
+        "--no-adaptation",
+    ]);
+    assert_eq!(rejected.status.code(), Some(2));
+}
+
 #[test]
 fn metrics_csv_contains_run_configuration_and_epochs() {
     let metrics_path = temp_metrics_path("metrics");
@@ -288,6 +433,19 @@ fn metrics_csv_contains_run_configuration_and_epochs() {
         "0.25",
         "--offspring-ratio",
         "0",
+        "--diversity-measure",
+        "entropy",
+        "--max-evaluations",
+        "1000",
+        "--restart-interval",
+        "5",
+        "--restart-keep-ratio",
+        "0.25",
+        "--restart-kind",
+        "perturb-best",
+        "--no-adaptation",
+        "--parameter-control",
+        "stagnation",
         "--no-board",
         "--metrics-csv",
         &metrics_path_string,
@@ -305,13 +463,20 @@ fn metrics_csv_contains_run_configuration_and_epochs() {
     let lines = csv.lines().collect::<Vec<_>>();
     assert_eq!(
         lines[0],
-        "seed,board_size,target_population,max_epochs,mutation_rate,elite_ratio,offspring_ratio,min_diversity_ratio,selection_strategy,tournament_size,local_search_rate,local_search_attempts,epoch,best_conflicts_sum,population_size,elapsed_ms,average_conflicts_sum,unique_chromosomes,diversity_ratio,epoch_mutation_rate,epoch_elite_ratio,offspring_count,local_search_improvements,stagnation_epochs,diversity_replacements"
+        "seed,board_size,target_population,max_epochs,mutation_rate,elite_ratio,offspring_ratio,min_diversity_ratio,selection_strategy,tournament_size,local_search_rate,local_search_attempts,count_symmetry_classes,diversity_measure,max_evaluations,restart_interval,restart_keep_ratio,restart_kind,adaptive,parameter_control,symmetry,epoch,best_conflicts_sum,population_size,elapsed_ms,average_conflicts_sum,unique_chromosomes,unique_symmetry_classes,diversity_ratio,gene_entropy,mean_pairwise_distance,mean_best_distance,epoch_mutation_rate,epoch_elite_ratio,offspring_count,local_search_improvements,stagnation_epochs,diversity_replacements,mating_us,mutation_us,local_search_us,selection_us,fitness_us,diversity_us,full_evaluations,swap_evaluations,evaluations,mutation_success_ratio,pmx_probability,order_probability,cycle_probability,swap_probability,inversion_probability,insertion_probability"
     );
     assert_eq!(lines.len(), 4);
-    assert!(lines[1].starts_with("42,4,8,2,0,0.25,0,0.1,roulette,3,0,8,0,"));
-    assert!(lines[2].starts_with("42,4,8,2,0,0.25,0,0.1,roulette,3,0,8,1,"));
-    assert!(lines[3].starts_with("42,4,8,2,0,0.25,0,0.1,roulette,3,0,8,2,"));
-    assert_eq!(lines[1].split(',').count(), 25);
+    assert!(lines[1].starts_with(
+        "42,4,8,2,0,0.25,0,0.1,roulette,3,0,8,false,entropy,1000,5,0.25,perturb-best,false,stagnation,none,0,"
+    ));
+    assert!(lines[2].starts_with(
+        "42,4,8,2,0,0.25,0,0.1,roulette,3,0,8,false,entropy,1000,5,0.25,perturb-best,false,stagnation,none,1,"
+    ));
+    assert!(lines[3].starts_with(
+        "42,4,8,2,0,0.25,0,0.1,roulette,3,0,8,false,entropy,1000,5,0.25,perturb-best,false,stagnation,none,2,"
+    ));
+    assert_eq!(lines[1].split(',').count(), 54);
+    assert!(lines[1].ends_with(",8,0,8,0,1,0,0,1,0,0"));
 }
 
 #[test]
@@ -354,3 +519,608 @@ fn unsolvable_board_sizes_do_not_report_solution() {
         );
     }
 }
+
+#[test]
+fn symmetry_constraint_finds_symmetric_solution() {
+    let output = run_success(&[
+        "--size",
+        "12",
+        "--population",
+        "64",
+        "--epochs",
+        "200",
+        "--seed",
+        "42",
+        "--symmetry",
+        "rot90",
+        "--local-search-rate",
+        "0.25",
+        "--json",
+    ]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let summary = serde_json::from_str::<Value>(&stdout)
+        .unwrap_or_else(|error| panic!("stdout should be valid JSON: {error}\n{stdout}"));
+
+    assert_eq!(summary["symmetry"], "rot90");
+    assert_eq!(summary["best_chromosome"]["conflicts_sum"], 0);
+
+    let positions = summary["best_chromosome"]["positions"]
+        .as_array()
+        .expect("positions should be an array")
+        .iter()
+        .map(|value| value.as_u64().expect("positions should be numbers"))
+        .collect::<Vec<_>>();
+    let last = positions.len() as u64 - 1;
+    for (x, &y) in positions.iter().enumerate() {
+        assert_eq!(positions[y as usize], last - x as u64);
+    }
+}
+
+#[test]
+fn cli_rejects_symmetry_unsupported_by_board_size() {
+    let output = run_command(&["--size", "6", "--symmetry", "rot90", "--no-board"]);
+
+    assert_eq!(output.status.code(), Some(2));
+    assert!(
+        output_text(&output).contains("no solution of this board size has the requested symmetry"),
+        "expected validation message, got:\n{}",
+        output_text(&output)
+    );
+}
+
+#[test]
+fn multiple_runs_report_new_solutions_up_to_symmetry() {
+    let output = run_success(&[
+        "--size",
+        "6",
+        "--population",
+        "64",
+        "--epochs",
+        "200",
+        "--seed",
+        "1",
+        "--runs",
+        "3",
+        "--json",
+    ]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let summary = serde_json::from_str::<Value>(&stdout)
+        .unwrap_or_else(|error| panic!("stdout should be valid JSON: {error}\n{stdout}"));
+    let runs = summary["runs"].as_array().expect("runs should be an array");
+
+    assert_eq!(runs.len(), 3);
+    assert_eq!(summary["solved_runs"], 3);
+    assert_eq!(summary["distinct_solutions"], 1);
+    assert_eq!(runs[0]["seed"], 1);
+    assert_eq!(runs[0]["new_solution"], true);
+    assert_eq!(runs[0]["symmetry_group"], "rot180");
+    for run in &runs[1..] {
+        assert_eq!(run["new_solution"], false);
+        assert_eq!(run["first_seed"], 1);
+        assert_eq!(run["canonical_positions"], runs[0]["canonical_positions"]);
+    }
+}
+
+#[test]
+fn cli_rejects_multiple_runs_with_metrics_csv() {
+    let output = run_command(&["--runs", "2", "--metrics-csv", "metrics.csv"]);
+
+    assert!(!output.status.success());
+    assert!(
+        output_text(&output).contains("cannot be used with"),
+        "expected conflict message, got:\n{}",
+        output_text(&output)
+    );
+}
+
+#[test]
+fn verify_distinguishes_solutions_conflicts_and_malformed_boards() {
+    let solution = run_command(&["verify", "2Q1/Q3/3Q/1Q2"]);
+    assert_eq!(solution.status.code(), Some(0));
+    assert!(output_text(&solution).contains("Result: solution"));
+
+    let conflicted = run_command(&["verify", "--json", "0,2,4,6,1,3,5,7"]);
+    assert_eq!(conflicted.status.code(), Some(1));
+    let report: Value =
+        serde_json::from_slice(&conflicted.stdout).expect("verify report should be valid JSON");
+    assert_eq!(report["valid"], true);
+    assert_eq!(report["conflicts_sum"], 1);
+    assert_eq!(
+        report["attacking_pairs"],
+        serde_json::json!([[[0, 0], [7, 7]]])
+    );
+
+    let duplicate = run_command(&["verify", "0,0,1"]);
+    assert_eq!(duplicate.status.code(), Some(2));
+    assert!(output_text(&duplicate).contains("chromosome positions must be unique"));
+
+    let unparsable = run_command(&["verify", "1,x,2"]);
+    assert_eq!(unparsable.status.code(), Some(2));
+    assert!(output_text(&unparsable).contains("invalid board"));
+}
+
+#[test]
+fn verify_accepts_json_summary_file() {
+    let output = run_success(&["-n", "8", "-p", "200", "-e", "200", "-s", "7", "--json"]);
+    let path = temp_metrics_path("verify_summary").with_extension("json");
+    fs::write(&path, &output.stdout).expect("summary should be writable");
+
+    let verify = run_command(&["verify", "--file", path.to_str().expect("utf-8 path")]);
+    fs::remove_file(&path).expect("summary should be removable");
+
+    let summary: Value = serde_json::from_slice(&output.stdout).expect("summary should be JSON");
+    let expected_code = if summary["best_chromosome"]["conflicts_sum"] == 0 {
+        0
+    } else {
+        1
+    };
+    assert_eq!(verify.status.code(), Some(expected_code));
+    assert!(output_text(&verify).contains("Valid Permutation: yes"));
+}
+
+#[test]
+fn solve_subcommand_matches_default_command() {
+    let args = [
+        "-n",
+        "8",
+        "-p",
+        "200",
+        "-e",
+        "200",
+        "-s",
+        "11",
+        "--quiet",
+        "--no-board",
+    ];
+    let default_output = run_success(&args);
+    let solve_output = run_success(&[&["solve"], &args[..]].concat());
+
+    assert_eq!(
+        stable_summary(&default_output),
+        stable_summary(&solve_output)
+    );
+}
+
+#[test]
+fn count_subcommand_reports_exact_totals() {
+    let output = run_success(&["count", "-n", "8", "--fundamental", "--list", "1", "--json"]);
+    let summary: Value = serde_json::from_slice(&output.stdout).expect("count should emit JSON");
+
+    assert_eq!(summary["solutions"], 92);
+    assert_eq!(summary["fundamental_solutions"], 12);
+    assert_eq!(summary["listed"].as_array().map(Vec::len), Some(1));
+
+    let output = run_success(&["count", "-n", "6", "--symmetry", "rot180"]);
+    assert!(output_text(&output).contains("Solutions: 4"));
+}
+
+#[test]
+fn render_subcommand_draws_parsed_board() {
+    let output = run_success(&["render", "--json", "1,3,0,2"]);
+    let summary: Value = serde_json::from_slice(&output.stdout).expect("render should emit JSON");
+    assert_eq!(
+        summary["rows"],
+        serde_json::json!(["..Q.", "Q...", "...Q", ".Q.."])
+    );
+
+    let output = run_command(&["render", "0,0"]);
+    assert_eq!(output.status.code(), Some(2));
+
+    let output = run_success(&[
+        "render",
+        "1,0,2,3",
+        "--board-style",
+        "compact",
+        "--color",
+        "never",
+    ]);
+    assert_eq!(output_text(&output), ".1..\n1...\n..1.\n...1\n");
+
+    let positions = (0..200)
+        .map(|x| x.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    let output = run_success(&["render", &positions, "--board-width", "40"]);
+    let stdout = output_text(&output);
+    let mut lines = stdout.lines();
+    assert_eq!(
+        lines.next(),
+        Some("(200x200 board, each dot covers 3x3 cells)")
+    );
+    assert!(lines.all(|line| line.chars().count() <= 40));
+}
+
+#[test]
+fn tui_requires_an_interactive_terminal() {
+    let output = run_command(&["-n", "6", "-s", "1", "--tui", "--json"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(output_text(&output).contains("--tui cannot be combined with --json"));
+
+    let output = run_command(&["-n", "6", "-s", "1", "--tui"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(output_text(&output).contains("--tui needs stdout to be an interactive terminal"));
+    assert!(output.stdout.is_empty());
+}
+
+#[test]
+fn export_board_writes_format_chosen_by_extension() {
+    let svg_path = temp_metrics_path("export_board").with_extension("svg");
+    let svg_arg = svg_path.to_string_lossy().into_owned();
+    let output = run_success(&[
+        "-n",
+        "6",
+        "-p",
+        "200",
+        "-e",
+        "200",
+        "-s",
+        "1",
+        "--json",
+        "--export-board",
+        &svg_arg,
+    ]);
+    let summary: Value = serde_json::from_slice(&output.stdout).expect("solve should emit JSON");
+    assert_eq!(summary["export_board"], svg_arg.as_str());
+    let svg = fs::read_to_string(&svg_path).expect("SVG export should be written");
+    assert!(svg.starts_with("<svg"));
+    assert_eq!(svg.matches("class=\"queen").count(), 6);
+    fs::remove_file(&svg_path).expect("SVG export should be removable");
+
+    let png_path = temp_metrics_path("export_board").with_extension("png");
+    let png_arg = png_path.to_string_lossy().into_owned();
+    run_success(&[
+        "render",
+        "1,0,2,3",
+        "--export-board",
+        &png_arg,
+        "--export-attack-lines",
+    ]);
+    let png = fs::read(&png_path).expect("PNG export should be written");
+    assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+    fs::remove_file(&png_path).expect("PNG export should be removable");
+
+    let output = run_command(&["render", "0,2,4,1,3", "--export-board", "board.txt"]);
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn sweep_and_bench_subcommands_summarize_seeds() {
+    let output = run_success(&[
+        "sweep",
+        "--sizes",
+        "6",
+        "--populations",
+        "100",
+        "--epochs",
+        "100",
+        "--seeds",
+        "2",
+    ]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut lines = stdout.lines();
+    assert!(
+        lines
+            .next()
+            .is_some_and(|header| header.starts_with("size,population,epochs"))
+    );
+    assert!(
+        lines
+            .next()
+            .is_some_and(|row| row.starts_with("6,100,100,"))
+    );
+
+    let output = run_success(&[
+        "bench", "-n", "6", "-p", "100", "-e", "100", "-s", "3", "--repeat", "2", "--json",
+    ]);
+    let summary: Value = serde_json::from_slice(&output.stdout).expect("bench should emit JSON");
+    assert_eq!(summary["runs"].as_array().map(Vec::len), Some(2));
+    assert_eq!(summary["runs"][1]["seed"], 4);
+}
+
+#[test]
+fn portfolio_subcommand_reports_winning_strategy() {
+    let output = run_success(&["portfolio", "-n", "20", "-p", "200", "-s", "5", "--json"]);
+    let summary: Value =
+        serde_json::from_slice(&output.stdout).expect("portfolio should emit JSON");
+    let winner = summary["winner"].as_str().expect("a strategy should win");
+    assert_eq!(summary["solution"]["conflicts_sum"], 0);
+    let strategies = summary["strategies"].as_array().unwrap();
+    assert_eq!(strategies.len(), 4);
+    for strategy in strategies {
+        let expected = if strategy["strategy"] == winner {
+            "solved"
+        } else {
+            "stopped"
+        };
+        assert_eq!(strategy["status"], expected);
+    }
+
+    let output = run_success(&[
+        "portfolio",
+        "-n",
+        "3",
+        "-e",
+        "4000000000",
+        "--strategies",
+        "ga",
+        "--time-limit",
+        "0.2",
+    ]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("ga: stopped"));
+    assert!(stdout.contains("No solution within the time limit."));
+
+    let output = run_success(&["portfolio", "-n", "3", "--strategies", "min-conflicts"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("min-conflicts: skipped"));
+    assert!(stdout.contains("No solution found."));
+
+    let output = run_command(&["portfolio", "--time-limit", "0"]);
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn config_file_values_apply_with_cli_overrides() {
+    let path = temp_metrics_path("config").with_extension("toml");
+    fs::write(
+        &path,
+        "board_size = 10\ntarget_population = 300\nmax_epochs = 250\nseed = 9\nselection_strategy = \"tournament\"\nmutation_rate = 0.12345679\n",
+    )
+    .expect("config should be writable");
+    let config_path = path.to_str().expect("utf-8 path");
+
+    let output = run_success(&[
+        "--config",
+        config_path,
+        "-n",
+        "8",
+        "--dump-config",
+        "--json",
+    ]);
+    fs::remove_file(&path).expect("config should be removable");
+    let config: Value = serde_json::from_slice(&output.stdout).expect("dump should be JSON");
+
+    assert_eq!(config["board_size"], 8);
+    assert_eq!(config["target_population"], 300);
+    assert_eq!(config["seed"], 9);
+    assert_eq!(config["selection_strategy"], "tournament");
+    assert_eq!(config["mutation_rate"], 0.12345679);
+}
+
+#[test]
+fn json_summary_reloads_as_config_and_reproduces_run() {
+    let output = run_success(&[
+        "-n",
+        "8",
+        "-p",
+        "200",
+        "-e",
+        "200",
+        "-s",
+        "21",
+        "-m",
+        "0.0625",
+        "-r",
+        "0.123456789",
+        "--restart-keep-ratio",
+        "0.3333333",
+        "--json",
+    ]);
+    let path = temp_metrics_path("summary_config").with_extension("json");
+    fs::write(&path, &output.stdout).expect("summary should be writable");
+
+    let rerun = run_success(&["--config", path.to_str().expect("utf-8 path"), "--json"]);
+    fs::remove_file(&path).expect("summary should be removable");
+
+    let mut first: Value = serde_json::from_slice(&output.stdout).expect("summary should be JSON");
+    let mut second: Value = serde_json::from_slice(&rerun.stdout).expect("rerun should be JSON");
+    first["elapsed_ms"] = Value::Null;
+    second["elapsed_ms"] = Value::Null;
+    first["phase_us"] = Value::Null;
+    second["phase_us"] = Value::Null;
+    assert_eq!(first, second);
+}
+
+#[test]
+fn multi_run_and_ndjson_summaries_reload_as_config() {
+    let output = run_success(&[
+        "-n", "6", "-p", "64", "-e", "200", "-s", "1", "--runs", "3", "--json",
+    ]);
+    let path = temp_metrics_path("runs_summary_config").with_extension("json");
+    fs::write(&path, &output.stdout).expect("summary should be writable");
+    let rerun = run_success(&["--config", path.to_str().expect("utf-8 path"), "--json"]);
+    fs::remove_file(&path).expect("summary should be removable");
+
+    let strip_elapsed = |output: &Output| {
+        let mut summary: Value = serde_json::from_slice(&output.stdout).expect("JSON summary");
+        for run in summary["runs"]
+            .as_array_mut()
+            .expect("runs should be an array")
+        {
+            run["elapsed_ms"] = Value::Null;
+        }
+        summary
+    };
+    assert_eq!(strip_elapsed(&output), strip_elapsed(&rerun));
+
+    let output = run_success(&[
+        "-n",
+        "8",
+        "-p",
+        "120",
+        "-e",
+        "40",
+        "-s",
+        "13",
+        "--progress-format",
+        "ndjson",
+    ]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let summary_line = stdout
+        .lines()
+        .last()
+        .expect("stream should end with a summary");
+    let path = temp_metrics_path("ndjson_summary_config").with_extension("json");
+    fs::write(&path, summary_line).expect("summary should be writable");
+    let rerun = run_success(&["--config", path.to_str().expect("utf-8 path"), "--json"]);
+    fs::remove_file(&path).expect("summary should be removable");
+
+    let mut first: Value = serde_json::from_str(summary_line).expect("summary should be JSON");
+    let mut second: Value = serde_json::from_slice(&rerun.stdout).expect("rerun should be JSON");
+    assert_eq!(
+        first
+            .as_object_mut()
+            .and_then(|summary| summary.remove("event")),
+        Some(Value::from("summary"))
+    );
+    for summary in [&mut first, &mut second] {
+        summary["elapsed_ms"] = Value::Null;
+        summary["phase_us"] = Value::Null;
+    }
+    assert_eq!(first, second);
+}
+
+#[test]
+fn cli_rejects_unknown_config_keys() {
+    let path = temp_metrics_path("bad_config").with_extension("toml");
+    fs::write(&path, "board_sise = 8\n").expect("config should be writable");
+
+    let output = run_command(&["--config", path.to_str().expect("utf-8 path")]);
+    fs::remove_file(&path).expect("config should be removable");
+
+    assert_eq!(output.status.code(), Some(2));
+    assert!(output_text(&output).contains("unknown key `board_sise`"));
+}
+
+#[test]
+fn ndjson_progress_streams_one_object_per_epoch() {
+    let output = run_success(&[
+        "-n",
+        "8",
+        "-p",
+        "120",
+        "-e",
+        "40",
+        "-s",
+        "13",
+        "--progress-format",
+        "ndjson",
+    ]);
+    let events = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| serde_json::from_str::<Value>(line).expect("each line should be JSON"))
+        .collect::<Vec<_>>();
+    let (summary, epochs) = events.split_last().expect("stream should not be empty");
+
+    assert_eq!(summary["event"], "summary");
+    assert!(!epochs.is_empty());
+    for (index, event) in epochs.iter().enumerate() {
+        assert_eq!(event["event"], "epoch");
+        assert_eq!(event["seed"], 13);
+        assert_eq!(event["epoch"], index);
+        assert_eq!(event["best_positions"].as_array().map(Vec::len), Some(8));
+    }
+    assert_eq!(
+        epochs.last().map(|event| &event["best_positions"]),
+        Some(&summary["best_chromosome"]["positions"])
+    );
+}
+
+#[test]
+fn recorded_run_log_replays_epochs() {
+    let log_path = temp_metrics_path("record").with_extension("ndjson");
+    let log_arg = log_path.to_string_lossy().into_owned();
+    let output = run_success(&[
+        "-n", "6", "-p", "200", "-e", "200", "-s", "1", "--json", "--record", &log_arg,
+    ]);
+    let summary: Value = serde_json::from_slice(&output.stdout).expect("solve should emit JSON");
+    assert_eq!(summary["record"], log_arg.as_str());
+
+    let output = run_success(&["replay", &log_arg, "--json"]);
+    let replay: Value = serde_json::from_slice(&output.stdout).expect("replay should emit JSON");
+    assert_eq!(replay["seed"], 1);
+    assert_eq!(replay["board_size"], 6);
+    assert_eq!(replay["solved_epoch"], summary["solved_epoch"]);
+    assert_eq!(replay["snapshot"]["best_conflicts_sum"], 0);
+    let improvements = replay["improvements"]
+        .as_array()
+        .expect("improvements should be an array");
+    assert_eq!(improvements[0]["epoch"], 0);
+
+    let output = run_success(&["replay", &log_arg, "--epoch", "0", "--json"]);
+    let replay: Value = serde_json::from_slice(&output.stdout).expect("replay should emit JSON");
+    assert_eq!(replay["snapshot"]["epoch"], 0);
+
+    let output = run_command(&["replay", &log_arg, "--epoch", "100000"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(output_text(&output).contains("is not in the run log"));
+    fs::remove_file(&log_path).expect("run log should be removable");
+}
+
+#[test]
+fn metrics_csv_replays_without_boards() {
+    let metrics_path = temp_metrics_path("replay_csv");
+    let metrics_arg = metrics_path.to_string_lossy().into_owned();
+    run_success(&[
+        "-n",
+        "6",
+        "-p",
+        "200",
+        "-e",
+        "200",
+        "-s",
+        "1",
+        "--metrics-csv",
+        &metrics_arg,
+    ]);
+
+    let output = run_success(&["replay", &metrics_arg, "--json"]);
+    let replay: Value = serde_json::from_slice(&output.stdout).expect("replay should emit JSON");
+    assert_eq!(replay["seed"], 1);
+    assert_eq!(replay["board_size"], 6);
+    assert_eq!(replay["snapshot"]["best_conflicts_sum"], 0);
+    assert_eq!(replay["snapshot"]["best_positions"], serde_json::json!([]));
+    assert!(replay["snapshot"]["unique_symmetry_classes"].is_null());
+
+    let output = run_success(&["replay", &metrics_arg]);
+    assert!(output_text(&output).contains("Run: seed 1, 6x6 board"));
+    fs::remove_file(&metrics_path).expect("metrics CSV should be removable");
+}
+
+#[test]
+fn metrics_csv_writes_unique_symmetry_classes_when_counted() {
+    let metrics_path = temp_metrics_path("symmetry_classes");
+    let metrics_arg = metrics_path.to_string_lossy().into_owned();
+    run_success(&[
+        "-n",
+        "8",
+        "-p",
+        "50",
+        "-e",
+        "5",
+        "-s",
+        "3",
+        "--count-symmetry-classes",
+        "--no-board",
+        "--metrics-csv",
+        &metrics_arg,
+    ]);
+
+    let csv = fs::read_to_string(&metrics_path).expect("metrics CSV should be written");
+    let mut lines = csv.lines();
+    let column = lines
+        .next()
+        .unwrap()
+        .split(',')
+        .position(|column| column == "unique_symmetry_classes")
+        .expect("header should list unique_symmetry_classes");
+    for line in lines {
+        let classes = line.split(',').nth(column).unwrap();
+        assert!((1..=50).contains(&classes.parse::<u32>().unwrap()));
+    }
+
+    let output = run_success(&["replay", &metrics_arg, "--json"]);
+    let replay: Value = serde_json::from_slice(&output.stdout).expect("replay should emit JSON");
+    assert!(replay["snapshot"]["unique_symmetry_classes"].is_u64());
+    fs::remove_file(&metrics_path).expect("metrics CSV should be removable");
+}